@@ -0,0 +1,95 @@
+//! Compares decoding unmapped BAM records with decoding the same records as mapped records.
+//!
+//! Unmapped records without CIGAR operations use a fast decode path. Clearing the unmapped flag in
+//! the encoded records decodes the same bytes using the general path.
+//!
+//! Run with `cargo bench -p noodles-bam --bench decode_unmapped`.
+
+use std::{io, time::Instant};
+
+use noodles_bam as bam;
+use noodles_sam::{
+    self as sam,
+    alignment::Record,
+    record::{sequence::Base, Flags, QualityScores, Sequence},
+};
+
+const RECORD_COUNT: usize = 1 << 20;
+const READ_LENGTH: usize = 151;
+
+// block_size (4) + ref_id (4) + pos (4) + l_read_name (1) + mapq (1) + bin (2) + n_cigar_op (2)
+const FLAGS_OFFSET: usize = 18;
+
+fn build_records(
+    header: &sam::Header,
+    flags: Flags,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let bases = b"ACGTN";
+    let sequence: Sequence = (0..READ_LENGTH)
+        .map(|i| Base::try_from(bases[i % bases.len()]))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Sequence::from)?;
+
+    let scores: Vec<u8> = (0..READ_LENGTH).map(|i| (i % 42) as u8).collect();
+    let quality_scores = QualityScores::try_from(scores)?;
+
+    let mut dst = Vec::new();
+    let mut buf = Vec::new();
+
+    for i in 0..RECORD_COUNT {
+        let record = Record::builder()
+            .set_read_name(format!("r{i}").parse()?)
+            .set_flags(Flags::UNMAPPED)
+            .set_sequence(sequence.clone())
+            .set_quality_scores(quality_scores.clone())
+            .build();
+
+        buf.clear();
+        bam::record::codec::encode(&mut buf, header, &record)?;
+
+        let start = dst.len();
+
+        let block_size = u32::try_from(buf.len())?;
+        dst.extend(block_size.to_le_bytes());
+        dst.extend(&buf);
+
+        let i = start + FLAGS_OFFSET;
+        dst[i..i + 2].copy_from_slice(&flags.bits().to_le_bytes());
+    }
+
+    Ok(dst)
+}
+
+fn decode(src: &[u8], header: &sam::Header) -> io::Result<usize> {
+    let mut reader = bam::Reader::from(src);
+    let mut record = Record::default();
+    let mut n = 0;
+
+    while reader.read_record(header, &mut record)? != 0 {
+        n += 1;
+    }
+
+    Ok(n)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let header = sam::Header::default();
+
+    let unmapped_records = build_records(&header, Flags::UNMAPPED)?;
+    let mapped_records = build_records(&header, Flags::empty())?;
+
+    let start = Instant::now();
+    assert_eq!(decode(&mapped_records, &header)?, RECORD_COUNT);
+    let general_path_elapsed = start.elapsed();
+
+    let start = Instant::now();
+    assert_eq!(decode(&unmapped_records, &header)?, RECORD_COUNT);
+    let fast_path_elapsed = start.elapsed();
+
+    println!(
+        "{RECORD_COUNT} records: general path = {general_path_elapsed:?}, \
+         unmapped fast path = {fast_path_elapsed:?}"
+    );
+
+    Ok(())
+}
@@ -4,100 +4,11 @@
 
 use std::{env, fmt};
 
-use noodles_bam as bam;
-use noodles_sam::{alignment::Record, record::MappingQuality};
-
-const MIN_HQ_MAPPING_QUALITY: MappingQuality = match MappingQuality::new(5) {
-    Some(mapping_quality) => mapping_quality,
-    None => unreachable!(),
+use noodles_bam::{
+    self as bam,
+    flagstat::{Counts, MIN_HQ_MAPPING_QUALITY},
 };
 
-#[derive(Debug, Default)]
-struct Counts {
-    read: u64,
-    primary: u64,
-    secondary: u64,
-    supplementary: u64,
-    duplicate: u64,
-    primary_duplicate: u64,
-    mapped: u64,
-    primary_mapped: u64,
-    paired: u64,
-    read_1: u64,
-    read_2: u64,
-    proper_pair: u64,
-    mate_mapped: u64,
-    singleton: u64,
-    mate_reference_sequence_id_mismatch: u64,
-    mate_reference_sequence_id_mismatch_hq: u64,
-}
-
-fn count(counts: &mut Counts, record: &Record) {
-    let flags = record.flags();
-
-    counts.read += 1;
-
-    if !flags.is_unmapped() {
-        counts.mapped += 1;
-    }
-
-    if flags.is_duplicate() {
-        counts.duplicate += 1;
-    }
-
-    if flags.is_secondary() {
-        counts.secondary += 1;
-    } else if flags.is_supplementary() {
-        counts.supplementary += 1;
-    } else {
-        counts.primary += 1;
-
-        if !flags.is_unmapped() {
-            counts.primary_mapped += 1;
-        }
-
-        if flags.is_duplicate() {
-            counts.primary_duplicate += 1;
-        }
-
-        if flags.is_segmented() {
-            counts.paired += 1;
-
-            if flags.is_first_segment() {
-                counts.read_1 += 1;
-            }
-
-            if flags.is_last_segment() {
-                counts.read_2 += 1;
-            }
-
-            if !flags.is_unmapped() {
-                if flags.is_properly_aligned() {
-                    counts.proper_pair += 1;
-                }
-
-                if flags.is_mate_unmapped() {
-                    counts.singleton += 1;
-                } else {
-                    counts.mate_mapped += 1;
-
-                    if record.mate_reference_sequence_id() != record.reference_sequence_id() {
-                        counts.mate_reference_sequence_id_mismatch += 1;
-
-                        if record
-                            .mapping_quality()
-                            .map(|mapq| mapq >= MIN_HQ_MAPPING_QUALITY)
-                            .unwrap_or(true)
-                        {
-                            counts.mate_reference_sequence_id_mismatch_hq += 1;
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
-
 struct PercentageFormat(u64, u64);
 
 impl fmt::Display for PercentageFormat {
@@ -198,18 +109,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut reader = bam::reader::Builder::default().build_from_path(src)?;
     let header = reader.read_header()?;
 
-    let mut qc_pass_counts = Counts::default();
-    let mut qc_fail_counts = Counts::default();
-
-    for result in reader.records(&header) {
-        let record = result?;
-
-        if record.flags().is_qc_fail() {
-            count(&mut qc_fail_counts, &record);
-        } else {
-            count(&mut qc_pass_counts, &record);
-        }
-    }
+    let (qc_pass_counts, qc_fail_counts) = bam::flagstat::count(reader.records(&header))?;
 
     print_stats(&qc_pass_counts, &qc_fail_counts);
 
@@ -0,0 +1,299 @@
+//! BAM flag statistics.
+//!
+//! The counts match the output of `samtools flagstat`.
+
+use std::io;
+
+use noodles_sam::{alignment::Record, record::MappingQuality};
+
+/// The minimum mapping quality of a high-quality record.
+///
+/// This is used to count records with a mate mapped to a different reference sequence.
+pub const MIN_HQ_MAPPING_QUALITY: MappingQuality = match MappingQuality::new(5) {
+    Some(mapping_quality) => mapping_quality,
+    None => unreachable!(),
+};
+
+/// Flag statistics counts.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Counts {
+    /// The number of records.
+    pub read: u64,
+    /// The number of primary records.
+    pub primary: u64,
+    /// The number of secondary records.
+    pub secondary: u64,
+    /// The number of supplementary records.
+    pub supplementary: u64,
+    /// The number of duplicate records.
+    pub duplicate: u64,
+    /// The number of primary duplicate records.
+    pub primary_duplicate: u64,
+    /// The number of mapped records.
+    pub mapped: u64,
+    /// The number of primary mapped records.
+    pub primary_mapped: u64,
+    /// The number of primary paired records.
+    pub paired: u64,
+    /// The number of primary paired records that are the first segment.
+    pub read_1: u64,
+    /// The number of primary paired records that are the last segment.
+    pub read_2: u64,
+    /// The number of primary mapped paired records that are properly aligned.
+    pub proper_pair: u64,
+    /// The number of primary mapped paired records with a mapped mate.
+    pub mate_mapped: u64,
+    /// The number of primary mapped paired records with an unmapped mate.
+    pub singleton: u64,
+    /// The number of primary mapped paired records with a mate mapped to a different reference
+    /// sequence.
+    pub mate_reference_sequence_id_mismatch: u64,
+    /// The number of primary mapped paired records with a mate mapped to a different reference
+    /// sequence and a mapping quality of at least [`MIN_HQ_MAPPING_QUALITY`].
+    pub mate_reference_sequence_id_mismatch_hq: u64,
+}
+
+impl Counts {
+    /// Adds a record to the counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::flagstat::Counts;
+    /// use noodles_sam::alignment::Record;
+    ///
+    /// let mut counts = Counts::default();
+    /// counts.add(&Record::default());
+    ///
+    /// assert_eq!(counts.read, 1);
+    /// assert_eq!(counts.primary, 1);
+    /// assert_eq!(counts.mapped, 0);
+    /// ```
+    pub fn add(&mut self, record: &Record) {
+        let flags = record.flags();
+
+        self.read += 1;
+
+        if !flags.is_unmapped() {
+            self.mapped += 1;
+        }
+
+        if flags.is_duplicate() {
+            self.duplicate += 1;
+        }
+
+        if flags.is_secondary() {
+            self.secondary += 1;
+        } else if flags.is_supplementary() {
+            self.supplementary += 1;
+        } else {
+            self.primary += 1;
+
+            if !flags.is_unmapped() {
+                self.primary_mapped += 1;
+            }
+
+            if flags.is_duplicate() {
+                self.primary_duplicate += 1;
+            }
+
+            if flags.is_segmented() {
+                self.paired += 1;
+
+                if flags.is_first_segment() {
+                    self.read_1 += 1;
+                }
+
+                if flags.is_last_segment() {
+                    self.read_2 += 1;
+                }
+
+                if !flags.is_unmapped() {
+                    if flags.is_properly_aligned() {
+                        self.proper_pair += 1;
+                    }
+
+                    if flags.is_mate_unmapped() {
+                        self.singleton += 1;
+                    } else {
+                        self.mate_mapped += 1;
+
+                        if record.mate_reference_sequence_id() != record.reference_sequence_id() {
+                            self.mate_reference_sequence_id_mismatch += 1;
+
+                            if record
+                                .mapping_quality()
+                                .map(|mapq| mapq >= MIN_HQ_MAPPING_QUALITY)
+                                .unwrap_or(true)
+                            {
+                                self.mate_reference_sequence_id_mismatch_hq += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Counts the flag statistics of records.
+///
+/// This returns the counts of records that pass quality control and the counts of records that
+/// fail quality control, respectively.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// let header = reader.read_header()?;
+///
+/// let (qc_pass_counts, qc_fail_counts) = bam::flagstat::count(reader.records(&header))?;
+/// println!("{} + {} in total", qc_pass_counts.read, qc_fail_counts.read);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn count<I>(records: I) -> io::Result<(Counts, Counts)>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut qc_pass_counts = Counts::default();
+    let mut qc_fail_counts = Counts::default();
+
+    for result in records {
+        let record = result?;
+
+        if record.flags().is_qc_fail() {
+            qc_fail_counts.add(&record);
+        } else {
+            qc_pass_counts.add(&record);
+        }
+    }
+
+    Ok((qc_pass_counts, qc_fail_counts))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::Flags;
+
+    use super::*;
+
+    fn build_record(
+        flags: Flags,
+        reference_sequence_id: Option<usize>,
+        mate_reference_sequence_id: Option<usize>,
+        mapping_quality: Option<u8>,
+    ) -> io::Result<Record> {
+        let mut builder = Record::builder().set_flags(flags);
+
+        if let Some(id) = reference_sequence_id {
+            builder = builder.set_reference_sequence_id(id);
+        }
+
+        if let Some(id) = mate_reference_sequence_id {
+            builder = builder.set_mate_reference_sequence_id(id);
+        }
+
+        if let Some(mapping_quality) = mapping_quality.and_then(MappingQuality::new) {
+            builder = builder.set_mapping_quality(mapping_quality);
+        }
+
+        Ok(builder.build())
+    }
+
+    #[test]
+    fn test_count() -> io::Result<()> {
+        let pair = Flags::SEGMENTED | Flags::PROPERLY_ALIGNED;
+
+        let records = vec![
+            // properly paired
+            build_record(pair | Flags::FIRST_SEGMENT, Some(0), Some(0), Some(60)),
+            build_record(pair | Flags::LAST_SEGMENT, Some(0), Some(0), Some(60)),
+            // mate mapped to a different reference sequence
+            build_record(
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT,
+                Some(0),
+                Some(1),
+                Some(60),
+            ),
+            build_record(
+                Flags::SEGMENTED | Flags::LAST_SEGMENT,
+                Some(1),
+                Some(0),
+                Some(3),
+            ),
+            // singleton and its unmapped mate
+            build_record(
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_UNMAPPED,
+                Some(0),
+                Some(0),
+                Some(60),
+            ),
+            build_record(
+                Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::UNMAPPED,
+                Some(0),
+                Some(0),
+                None,
+            ),
+            // unpaired
+            build_record(Flags::DUPLICATE, Some(0), None, Some(60)),
+            build_record(Flags::SECONDARY | Flags::DUPLICATE, Some(0), None, Some(60)),
+            build_record(Flags::SUPPLEMENTARY, Some(0), None, Some(60)),
+            build_record(Flags::UNMAPPED, None, None, None),
+            // QC fail
+            build_record(Flags::QC_FAIL, Some(0), None, Some(60)),
+        ];
+
+        let (qc_pass_counts, qc_fail_counts) = count(records)?;
+
+        assert_eq!(
+            qc_pass_counts,
+            Counts {
+                read: 10,
+                primary: 8,
+                secondary: 1,
+                supplementary: 1,
+                duplicate: 2,
+                primary_duplicate: 1,
+                mapped: 8,
+                primary_mapped: 6,
+                paired: 6,
+                read_1: 3,
+                read_2: 3,
+                proper_pair: 2,
+                mate_mapped: 4,
+                singleton: 1,
+                mate_reference_sequence_id_mismatch: 2,
+                mate_reference_sequence_id_mismatch_hq: 1,
+            }
+        );
+
+        assert_eq!(
+            qc_fail_counts,
+            Counts {
+                read: 1,
+                primary: 1,
+                mapped: 1,
+                primary_mapped: 1,
+                ..Default::default()
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_with_error() {
+        let records = vec![
+            build_record(Flags::empty(), Some(0), None, Some(60)),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+        ];
+
+        assert!(matches!(
+            count(records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
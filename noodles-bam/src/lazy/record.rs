@@ -392,7 +392,12 @@ impl TryFrom<Record> for sam::alignment::Record {
             .set_quality_scores(lazy_record.quality_scores().try_into()?)
             .set_data(lazy_record.data().try_into()?);
 
-        Ok(builder.build())
+        let mut record = builder.build();
+
+        crate::record::codec::decoder::cigar::resolve(&mut record)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(record)
     }
 }
 
@@ -1,6 +1,8 @@
 use std::io;
 
-use noodles_sam as sam;
+use noodles_sam::{self as sam, record::sequence::Base};
+
+use crate::record::codec::decoder::sequence::decode_base;
 
 /// A raw BAM record sequence.
 #[derive(Debug, Eq, PartialEq)]
@@ -25,6 +27,33 @@ impl<'a> Sequence<'a> {
     pub fn len(&self) -> usize {
         self.base_count
     }
+
+    /// Returns the base at the given index.
+    ///
+    /// The index is 0-based. This decodes only the requested base.
+    pub fn get(&self, i: usize) -> Option<Base> {
+        if i >= self.base_count {
+            return None;
+        }
+
+        self.src.get(i / 2).map(|&b| {
+            if i % 2 == 0 {
+                decode_base(b >> 4)
+            } else {
+                decode_base(b)
+            }
+        })
+    }
+
+    /// Returns an iterator over the bases in the sequence.
+    ///
+    /// Bases are decoded as they are read. The padding of an odd-length sequence is not included.
+    pub fn iter(&self) -> impl Iterator<Item = Base> + '_ {
+        self.src
+            .iter()
+            .flat_map(|&b| [decode_base(b >> 4), decode_base(b)])
+            .take(self.base_count)
+    }
 }
 
 impl<'a> AsRef<[u8]> for Sequence<'a> {
@@ -48,3 +77,43 @@ impl<'a> TryFrom<Sequence<'a>> for sam::record::Sequence {
         Ok(sam_sequence)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let src = [0x12, 0x4f]; // ACG
+        let sequence = Sequence::new(&src, 3);
+
+        assert_eq!(sequence.get(0), Some(Base::A));
+        assert_eq!(sequence.get(1), Some(Base::C));
+        assert_eq!(sequence.get(2), Some(Base::G));
+        assert!(sequence.get(3).is_none());
+        assert!(sequence.get(8).is_none());
+
+        let sequence = Sequence::new(&[], 0);
+        assert!(sequence.get(0).is_none());
+    }
+
+    #[test]
+    fn test_iter() {
+        let src = [0x12, 0x4f]; // ACG
+        let sequence = Sequence::new(&src, 3);
+        assert_eq!(
+            sequence.iter().collect::<Vec<_>>(),
+            [Base::A, Base::C, Base::G]
+        );
+
+        let src = [0x12, 0x48]; // ACGT
+        let sequence = Sequence::new(&src, 4);
+        assert_eq!(
+            sequence.iter().collect::<Vec<_>>(),
+            [Base::A, Base::C, Base::G, Base::T]
+        );
+
+        let sequence = Sequence::new(&[], 0);
+        assert!(sequence.iter().next().is_none());
+    }
+}
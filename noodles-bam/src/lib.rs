@@ -48,6 +48,7 @@
 mod r#async;
 
 pub mod bai;
+pub mod flagstat;
 pub mod indexed_reader;
 pub mod lazy;
 pub mod reader;
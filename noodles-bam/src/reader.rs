@@ -1,5 +1,6 @@
 //! BAM reader and iterators.
 
+mod assert_sorted;
 mod builder;
 mod header;
 mod lazy_records;
@@ -9,7 +10,11 @@ mod records;
 mod unmapped_records;
 
 pub use self::{
-    builder::Builder, lazy_records::LazyRecords, query::Query, records::Records,
+    assert_sorted::{assert_sorted, AssertSorted},
+    builder::Builder,
+    lazy_records::LazyRecords,
+    query::Query,
+    records::Records,
     unmapped_records::UnmappedRecords,
 };
 
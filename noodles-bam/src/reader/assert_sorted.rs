@@ -0,0 +1,172 @@
+use std::{fmt, io};
+
+use noodles_core::Position;
+use noodles_sam::alignment::Record;
+
+type Key = (Option<usize>, Option<Position>);
+
+/// An iterator adapter that verifies records are coordinate-sorted.
+///
+/// This is created by calling [`assert_sorted`].
+pub struct AssertSorted<I> {
+    records: I,
+    last_key: Option<Key>,
+}
+
+impl<I> Iterator for AssertSorted<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.records.next()? {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let key = (record.reference_sequence_id(), record.alignment_start());
+
+        if let Some(last_key) = self.last_key {
+            if !is_ordered(last_key, key) {
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "out-of-order record at {} (previous record at {})",
+                        DisplayKey(key),
+                        DisplayKey(last_key),
+                    ),
+                )));
+            }
+        }
+
+        self.last_key = Some(key);
+
+        Some(Ok(record))
+    }
+}
+
+/// Wraps an iterator of records to verify they are coordinate-sorted.
+///
+/// Records must be ordered by reference sequence ID and then by alignment start. Unmapped records
+/// without a reference sequence ID must be last.
+///
+/// The returned iterator yields an error with the kind [`io::ErrorKind::InvalidData`] for a
+/// record that is out of order.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// let header = reader.read_header()?;
+///
+/// for result in bam::reader::assert_sorted(reader.records(&header)) {
+///     let record = result?;
+///     // ...
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn assert_sorted<I>(records: I) -> AssertSorted<I::IntoIter>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    AssertSorted {
+        records: records.into_iter(),
+        last_key: None,
+    }
+}
+
+fn is_ordered(a: Key, b: Key) -> bool {
+    match (a.0, b.0) {
+        (Some(a_id), Some(b_id)) => a_id < b_id || (a_id == b_id && a.1 <= b.1),
+        (Some(_), None) => true,
+        (None, Some(_)) => false,
+        (None, None) => true,
+    }
+}
+
+struct DisplayKey(Key);
+
+impl fmt::Display for DisplayKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            (Some(id), Some(position)) => write!(f, "{id}:{position}"),
+            (Some(id), None) => write!(f, "{id}:*"),
+            (None, _) => write!(f, "*"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(
+        reference_sequence_id: Option<usize>,
+        alignment_start: Option<usize>,
+    ) -> io::Result<Record> {
+        let mut builder = Record::builder();
+
+        if let Some(id) = reference_sequence_id {
+            builder = builder.set_reference_sequence_id(id);
+        }
+
+        if let Some(position) = alignment_start.and_then(Position::new) {
+            builder = builder.set_alignment_start(position);
+        }
+
+        Ok(builder.build())
+    }
+
+    #[test]
+    fn test_assert_sorted() -> io::Result<()> {
+        let records = vec![
+            build_record(Some(0), Some(8)),
+            build_record(Some(0), Some(8)),
+            build_record(Some(0), Some(13)),
+            build_record(Some(1), Some(5)),
+            build_record(None, None),
+        ];
+
+        let actual: Vec<_> = assert_sorted(records).collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_sorted_with_unsorted_records() {
+        let t = |records: Vec<io::Result<Record>>, expected_message: &str| {
+            let mut iter = assert_sorted(records);
+            assert!(iter.next().unwrap().is_ok());
+
+            let e = iter.next().unwrap().unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+            assert_eq!(e.to_string(), expected_message);
+        };
+
+        t(
+            vec![
+                build_record(Some(0), Some(13)),
+                build_record(Some(0), Some(8)),
+            ],
+            "out-of-order record at 0:8 (previous record at 0:13)",
+        );
+
+        t(
+            vec![
+                build_record(Some(1), Some(5)),
+                build_record(Some(0), Some(8)),
+            ],
+            "out-of-order record at 0:8 (previous record at 1:5)",
+        );
+
+        t(
+            vec![build_record(None, None), build_record(Some(0), Some(8))],
+            "out-of-order record at 0:8 (previous record at *)",
+        );
+    }
+}
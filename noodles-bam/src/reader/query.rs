@@ -77,15 +77,6 @@ pub(crate) fn intersects(
     reference_sequence_id: usize,
     region_interval: Interval,
 ) -> bool {
-    match (
-        record.reference_sequence_id(),
-        record.alignment_start(),
-        record.alignment_end(),
-    ) {
-        (Some(id), Some(start), Some(end)) => {
-            let alignment_interval = (start..=end).into();
-            id == reference_sequence_id && region_interval.intersects(alignment_interval)
-        }
-        _ => false,
-    }
+    record.reference_sequence_id() == Some(reference_sequence_id)
+        && record.overlaps(region_interval)
 }
@@ -1,4 +1,6 @@
 pub mod decoder;
 pub mod encoder;
 
-pub(crate) use self::{decoder::decode, encoder::encode};
+pub use self::encoder::encode;
+
+pub(crate) use self::decoder::decode;
@@ -133,13 +133,26 @@ where
 
     get_read_name(src, record.read_name_mut(), l_read_name)
         .map_err(DecodeError::InvalidReadName)?;
-    get_cigar(src, record.cigar_mut(), n_cigar_op).map_err(DecodeError::InvalidCigar)?;
-    get_sequence(src, record.sequence_mut(), l_seq).map_err(DecodeError::InvalidSequence)?;
+
+    // Unmapped records without CIGAR operations have no alignment to read or resolve.
+    let is_unmapped = record.flags().is_unmapped() && n_cigar_op == 0;
+
+    if is_unmapped {
+        record.cigar_mut().clear();
+        sequence::get_unmapped_sequence(src, record.sequence_mut(), l_seq)
+            .map_err(DecodeError::InvalidSequence)?;
+    } else {
+        get_cigar(src, record.cigar_mut(), n_cigar_op).map_err(DecodeError::InvalidCigar)?;
+        get_sequence(src, record.sequence_mut(), l_seq).map_err(DecodeError::InvalidSequence)?;
+    }
+
     get_quality_scores(src, record.quality_scores_mut(), l_seq)
         .map_err(DecodeError::InvalidQualityScores)?;
     get_data(src, record.data_mut()).map_err(DecodeError::InvalidData)?;
 
-    cigar::resolve(header, record).map_err(DecodeError::InvalidCigar)?;
+    if !is_unmapped {
+        cigar::resolve(record).map_err(DecodeError::InvalidCigar)?;
+    }
 
     Ok(())
 }
@@ -165,4 +178,54 @@ mod tests {
             Err(DecodeError::InvalidReadName(_))
         ));
     }
+
+    #[test]
+    fn test_decode_with_unmapped_record() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::{sequence::Base, Flags, QualityScores, Sequence};
+
+        use crate::record::codec::encode;
+
+        // bin_mq_nl (4) + flag_nc (4) + ref_id (4) + pos (2) + l_read_name (2)
+        const FLAGS_OFFSET: usize = 14;
+
+        let header = sam::Header::default();
+
+        for read_length in [0, 1, 2, 5, 8, 151] {
+            let bases = b"ACGTN";
+            let sequence = (0..read_length)
+                .map(|i| Base::try_from(bases[i % bases.len()]))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Sequence::from)?;
+            let scores: Vec<u8> = (0..read_length).map(|i| (i % 42) as u8).collect();
+
+            let expected = Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(sequence)
+                .set_quality_scores(QualityScores::try_from(scores)?)
+                .build();
+
+            let mut buf = Vec::new();
+            encode(&mut buf, &header, &expected)?;
+
+            let mut actual = Record::default();
+            decode(&mut &buf[..], &header, &mut actual)?;
+            assert_eq!(actual, expected);
+
+            // Clearing the unmapped flag decodes the same bytes using the general path.
+            let flags = u16::from_le_bytes([buf[FLAGS_OFFSET], buf[FLAGS_OFFSET + 1]]);
+            let flags = flags & !Flags::UNMAPPED.bits();
+            buf[FLAGS_OFFSET..FLAGS_OFFSET + 2].copy_from_slice(&flags.to_le_bytes());
+
+            let mut mapped_record = Record::default();
+            decode(&mut &buf[..], &header, &mut mapped_record)?;
+
+            assert_eq!(mapped_record.cigar(), actual.cigar());
+            assert_eq!(mapped_record.sequence(), actual.sequence());
+            assert_eq!(mapped_record.quality_scores(), actual.quality_scores());
+            assert_eq!(mapped_record.data(), actual.data());
+        }
+
+        Ok(())
+    }
 }
@@ -70,39 +70,33 @@ where
 }
 
 // § 4.2.2 "`N_CIGAR_OP` field" (2022-08-22)
-pub(super) fn resolve(header: &sam::Header, record: &mut Record) -> Result<(), DecodeError> {
+pub(crate) fn resolve(record: &mut Record) -> Result<(), DecodeError> {
     use sam::record::{
         cigar::{op::Kind, Op},
         data::field::{tag, value::Array},
     };
 
     if let [op_0, op_1] = record.cigar().as_ref() {
-        let rs = record
-            .reference_sequence(header)
-            .transpose()
-            .map_err(|_| DecodeError::InvalidReferenceSequence)?;
-
-        if let Some((_, reference_sequence)) = rs {
-            let k = record.sequence().len();
-            let m = reference_sequence.length().get();
-
-            if *op_0 == Op::new(Kind::SoftClip, k) && *op_1 == Op::new(Kind::Skip, m) {
-                if let Some((_, value)) = record.data_mut().remove(&tag::CIGAR) {
-                    let data = value
-                        .as_array()
-                        .and_then(|array| match array {
-                            Array::UInt32(values) => Some(values),
-                            _ => None,
-                        })
-                        .ok_or(DecodeError::InvalidDataType)?;
-
-                    let cigar = record.cigar_mut();
-                    cigar.clear();
-
-                    for &n in data {
-                        let op = decode_op(n).map_err(DecodeError::InvalidOp)?;
-                        cigar.as_mut().push(op);
-                    }
+        let k = record.sequence().len();
+
+        // The length of the `N` op is the reference length of the alignment. This is not checked
+        // against the real CIGAR.
+        if *op_0 == Op::new(Kind::SoftClip, k) && op_1.kind() == Kind::Skip {
+            if let Some((_, value)) = record.data_mut().remove(&tag::CIGAR) {
+                let data = value
+                    .as_array()
+                    .and_then(|array| match array {
+                        Array::UInt32(values) => Some(values),
+                        _ => None,
+                    })
+                    .ok_or(DecodeError::InvalidDataType)?;
+
+                let cigar = record.cigar_mut();
+                cigar.clear();
+
+                for &n in data {
+                    let op = decode_op(n).map_err(DecodeError::InvalidOp)?;
+                    cigar.as_mut().push(op);
                 }
             }
         }
@@ -156,27 +150,15 @@ mod tests {
 
     #[test]
     fn test_resolve() -> Result<(), Box<dyn std::error::Error>> {
-        use std::num::NonZeroUsize;
-
-        use sam::{
-            header::record::value::{map::ReferenceSequence, Map},
-            record::{
-                cigar::op::{self, Op},
-                data::field::{tag, value::Array, Value},
-                Cigar,
-            },
+        use sam::record::{
+            cigar::op::{self, Op},
+            data::field::{tag, value::Array, Value},
+            Cigar,
         };
 
-        let header = sam::Header::builder()
-            .add_reference_sequence(
-                "sq0".parse()?,
-                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
-            )
-            .build();
-
         let mut record = Record::builder()
             .set_reference_sequence_id(0)
-            .set_cigar("4S8N".parse()?)
+            .set_cigar("4S4N".parse()?)
             .set_sequence("ACGT".parse()?)
             .set_data(
                 [(tag::CIGAR, Value::Array(Array::UInt32(vec![0x40])))]
@@ -185,7 +167,7 @@ mod tests {
             )
             .build();
 
-        resolve(&header, &mut record)?;
+        resolve(&mut record)?;
 
         let expected = Cigar::try_from(vec![Op::new(op::Kind::Match, 4)])?;
 
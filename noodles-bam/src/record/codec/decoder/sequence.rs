@@ -1,4 +1,4 @@
-use std::{error, fmt, mem, num};
+use std::{cmp, error, fmt, mem, num};
 
 use bytes::Buf;
 use noodles_sam::record::{sequence::Base, Sequence};
@@ -48,6 +48,36 @@ pub fn get_sequence<B>(
 ) -> Result<(), DecodeError>
 where
     B: Buf,
+{
+    get_sequence_with(src, sequence, l_seq, |b| {
+        [decode_base(b >> 4), decode_base(b)]
+    })
+}
+
+// Decodes the sequence of an unmapped record.
+//
+// This is the same as `get_sequence` but uses a lookup table of base pairs, as the sequence
+// dominates the size of unmapped records.
+pub(crate) fn get_unmapped_sequence<B>(
+    src: &mut B,
+    sequence: &mut Sequence,
+    l_seq: usize,
+) -> Result<(), DecodeError>
+where
+    B: Buf,
+{
+    get_sequence_with(src, sequence, l_seq, |b| BASE_PAIRS[usize::from(b)])
+}
+
+fn get_sequence_with<B, F>(
+    src: &mut B,
+    sequence: &mut Sequence,
+    l_seq: usize,
+    decode_base_pair: F,
+) -> Result<(), DecodeError>
+where
+    B: Buf,
+    F: Fn(u8) -> [Base; 2],
 {
     let seq_len = (l_seq + 1) / 2;
 
@@ -55,22 +85,43 @@ where
         return Err(DecodeError::UnexpectedEof);
     }
 
-    let seq = src.take(seq_len);
-    let bases = seq
-        .chunk()
-        .iter()
-        .flat_map(|&b| [decode_base(b >> 4), decode_base(b)]);
+    let bases = sequence.as_mut();
+    bases.clear();
+    bases.reserve(seq_len * 2);
+
+    let mut remaining = seq_len;
+
+    while remaining > 0 {
+        let chunk = src.chunk();
+        let len = cmp::min(chunk.len(), remaining);
 
-    sequence.clear();
-    sequence.as_mut().extend(bases);
-    sequence.as_mut().truncate(l_seq);
+        bases.extend(chunk[..len].iter().flat_map(|&b| decode_base_pair(b)));
+
+        src.advance(len);
+        remaining -= len;
+    }
 
-    src.advance(seq_len);
+    bases.truncate(l_seq);
 
     Ok(())
 }
 
-fn decode_base(n: u8) -> Base {
+static BASE_PAIRS: [[Base; 2]; 256] = build_base_pairs();
+
+const fn build_base_pairs() -> [[Base; 2]; 256] {
+    let mut pairs = [[Base::Eq; 2]; 256];
+    let mut i = 0;
+
+    while i < pairs.len() {
+        let b = i as u8;
+        pairs[i] = [decode_base(b >> 4), decode_base(b)];
+        i += 1;
+    }
+
+    pairs
+}
+
+pub(crate) const fn decode_base(n: u8) -> Base {
     match n & 0x0f {
         0 => Base::Eq,
         1 => Base::A,
@@ -120,6 +171,11 @@ mod tests {
         t(&[0x12, 0x40], &mut sequence, &"ACG".parse()?)?;
         t(&[0x12, 0x48], &mut sequence, &"ACGT".parse()?)?;
 
+        let mut src = (&[0x12][..]).chain(&[0x48][..]);
+        get_sequence(&mut src, &mut sequence, 4)?;
+        assert_eq!(sequence, "ACGT".parse()?);
+        assert!(!src.has_remaining());
+
         sequence.clear();
         let mut src = &b""[..];
         assert_eq!(
@@ -130,6 +186,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_unmapped_sequence() -> Result<(), DecodeError> {
+        // Every possible base pair, with the last base of the last pair unused for odd lengths.
+        let src: Vec<u8> = (0..=u8::MAX).collect();
+
+        let mut expected = Sequence::default();
+        let mut actual = Sequence::default();
+
+        for l_seq in [0, 1, 2, 3, 255, 256, 511, 512] {
+            let n = (l_seq + 1) / 2;
+
+            get_sequence(&mut &src[..n], &mut expected, l_seq)?;
+            get_unmapped_sequence(&mut &src[..n], &mut actual, l_seq)?;
+
+            assert_eq!(actual.len(), l_seq);
+            assert_eq!(actual, expected, "l_seq = {l_seq}");
+        }
+
+        let mut src = &src[..1];
+        assert_eq!(
+            get_unmapped_sequence(&mut src, &mut actual, 4),
+            Err(DecodeError::UnexpectedEof)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_decode_base() {
         assert_eq!(decode_base(0), Base::Eq);
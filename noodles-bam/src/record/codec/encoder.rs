@@ -22,10 +22,33 @@ use noodles_sam::{self as sam, alignment::Record, record::Cigar};
 // becomes -1 in BAM) therefore use `reg2bin(-1, 0)` which is computed as 4680."
 pub(crate) const UNMAPPED_BIN: u16 = 4680;
 
-pub(crate) fn encode<B>(dst: &mut B, header: &sam::Header, record: &Record) -> io::Result<()>
+/// Encodes an alignment record using the BAM record layout.
+///
+/// This does not write the record block size (`block_size`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+/// let record = sam::alignment::Record::default();
+///
+/// let mut buf = Vec::new();
+/// bam::record::codec::encode(&mut buf, &header, &record)?;
+///
+/// let expected = bam::lazy::Record::default();
+/// assert_eq!(buf, expected.as_ref());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn encode<B>(dst: &mut B, header: &sam::Header, record: &Record) -> io::Result<()>
 where
     B: BufMut,
 {
+    record.validate()?;
+
     // ref_id
     put_reference_sequence_id(dst, header, record.reference_sequence_id())?;
 
@@ -41,7 +64,7 @@ where
     put_bin(dst, record.alignment_start(), record.alignment_end())?;
 
     // n_cigar_op
-    let cigar = overflowing_put_cigar_op_count(dst, header, record)?;
+    let cigar = overflowing_put_cigar_op_count(dst, record)?;
 
     // flag
     put_flags(dst, record.flags());
@@ -71,7 +94,7 @@ where
     let quality_scores = record.quality_scores();
 
     // seq
-    put_sequence(dst, record.cigar().read_length(), sequence)?;
+    put_sequence(dst, sequence);
 
     // qual
     put_quality_scores(dst, sequence.len(), quality_scores)?;
@@ -174,11 +197,7 @@ where
     Ok(())
 }
 
-fn overflowing_put_cigar_op_count<B>(
-    dst: &mut B,
-    header: &sam::Header,
-    record: &Record,
-) -> io::Result<Option<Cigar>>
+fn overflowing_put_cigar_op_count<B>(dst: &mut B, record: &Record) -> io::Result<Option<Cigar>>
 where
     B: BufMut,
 {
@@ -190,13 +209,10 @@ where
     } else {
         dst.put_u16_le(2);
 
+        // § 4.2.2 "`N_CIGAR_OP` field" (2022-08-22): The placeholder CIGAR is `kSmN`, where `k`
+        // is the read length and `m` is the reference length of the alignment.
         let k = record.sequence().len();
-        let m = record
-            .reference_sequence(header)
-            .ok_or_else(|| {
-                io::Error::new(io::ErrorKind::InvalidInput, "missing reference sequence")
-            })?
-            .map(|(_, rs)| rs.length().get())?;
+        let m = record.cigar().alignment_span();
 
         Cigar::try_from(vec![
             Op::new(op::Kind::SoftClip, k),
@@ -342,6 +358,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_encode_with_read_length_sequence_length_mismatch(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+        let mut buf = Vec::new();
+
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_cigar("4M".parse()?)
+            .set_sequence("ACG".parse()?)
+            .build();
+
+        assert!(matches!(
+            encode(&mut buf, &header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(buf.is_empty());
+
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_cigar("4M".parse()?)
+            .build();
+
+        buf.clear();
+        encode(&mut buf, &header, &record)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_encode_with_oversized_cigar() -> Result<(), Box<dyn std::error::Error>> {
         use sam::record::{
@@ -388,7 +433,7 @@ mod tests {
             0x00, 0x00, 0x00, 0x00, // tlen = 0
             b'*', 0x00, // read_name = "*\x00"
             0x04, 0x00, 0x10, 0x00, // cigar[0] = 65536S
-            0x03, 0x00, 0x20, 0x00, // cigar[1] = 131072N
+            0x03, 0x00, 0x10, 0x00, // cigar[1] = 65536N
         ];
 
         expected.resize(expected.len() + (base_count + 1) / 2, 0x11); // seq = [A, ...]
@@ -91,4 +91,67 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_put_value_with_sam_array_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::{
+            self as sam,
+            record::data::field::{value::Array, Tag, Type},
+        };
+
+        use crate::record::codec::decoder::data::field::get_value;
+
+        fn t(
+            raw_value: &str,
+            expected_subtype: u8,
+            expected: Array,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let tag: Tag = "ZB".parse()?;
+
+            let src = format!("*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tZB:B:{raw_value}\n");
+            let mut reader = sam::Reader::new(src.as_bytes());
+            let header = sam::Header::default();
+            let mut record = sam::alignment::Record::default();
+            reader.read_record(&header, &mut record)?;
+
+            let value = record.data().get(&tag).ok_or("missing field")?;
+            assert_eq!(value, &Value::Array(expected));
+
+            let mut buf = Vec::new();
+            put_value(&mut buf, value)?;
+            assert_eq!(buf[0], expected_subtype);
+
+            let mut src = &buf[..];
+            let actual = get_value(&mut src, Type::Array)?;
+            assert_eq!(&actual, value);
+
+            Ok(())
+        }
+
+        t("c,-128,127", b'c', Array::Int8(vec![i8::MIN, i8::MAX]))?;
+        t("C,0,255", b'C', Array::UInt8(vec![u8::MIN, u8::MAX]))?;
+        t(
+            "s,-32768,32767",
+            b's',
+            Array::Int16(vec![i16::MIN, i16::MAX]),
+        )?;
+        t("S,0,65535", b'S', Array::UInt16(vec![u16::MIN, u16::MAX]))?;
+        t(
+            "i,-2147483648,2147483647",
+            b'i',
+            Array::Int32(vec![i32::MIN, i32::MAX]),
+        )?;
+        t(
+            "I,0,4294967295",
+            b'I',
+            Array::UInt32(vec![u32::MIN, u32::MAX]),
+        )?;
+        t("f,-0.5,8.25", b'f', Array::Float(vec![-0.5, 8.25]))?;
+
+        // An array of small values keeps its subtype.
+        t("i,1,2", b'i', Array::Int32(vec![1, 2]))?;
+        t("I,1,2", b'I', Array::UInt32(vec![1, 2]))?;
+
+        Ok(())
+    }
 }
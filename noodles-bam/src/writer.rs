@@ -372,4 +372,67 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_alignment_record_with_edited_data() -> io::Result<()> {
+        use sam::record::data::field::{tag, Value};
+
+        fn round_trip(header: &sam::Header, record: &Record) -> io::Result<Record> {
+            let mut writer = Writer::new(Vec::new());
+            writer.write_alignment_record(header, record)?;
+            writer.try_finish()?;
+
+            let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+
+            let mut record = Record::default();
+            reader.read_record(header, &mut record)?;
+
+            Ok(record)
+        }
+
+        let header = sam::Header::default();
+
+        let sam_record = Record::builder()
+            .set_data(
+                [
+                    (tag::READ_GROUP, Value::String(String::from("rg0"))),
+                    (tag::ALIGNMENT_HIT_COUNT, Value::UInt8(1)),
+                    (tag::COMMENT, Value::String(String::from("noodles"))),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut record = round_trip(&header, &sam_record)?;
+        let data = record.data_mut();
+
+        assert_eq!(
+            data.remove(&tag::ALIGNMENT_HIT_COUNT),
+            Some((tag::ALIGNMENT_HIT_COUNT, Value::UInt8(1)))
+        );
+        assert!(data.remove(&tag::ALIGNMENT_HIT_COUNT).is_none());
+
+        assert!(data
+            .insert(tag::ALIGNMENT_SCORE, Value::UInt8(98))
+            .is_none());
+        assert_eq!(
+            data.insert(tag::READ_GROUP, Value::String(String::from("rg1"))),
+            Some((tag::READ_GROUP, Value::String(String::from("rg0"))))
+        );
+
+        let actual = round_trip(&header, &record)?;
+
+        let expected = [
+            (tag::READ_GROUP, Value::String(String::from("rg1"))),
+            (tag::COMMENT, Value::String(String::from("noodles"))),
+            (tag::ALIGNMENT_SCORE, Value::UInt8(98)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(actual.data(), &expected);
+
+        Ok(())
+    }
 }
@@ -37,5 +37,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         writer.write_record(&header, &record)?;
     }
 
+    writer.flush()?;
+
     Ok(())
 }
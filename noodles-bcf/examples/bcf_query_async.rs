@@ -7,7 +7,7 @@
 use std::{env, path::PathBuf};
 
 use futures::TryStreamExt;
-use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_bcf as bcf;
 use noodles_csi as csi;
 use noodles_vcf as vcf;
 use tokio::fs::File;
@@ -21,19 +21,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut reader = File::open(&src).await.map(bcf::AsyncReader::new)?;
     reader.read_file_format().await?;
-    let raw_header = reader.read_header().await?;
-
-    let header: vcf::Header = raw_header.parse()?;
-    let string_maps: StringMaps = raw_header.parse()?;
+    let header: vcf::Header = reader.read_header().await?.parse()?;
 
     let index = csi::r#async::read(src.with_extension("bcf.csi")).await?;
 
     let region = raw_region.parse()?;
-    let mut query = reader.query(string_maps.contigs(), &index, &region)?;
+    let mut query = reader.query(&header, &index, &region)?;
 
     while let Some(record) = query.try_next().await? {
-        let vcf_record = record.try_into_vcf_record(&header, &string_maps)?;
-        println!("{vcf_record}");
+        println!("{record}");
     }
 
     Ok(())
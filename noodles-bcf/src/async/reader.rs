@@ -5,10 +5,11 @@ use futures::{stream, Stream};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi as csi;
+use noodles_vcf as vcf;
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 
 use self::{lazy_record::read_lazy_record, query::query};
-use crate::{header::string_maps::ContigStringMap, lazy};
+use crate::{header::StringMaps, lazy};
 
 /// An async BCF reader.
 ///
@@ -38,6 +39,7 @@ use crate::{header::string_maps::ContigStringMap, lazy};
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    string_maps: StringMaps,
 }
 
 impl<R> Reader<R>
@@ -86,6 +88,13 @@ where
         self.inner
     }
 
+    /// Returns the string maps.
+    ///
+    /// This is only built after reading the header using [`Self::read_header`].
+    pub fn string_maps(&self) -> &StringMaps {
+        &self.string_maps
+    }
+
     /// Reads the BCF file format.
     ///
     /// The BCF magic number is also checked.
@@ -118,7 +127,8 @@ where
     /// The position of the stream is expected to be directly after the file format.
     ///
     /// This returns the raw VCF header as a [`String`]. It can subsequently be parsed as a
-    /// [`noodles_vcf::Header`].
+    /// [`noodles_vcf::Header`]. The string maps are built from the raw header and can be accessed
+    /// using [`Self::string_maps`].
     ///
     /// # Examples
     ///
@@ -138,7 +148,13 @@ where
     /// # }
     /// ```
     pub async fn read_header(&mut self) -> io::Result<String> {
-        read_header(&mut self.inner).await
+        let raw_header = read_header(&mut self.inner).await?;
+
+        self.string_maps = raw_header
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(raw_header)
     }
 
     /// Reads a single record without decoding (most of) its feilds.
@@ -290,19 +306,20 @@ where
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use futures::TryStreamExt;
-    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    /// use noodles_bcf as bcf;
     /// use noodles_core::Region;
     /// use noodles_csi as csi;
+    /// use noodles_vcf as vcf;
     /// use tokio::fs::File;
     ///
     /// let mut reader = File::open("sample.bcf").await.map(bcf::AsyncReader::new)?;
     /// reader.read_file_format().await?;
     ///
-    /// let string_maps: StringMaps = reader.read_header().await?.parse()?;
+    /// let header: vcf::Header = reader.read_header().await?.parse()?;
     ///
     /// let index = csi::r#async::read("sample.bcf.csi").await?;
     /// let region = "sq0:8-13".parse()?;
-    /// let mut query = reader.query(string_maps.contigs(), &index, &region)?;
+    /// let mut query = reader.query(&header, &index, &region)?;
     ///
     /// while let Some(record) = query.try_next().await? {
     ///     // ...
@@ -310,19 +327,22 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    pub fn query(
-        &mut self,
-        contig_string_map: &ContigStringMap,
+    pub fn query<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h vcf::Header,
         index: &csi::Index,
         region: &Region,
-    ) -> io::Result<impl Stream<Item = io::Result<lazy::Record>> + '_> {
+    ) -> io::Result<impl Stream<Item = io::Result<vcf::Record>> + 'r> {
         use crate::reader::resolve_region;
 
-        let reference_sequence_id = resolve_region(contig_string_map, region)?;
+        let reference_sequence_id = resolve_region(self.string_maps.contigs(), region)?;
         let chunks = index.query(reference_sequence_id, region.interval())?;
+        let string_maps = self.string_maps.clone();
 
         Ok(query(
             self,
+            header,
+            string_maps,
             chunks,
             reference_sequence_id,
             region.interval(),
@@ -335,6 +355,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            string_maps: StringMaps::default(),
         }
     }
 }
@@ -399,6 +420,92 @@ fn c_str_to_string(buf: &[u8]) -> io::Result<String> {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use futures::TryStreamExt;
+        use noodles_core::Position;
+        use vcf::{
+            header::record::value::{map::Contig, Map},
+            record::Position as VcfPosition,
+        };
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let records = [("sq0", 1), ("sq0", 8), ("sq0", 13), ("sq0", 21), ("sq1", 8)]
+            .into_iter()
+            .map(|(chromosome, position)| {
+                vcf::Record::builder()
+                    .set_chromosome(chromosome.parse()?)
+                    .set_position(VcfPosition::from(position))
+                    .set_reference_bases("A".parse()?)
+                    .build()
+                    .map_err(Box::<dyn std::error::Error>::from)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.get_ref().get_ref().clone();
+
+        let mut reader = crate::Reader::new(&data[..]);
+        reader.read_header()?;
+
+        let mut indexer = csi::index::Indexer::default();
+        let mut record = vcf::Record::default();
+        let mut start_position = reader.virtual_position();
+
+        while reader.read_record(&header, &mut record)? != 0 {
+            let end_position = reader.virtual_position();
+            let chunk =
+                csi::index::reference_sequence::bin::Chunk::new(start_position, end_position);
+
+            let reference_sequence_id = header
+                .contigs()
+                .get_index_of(record.chromosome().to_string().as_str())
+                .expect("missing contig");
+            let start = Position::try_from(usize::from(record.position()))?;
+            let end = record.end().map(usize::from).map(Position::try_from)??;
+
+            indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+
+            start_position = end_position;
+        }
+
+        let index = indexer.build(header.contigs().len());
+        let region = "sq0:8-13".parse()?;
+
+        let mut reader = crate::Reader::new(Cursor::new(&data));
+        reader.read_header()?;
+        let expected: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(expected, records[1..3]);
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        reader.read_file_format().await?;
+        reader.read_header().await?;
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .try_collect()
+            .await?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_magic() {
         let data = b"BCF";
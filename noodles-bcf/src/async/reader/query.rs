@@ -4,10 +4,14 @@ use futures::{stream, Stream};
 use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Position};
 use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_vcf as vcf;
 use tokio::io::{self, AsyncRead, AsyncSeek};
 
 use super::Reader;
-use crate::lazy::{self, record::ChromosomeId};
+use crate::{
+    header::StringMaps,
+    lazy::{self, record::ChromosomeId},
+};
 
 enum State {
     Seek,
@@ -15,11 +19,14 @@ enum State {
     Done,
 }
 
-struct Context<'a, R>
+struct Context<'r, 'h, R>
 where
     R: AsyncRead + AsyncSeek,
 {
-    reader: &'a mut Reader<bgzf::AsyncReader<R>>,
+    reader: &'r mut Reader<bgzf::AsyncReader<R>>,
+
+    header: &'h vcf::Header,
+    string_maps: StringMaps,
 
     chunks: vec::IntoIter<Chunk>,
 
@@ -29,18 +36,23 @@ where
     state: State,
 }
 
-pub fn query<R>(
-    reader: &mut Reader<bgzf::AsyncReader<R>>,
+pub fn query<'r, 'h: 'r, R>(
+    reader: &'r mut Reader<bgzf::AsyncReader<R>>,
+    header: &'h vcf::Header,
+    string_maps: StringMaps,
     chunks: Vec<Chunk>,
     chromosome_id: ChromosomeId,
     interval: Interval,
-) -> impl Stream<Item = io::Result<lazy::Record>> + '_
+) -> impl Stream<Item = io::Result<vcf::Record>> + 'r
 where
     R: AsyncRead + AsyncSeek + Unpin,
 {
     let ctx = Context {
         reader,
 
+        header,
+        string_maps,
+
         chunks: chunks.into_iter(),
 
         chromosome_id,
@@ -68,6 +80,9 @@ where
                         }
 
                         if intersects(&record, ctx.chromosome_id, ctx.interval)? {
+                            let record =
+                                record.try_into_vcf_record(ctx.header, &ctx.string_maps)?;
+
                             return Ok(Some((record, ctx)));
                         }
                     }
@@ -2,7 +2,10 @@
 
 mod string_map;
 
-use std::str::{FromStr, Lines};
+use std::{
+    error, fmt,
+    str::{FromStr, Lines},
+};
 
 use noodles_vcf::{
     self as vcf,
@@ -98,6 +101,159 @@ impl StringMaps {
     fn contigs_mut(&mut self) -> &mut ContigStringMap {
         &mut self.contig_string_map
     }
+
+    /// Validates that the string maps are consistent with the given header.
+    ///
+    /// Every contig and every FILTER, FORMAT, and INFO ID in the header must be in the string
+    /// maps. If a header record has an explicit position (`IDX`), the entry must be at that
+    /// index.
+    ///
+    /// This is useful to check that string maps built from one header can still be used to
+    /// encode and decode records after the header is edited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::{string_maps::ValidateError, StringMaps};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Info, Map},
+    ///     record::info::field::key,
+    /// };
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::try_from(&header)?;
+    /// assert!(string_maps.validate(&header).is_ok());
+    ///
+    /// header
+    ///     .infos_mut()
+    ///     .insert(key::SAMPLES_WITH_DATA_COUNT, Map::<Info>::from(&key::SAMPLES_WITH_DATA_COUNT));
+    ///
+    /// assert_eq!(
+    ///     string_maps.validate(&header),
+    ///     Err(ValidateError::MissingString(String::from("NS")))
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate(&self, header: &vcf::Header) -> Result<(), ValidateError> {
+        for (id, contig) in header.contigs() {
+            validate_entry(self.contigs(), id.as_ref(), contig.idx()).map_err(|e| match e {
+                EntryError::Missing => ValidateError::MissingContig(id.to_string()),
+                EntryError::IndexMismatch { expected, actual } => {
+                    ValidateError::ContigIndexMismatch {
+                        id: id.to_string(),
+                        expected,
+                        actual,
+                    }
+                }
+            })?;
+        }
+
+        let string_ids = header
+            .infos()
+            .iter()
+            .map(|(id, info)| (id.as_ref(), info.idx()))
+            .chain(
+                header
+                    .filters()
+                    .iter()
+                    .map(|(id, filter)| (id.as_str(), filter.idx())),
+            )
+            .chain(
+                header
+                    .formats()
+                    .iter()
+                    .map(|(id, format)| (id.as_ref(), format.idx())),
+            );
+
+        for (id, idx) in string_ids {
+            validate_entry(self.strings(), id, idx).map_err(|e| match e {
+                EntryError::Missing => ValidateError::MissingString(id.into()),
+                EntryError::IndexMismatch { expected, actual } => {
+                    ValidateError::StringIndexMismatch {
+                        id: id.into(),
+                        expected,
+                        actual,
+                    }
+                }
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when string maps are inconsistent with a VCF header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidateError {
+    /// A FILTER, FORMAT, or INFO ID is missing from the string string map.
+    MissingString(String),
+    /// A FILTER, FORMAT, or INFO ID is at a different position in the string string map than the
+    /// header record position (`IDX`).
+    StringIndexMismatch {
+        /// The ID.
+        id: String,
+        /// The header record position.
+        expected: usize,
+        /// The string map position.
+        actual: usize,
+    },
+    /// A contig name is missing from the contig string map.
+    MissingContig(String),
+    /// A contig name is at a different position in the contig string map than the header record
+    /// position (`IDX`).
+    ContigIndexMismatch {
+        /// The contig name.
+        id: String,
+        /// The header record position.
+        expected: usize,
+        /// The string map position.
+        actual: usize,
+    },
+}
+
+impl error::Error for ValidateError {}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingString(id) => write!(f, "missing string map entry: {id}"),
+            Self::StringIndexMismatch {
+                id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "string map position mismatch for {id}: expected {expected}, got {actual}"
+            ),
+            Self::MissingContig(id) => write!(f, "missing contig string map entry: {id}"),
+            Self::ContigIndexMismatch {
+                id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "contig string map position mismatch for {id}: expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+enum EntryError {
+    Missing,
+    IndexMismatch { expected: usize, actual: usize },
+}
+
+fn validate_entry(string_map: &StringMap, id: &str, idx: Option<usize>) -> Result<(), EntryError> {
+    let actual = string_map.get_index_of(id).ok_or(EntryError::Missing)?;
+
+    match idx {
+        Some(expected) if actual != expected => Err(EntryError::IndexMismatch { expected, actual }),
+        _ => Ok(()),
+    }
 }
 
 impl Default for StringMaps {
@@ -553,6 +709,68 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::{
+                map::{Contig, Filter, Info},
+                Map,
+            },
+            record::info,
+        };
+
+        let mut header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_info(
+                info::field::key::TOTAL_DEPTH,
+                Map::<Info>::from(&info::field::key::TOTAL_DEPTH),
+            )
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+        assert!(string_maps.validate(&header).is_ok());
+
+        header.infos_mut().insert(
+            info::field::key::SAMPLES_WITH_DATA_COUNT,
+            Map::<Info>::from(&info::field::key::SAMPLES_WITH_DATA_COUNT),
+        );
+
+        assert_eq!(
+            string_maps.validate(&header),
+            Err(ValidateError::MissingString(String::from("NS")))
+        );
+
+        header
+            .infos_mut()
+            .remove(&info::field::key::SAMPLES_WITH_DATA_COUNT);
+        header
+            .contigs_mut()
+            .insert("sq1".parse()?, Map::<Contig>::new());
+
+        assert_eq!(
+            string_maps.validate(&header),
+            Err(ValidateError::MissingContig(String::from("sq1")))
+        );
+
+        header.contigs_mut().remove("sq1");
+
+        if let Some(dp) = header.infos_mut().get_mut(&info::field::key::TOTAL_DEPTH) {
+            *dp.idx_mut() = Some(2);
+        }
+
+        assert_eq!(
+            string_maps.validate(&header),
+            Err(ValidateError::StringIndexMismatch {
+                id: String::from("DP"),
+                expected: 2,
+                actual: 1,
+            })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_file_format() {
         use vcf::header::FileFormat;
@@ -13,6 +13,8 @@ use std::io;
 
 use noodles_vcf as vcf;
 
+use crate::header::string_maps::StringStringMap;
+
 /// A chromosome ID.
 pub type ChromosomeId = usize;
 
@@ -118,6 +120,69 @@ impl Record {
         Ok(Position::from(end))
     }
 
+    /// Validates the reference length (`rlen`) against the INFO end position (`END`) field.
+    ///
+    /// When set, the `END` field must be the same as the end position calculated from the start
+    /// position and the reference length. This is not checked if the field is missing or has a
+    /// missing value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let record = bcf::lazy::Record::default();
+    /// let header = vcf::Header::default();
+    /// let string_maps = bcf::header::StringMaps::default();
+    ///
+    /// record.validate_end(&header, string_maps.strings())?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn validate_end(
+        &self,
+        header: &vcf::Header,
+        string_string_map: &StringStringMap,
+    ) -> io::Result<()> {
+        use vcf::record::info::field::{key, Value};
+
+        let value = match self
+            .info()
+            .get(header, string_string_map, &key::END_POSITION)
+            .transpose()?
+        {
+            Some(Some(value)) => value,
+            Some(None) | None => return Ok(()),
+        };
+
+        let info_end = match value {
+            Value::Integer(n) => {
+                usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid INFO end position (`END`) field value type",
+                ))
+            }
+        };
+
+        let end = usize::from(self.end()?);
+
+        if info_end == end {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "INFO end position (`END`) ({info_end}) does not match the end position \
+                     calculated from the reference length ({end})"
+                ),
+            ))
+        }
+    }
+
     /// Returns the quality score.
     ///
     /// # Examples
@@ -259,3 +324,49 @@ impl Default for Record {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_end() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::{map, Map},
+            record::info::field::key,
+        };
+
+        use crate::header::StringMaps;
+
+        let header = vcf::Header::builder()
+            .add_info(
+                key::END_POSITION,
+                Map::<map::Info>::from(&key::END_POSITION),
+            )
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let mut record = Record::default();
+        *record.info_mut() = Info::new(
+            vec![
+                0x11, 0x01, 0x11, 0x08, // END=8
+            ],
+            1,
+        );
+
+        *record.rlen_mut() = 8;
+        assert!(record.validate_end(&header, string_maps.strings()).is_ok());
+
+        *record.rlen_mut() = 5;
+        assert!(matches!(
+            record.validate_end(&header, string_maps.strings()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        *record.info_mut() = Info::default();
+        assert!(record.validate_end(&header, string_maps.strings()).is_ok());
+
+        Ok(())
+    }
+}
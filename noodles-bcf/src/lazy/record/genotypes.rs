@@ -13,6 +13,49 @@ pub struct Genotypes {
 }
 
 impl Genotypes {
+    /// Converts VCF record genotypes to BCF record genotypes.
+    ///
+    /// Each field is encoded with a type descriptor, followed by the values of every sample,
+    /// padded to the same length. Genotype (`GT`) values are encoded as allele indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{header::string_maps::StringMap, lazy::record::Genotypes};
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::default();
+    /// let string_maps = StringMap::default();
+    /// let vcf_genotypes = vcf::record::Genotypes::default();
+    ///
+    /// let bcf_genotypes =
+    ///     Genotypes::try_from_vcf_record_genotypes(&header, &string_maps, &vcf_genotypes)?;
+    ///
+    /// assert!(bcf_genotypes.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn try_from_vcf_record_genotypes(
+        header: &vcf::Header,
+        string_map: &StringStringMap,
+        genotypes: &vcf::record::Genotypes,
+    ) -> io::Result<Self> {
+        use crate::writer::record::genotypes::write_genotypes;
+
+        if genotypes.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut buf = Vec::new();
+        write_genotypes(&mut buf, header, string_map, genotypes)?;
+
+        Ok(Self {
+            buf,
+            format_count: genotypes.keys().len(),
+            sample_count: genotypes.values().count(),
+        })
+    }
+
     /// Converts BCF record genotypes to VCF record genotypes.
     ///
     /// # Examples
@@ -46,7 +89,7 @@ impl Genotypes {
 
         let genotypes = read_genotypes(
             &mut reader,
-            header.formats(),
+            header,
             string_map,
             self.len(),
             self.format_count(),
@@ -132,3 +175,83 @@ impl AsMut<Vec<u8>> for Genotypes {
         &mut self.buf
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_vcf_record_genotypes() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::Map,
+            record::genotypes::{keys::key, sample::Value, Keys},
+        };
+
+        use crate::header::StringMaps;
+
+        let header = vcf::Header::builder()
+            .add_format(key::GENOTYPE, Map::from(&key::GENOTYPE))
+            .add_format(
+                key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::from(&key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_format(key::READ_DEPTH, Map::from(&key::READ_DEPTH))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let vcf_genotypes = vcf::record::Genotypes::new(
+            Keys::try_from(vec![
+                key::GENOTYPE,
+                key::CONDITIONAL_GENOTYPE_QUALITY,
+                key::READ_DEPTH,
+            ])?,
+            vec![
+                vec![
+                    Some(Value::from("0/0")),
+                    Some(Value::from(10)),
+                    Some(Value::from(32)),
+                ],
+                vec![
+                    Some(Value::from("0|1")),
+                    Some(Value::from(10)),
+                    Some(Value::from(48)),
+                ],
+                vec![Some(Value::from("1")), None, Some(Value::from(64))],
+            ],
+        );
+
+        let genotypes = Genotypes::try_from_vcf_record_genotypes(
+            &header,
+            string_maps.strings(),
+            &vcf_genotypes,
+        )?;
+
+        assert_eq!(genotypes.len(), 3);
+        assert_eq!(genotypes.format_count(), 3);
+
+        let expected = [
+            0x11, 0x01, // string string map index = 1 (GT)
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x02, // 0/0
+            0x02, 0x05, // 0|1
+            0x04, 0x81, // 1 (end of vector)
+            0x11, 0x02, // string string map index = 2 (GQ)
+            0x11, // Some(Type::Int8(1))
+            0x0a, 0x0a, 0x80, // [Some(10), Some(10), None]
+            0x11, 0x03, // string string map index = 3 (DP)
+            0x11, // Some(Type::Int8(1))
+            0x20, 0x30, 0x40, // [Some(32), Some(48), Some(64)]
+        ];
+
+        assert_eq!(genotypes.as_ref(), expected);
+
+        let actual = genotypes.try_into_vcf_record_genotypes(&header, string_maps.strings())?;
+        assert_eq!(actual, vcf_genotypes);
+
+        Ok(())
+    }
+}
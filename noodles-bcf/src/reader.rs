@@ -113,6 +113,41 @@ where
         Ok(header)
     }
 
+    /// Reads the raw VCF header.
+    ///
+    /// The BCF magic number is checked, and the file format version is discarded.
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// This returns the raw VCF header text without parsing it as a [`vcf::Header`], e.g., to
+    /// copy it verbatim. The string maps are still built from the raw header and can be accessed
+    /// using [`Self::string_maps`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bcf as bcf;
+    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
+    /// let raw_header = reader.read_raw_header()?;
+    /// let string_maps = reader.string_maps();
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_raw_header(&mut self) -> io::Result<String> {
+        use self::header::read_raw_header;
+
+        read_magic(&mut self.inner)?;
+        read_format_version(&mut self.inner)?;
+
+        let raw_header = read_raw_header(&mut self.inner)?;
+
+        self.string_maps = raw_header
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(raw_header)
+    }
+
     /// Reads a single record.
     ///
     /// The stream is expected to be directly after the header or at the start of another record.
@@ -327,8 +362,9 @@ where
         let chunks = index.query(reference_sequence_id, region.interval())?;
 
         Ok(Query::new(
-            self.get_mut(),
+            &mut self.inner,
             header,
+            self.string_maps.clone(),
             chunks,
             reference_sequence_id,
             region.interval(),
@@ -409,6 +445,36 @@ pub(crate) fn resolve_region(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_raw_header() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::record::value::{map::Contig, Map};
+
+        use crate::Writer;
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .build();
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_header(&header)?;
+        let data = writer.get_ref();
+
+        let mut reader = Reader::from(&data[..]);
+        let raw_header = reader.read_raw_header()?;
+
+        // magic number (3) + format version (2) + l_text (4)
+        let text = &data[9..data.len() - 1];
+        assert_eq!(raw_header.as_bytes(), text);
+
+        let actual: vcf::Header = raw_header.parse()?;
+        assert_eq!(actual, header);
+
+        assert_eq!(reader.string_maps(), &StringMaps::try_from(&header)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_magic() {
         let data = b"BCF";
@@ -29,12 +29,17 @@ where
     pub(super) fn new(
         reader: &'r mut bgzf::Reader<R>,
         header: &'h vcf::Header,
+        string_maps: StringMaps,
         chunks: Vec<Chunk>,
         chromosome_id: usize,
         interval: Interval,
     ) -> Self {
         Self {
-            reader: Reader::from(csi::io::Query::new(reader, chunks)),
+            reader: Reader {
+                inner: csi::io::Query::new(reader, chunks),
+                buf: Vec::new(),
+                string_maps,
+            },
             header,
             chromosome_id,
             interval,
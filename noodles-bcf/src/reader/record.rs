@@ -49,13 +49,8 @@ where
     reader.read_exact(buf)?;
     let mut src = &buf[..];
 
-    *record.genotypes_mut() = read_genotypes(
-        &mut src,
-        header.formats(),
-        string_maps.strings(),
-        n_sample,
-        n_fmt,
-    )?;
+    *record.genotypes_mut() =
+        read_genotypes(&mut src, header, string_maps.strings(), n_sample, n_fmt)?;
 
     Ok(l_shared + l_indiv)
 }
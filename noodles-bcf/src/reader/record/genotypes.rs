@@ -26,7 +26,7 @@ use crate::{
 
 pub fn read_genotypes<R>(
     reader: &mut R,
-    formats: &vcf::header::Formats,
+    header: &vcf::Header,
     string_map: &StringStringMap,
     sample_count: usize,
     format_count: usize,
@@ -38,7 +38,7 @@ where
     let mut values = vec![Vec::new(); sample_count];
 
     for _ in 0..format_count {
-        let key = read_genotype_field_key(reader, formats, string_map)?;
+        let key = read_genotype_field_key(reader, header.formats(), string_map)?;
 
         let vs = if key == key::GENOTYPE {
             read_genotype_genotype_field_values(reader, sample_count)?
@@ -53,7 +53,8 @@ where
         }
     }
 
-    let keys = Keys::try_from(keys).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let keys = Keys::try_from_keys(header.file_format(), keys)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
     Ok(Genotypes::new(keys, values))
 }
@@ -76,10 +77,8 @@ where
             })
         })
         .and_then(|raw_key| {
-            formats
-                .keys()
-                .find(|k| k.as_ref() == raw_key)
-                .cloned()
+            vcf::header::lookup(formats, raw_key)
+                .map(|(key, _)| key.clone())
                 .ok_or_else(|| {
                     io::Error::new(
                         io::ErrorKind::InvalidData,
@@ -403,44 +402,97 @@ where
     let mut values = Vec::with_capacity(sample_count);
 
     match read_type(reader)? {
-        Some(Type::Int8(len)) => match len {
-            0 => values.push(None),
-            1 => {
-                for _ in 0..sample_count {
-                    let value = reader
-                        .read_i8()
-                        .map(|v| parse_genotype_genotype_field_values(&[v]))
-                        .map(Value::from)?;
-
-                    values.push(Some(value));
+        Some(Type::Int8(0) | Type::Int16(0) | Type::Int32(0)) => values.resize(sample_count, None),
+        Some(Type::Int8(len)) => {
+            let mut buf = vec![0; len];
+            let missing = i8::from(Int8::Missing);
+
+            for _ in 0..sample_count {
+                reader.read_i8_into(&mut buf)?;
+
+                if buf[0] == missing {
+                    values.push(None);
+                    continue;
                 }
+
+                let value = Value::from(parse_genotype_genotype_field_values(&buf));
+                values.push(Some(value));
             }
-            _ => {
-                let mut buf = vec![0; len];
+        }
+        Some(Type::Int16(len)) => {
+            let mut buf = vec![0; len];
+            let (missing, end_of_vector) =
+                (i16::from(Int16::Missing), i16::from(Int16::EndOfVector));
 
-                for _ in 0..sample_count {
-                    reader.read_i8_into(&mut buf)?;
-                    let value = Value::from(parse_genotype_genotype_field_values(&buf));
-                    values.push(Some(value));
+            for _ in 0..sample_count {
+                reader.read_i16_into::<LittleEndian>(&mut buf)?;
+
+                if buf[0] == missing {
+                    values.push(None);
+                    continue;
                 }
+
+                let genotype = format_genotype(
+                    buf.iter()
+                        .take_while(|&&n| n != end_of_vector)
+                        .map(|&n| i32::from(n)),
+                );
+
+                values.push(Some(Value::from(genotype)));
             }
-        },
-        ty => todo!("unhandled type: {:?}", ty),
+        }
+        Some(Type::Int32(len)) => {
+            let mut buf = vec![0; len];
+            let (missing, end_of_vector) =
+                (i32::from(Int32::Missing), i32::from(Int32::EndOfVector));
+
+            for _ in 0..sample_count {
+                reader.read_i32_into::<LittleEndian>(&mut buf)?;
+
+                if buf[0] == missing {
+                    values.push(None);
+                    continue;
+                }
+
+                let genotype =
+                    format_genotype(buf.iter().copied().take_while(|&n| n != end_of_vector));
+
+                values.push(Some(Value::from(genotype)));
+            }
+        }
+        ty => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid genotype (GT) type: {ty:?}"),
+            ))
+        }
     }
 
     Ok(values)
 }
 
 fn parse_genotype_genotype_field_values(values: &[i8]) -> String {
+    let end_of_vector = i8::from(Int8::EndOfVector);
+
+    format_genotype(
+        values
+            .iter()
+            .take_while(|&&n| n != end_of_vector)
+            .map(|&n| i32::from(n)),
+    )
+}
+
+// Each value is an allele index (+ 1) shifted left by one bit, with the lowest bit set if it is
+// phased with the previous allele. `0` is a missing allele.
+fn format_genotype<I>(values: I) -> String
+where
+    I: IntoIterator<Item = i32>,
+{
     use std::fmt::Write;
 
     let mut genotype = String::new();
 
-    for (i, &value) in values.iter().enumerate() {
-        if let Int8::EndOfVector = Int8::from(value) {
-            break;
-        }
-
+    for (i, value) in values.into_iter().enumerate() {
         let j = (value >> 1) - 1;
         let is_phased = value & 0x01 == 1;
 
@@ -466,6 +518,162 @@ fn parse_genotype_genotype_field_values(values: &[i8]) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_genotypes() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::record::value::Map;
+
+        use crate::header::StringMaps;
+
+        let header = vcf::Header::builder()
+            .add_format(key::GENOTYPE, Map::from(&key::GENOTYPE))
+            .add_format(
+                key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::from(&key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_format(key::READ_DEPTH, Map::from(&key::READ_DEPTH))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let data = [
+            0x11, 0x01, // string string map index = 1 (GT)
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x04, // 0/1
+            0x02, 0x05, // 0|1
+            0x00, 0x00, // ./.
+            0x11, 0x02, // string string map index = 2 (GQ)
+            0x11, // Some(Type::Int8(1))
+            0x0d, // Some(13)
+            0x15, // Some(21)
+            0x80, // None
+            0x11, 0x03, // string string map index = 3 (DP)
+            0x12, // Some(Type::Int16(1))
+            0x05, 0x00, // Some(5)
+            0x00, 0x80, // None
+            0x2c, 0x01, // Some(300)
+        ];
+
+        let mut reader = &data[..];
+        let actual = read_genotypes(&mut reader, &header, string_maps.strings(), 3, 3)?;
+
+        let expected = Genotypes::new(
+            Keys::try_from(vec![
+                key::GENOTYPE,
+                key::CONDITIONAL_GENOTYPE_QUALITY,
+                key::READ_DEPTH,
+            ])?,
+            vec![
+                vec![
+                    Some(Value::from("0/1")),
+                    Some(Value::from(13)),
+                    Some(Value::from(5)),
+                ],
+                vec![Some(Value::from("0|1")), Some(Value::from(21)), None],
+                vec![Some(Value::from("./.")), None, Some(Value::from(300))],
+            ],
+        );
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_genotypes_with_genotype_key_not_first() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::{record::value::Map, FileFormat};
+
+        use crate::header::StringMaps;
+
+        let build_header = |file_format| {
+            vcf::Header::builder()
+                .set_file_format(file_format)
+                .add_format(key::GENOTYPE, Map::from(&key::GENOTYPE))
+                .add_format(
+                    key::CONDITIONAL_GENOTYPE_QUALITY,
+                    Map::from(&key::CONDITIONAL_GENOTYPE_QUALITY),
+                )
+                .add_sample_name("sample0")
+                .build()
+        };
+
+        let data = [
+            0x11, 0x02, // string string map index = 2 (GQ)
+            0x11, // Some(Type::Int8(1))
+            0x0d, // Some(13)
+            0x11, 0x01, // string string map index = 1 (GT)
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x04, // 0/1
+        ];
+
+        let header = build_header(FileFormat::new(4, 4));
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let mut reader = &data[..];
+        let actual = read_genotypes(&mut reader, &header, string_maps.strings(), 1, 2)?;
+
+        let expected = Genotypes::new(
+            Keys::try_from_keys(
+                FileFormat::new(4, 4),
+                vec![key::CONDITIONAL_GENOTYPE_QUALITY, key::GENOTYPE],
+            )?,
+            vec![vec![Some(Value::from(13)), Some(Value::from("0/1"))]],
+        );
+
+        assert_eq!(actual, expected);
+
+        let header = build_header(FileFormat::new(4, 3));
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            read_genotypes(&mut reader, &header, string_maps.strings(), 1, 2),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_genotype_genotype_field_values() -> io::Result<()> {
+        let data = [
+            0x22, // Some(Type::Int16(2))
+            0x02, 0x00, 0x04, 0x01, // 0/129
+            0x05, 0x00, 0x01, 0x80, // 1 (end of vector)
+        ];
+        let mut reader = &data[..];
+
+        let actual = read_genotype_genotype_field_values(&mut reader, 2)?;
+        let expected = vec![Some(Value::from("0/129")), Some(Value::from("1"))];
+        assert_eq!(actual, expected);
+
+        let data = [
+            0x23, // Some(Type::Int32(2))
+            0x02, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, // 0|1
+        ];
+        let mut reader = &data[..];
+
+        let actual = read_genotype_genotype_field_values(&mut reader, 1)?;
+        assert_eq!(actual, vec![Some(Value::from("0|1"))]);
+
+        let data = [0x01]; // Some(Type::Int8(0))
+        let mut reader = &data[..];
+        let actual = read_genotype_genotype_field_values(&mut reader, 3)?;
+        assert_eq!(actual, vec![None, None, None]);
+
+        let data = [0x07]; // Some(Type::String(0))
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_genotype_genotype_field_values(&mut reader, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_genotype_field_values_with_int8_values() -> io::Result<()> {
         let data = [
@@ -85,10 +85,8 @@ where
             })
         })
         .and_then(|raw_key| {
-            infos
-                .keys()
-                .find(|k| k.as_ref() == raw_key)
-                .cloned()
+            vcf::header::lookup(infos, raw_key)
+                .map(|(key, _)| key.clone())
                 .ok_or_else(|| {
                     io::Error::new(
                         io::ErrorKind::InvalidData,
@@ -1,4 +1,4 @@
-mod record;
+pub(crate) mod record;
 mod string_map;
 mod value;
 
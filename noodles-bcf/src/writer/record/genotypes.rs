@@ -693,60 +693,128 @@ fn write_genotype_genotype_field_values<W>(
 where
     W: Write,
 {
-    let genotypes: Vec<_> = values
-        .iter()
-        .map(|v| match v {
-            Some(Value::String(s)) => Ok(s),
-            _ => Err(io::Error::from(io::ErrorKind::InvalidInput)),
-        })
-        .collect::<Result<_, _>>()?;
-
-    let mut raw_values = Vec::with_capacity(genotypes.len());
+    let mut raw_values = Vec::with_capacity(values.len());
     let mut max_len = 0;
+    let mut max_value = 0;
+
+    for value in values {
+        let raw_value = match value {
+            Some(Value::String(genotype)) => encode_genotype_genotype_field_values(genotype)?,
+            Some(v) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid genotype (GT) value: {v:?}"),
+                ))
+            }
+            None => Vec::new(),
+        };
 
-    for genotype in genotypes {
-        let raw_value = encode_genotype_genotype_field_values(genotype)?;
         max_len = cmp::max(max_len, raw_value.len());
+        max_value = raw_value.iter().copied().fold(max_value, cmp::max);
         raw_values.push(raw_value);
     }
 
-    write_type(writer, Some(Type::Int8(max_len)))?;
+    // A missing genotype is written as a single missing value.
+    max_len = cmp::max(max_len, 1);
 
-    for raw_value in raw_values {
-        let len = raw_value.len();
-        let pad = max_len - len;
+    if max_value <= i32::from(Int8::MAX_VALUE) {
+        write_type(writer, Some(Type::Int8(max_len)))?;
 
-        for n in raw_value {
-            let m = u8::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-            writer.write_all(&[m])?;
+        for raw_value in &raw_values {
+            write_genotype_genotype_field_value(
+                raw_value,
+                max_len,
+                i32::from(i8::from(Int8::Missing)),
+                i32::from(i8::from(Int8::EndOfVector)),
+                |n| writer.write_i8(n as i8),
+            )?;
+        }
+    } else if max_value <= i32::from(Int16::MAX_VALUE) {
+        write_type(writer, Some(Type::Int16(max_len)))?;
+
+        for raw_value in &raw_values {
+            write_genotype_genotype_field_value(
+                raw_value,
+                max_len,
+                i32::from(i16::from(Int16::Missing)),
+                i32::from(i16::from(Int16::EndOfVector)),
+                |n| writer.write_i16::<LittleEndian>(n as i16),
+            )?;
+        }
+    } else {
+        write_type(writer, Some(Type::Int32(max_len)))?;
+
+        for raw_value in &raw_values {
+            write_genotype_genotype_field_value(
+                raw_value,
+                max_len,
+                i32::from(Int32::Missing),
+                i32::from(Int32::EndOfVector),
+                |n| writer.write_i32::<LittleEndian>(n),
+            )?;
+        }
+    }
 
-            for _ in 0..pad {
-                writer.write_all(&[i8::from(Int8::EndOfVector) as u8])?;
-            }
+    Ok(())
+}
+
+fn write_genotype_genotype_field_value<F>(
+    raw_value: &[i32],
+    len: usize,
+    missing: i32,
+    end_of_vector: i32,
+    mut write: F,
+) -> io::Result<()>
+where
+    F: FnMut(i32) -> io::Result<()>,
+{
+    if raw_value.is_empty() {
+        write(missing)?;
+
+        for _ in 1..len {
+            write(end_of_vector)?;
+        }
+    } else {
+        for &n in raw_value {
+            write(n)?;
+        }
+
+        for _ in raw_value.len()..len {
+            write(end_of_vector)?;
         }
     }
 
     Ok(())
 }
 
-fn encode_genotype_genotype_field_values(genotype: &str) -> io::Result<Vec<i8>> {
+fn encode_genotype_genotype_field_values(genotype: &str) -> io::Result<Vec<i32>> {
     const MISSING_ALLELE: &str = ".";
 
     fn is_phasing(c: char) -> bool {
         matches!(c, '|' | '/')
     }
 
-    fn encode(s: &str, phasing: &str) -> io::Result<i8> {
+    fn encode(s: &str, phasing: &str) -> io::Result<i32> {
         if s == MISSING_ALLELE {
             return Ok(0);
         }
 
-        let j: i8 = s
+        let j: i32 = s
             .parse()
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
         let is_phased = phasing == "|";
 
-        let mut i = (j + 1) << 1;
+        let mut i = j
+            .checked_add(1)
+            .filter(|&n| n > 0 && n <= Int32::MAX_VALUE >> 1)
+            .map(|n| n << 1)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid allele index: {j}"),
+                )
+            })?;
 
         if is_phased {
             i |= 0x01;
@@ -1373,6 +1441,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_genotype_genotype_field_values_with_missing_and_wide_values() -> io::Result<()> {
+        let value_0 = Value::from("0/1/2");
+        let values = [Some(&value_0), None];
+
+        let mut buf = Vec::new();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x31, // Some(Type::Int8(3))
+            0x02, 0x04, 0x06, // "0/1/2"
+            0x80, 0x81, 0x81, // None
+        ];
+
+        assert_eq!(buf, expected);
+
+        let value_0 = Value::from("0/128");
+        let value_1 = Value::from("1");
+        let values = [Some(&value_0), Some(&value_1)];
+
+        buf.clear();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x22, // Some(Type::Int16(2))
+            0x02, 0x00, 0x02, 0x01, // "0/128"
+            0x04, 0x00, 0x01, 0x80, // "1"
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_encode_genotype_genotype_field_values() -> io::Result<()> {
         assert_eq!(encode_genotype_genotype_field_values("0/1")?, [0x02, 0x04]);
@@ -1389,6 +1491,11 @@ mod tests {
             [0x02, 0x04, 0x07]
         );
 
+        assert!(matches!(
+            encode_genotype_genotype_field_values("0/-2"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
         Ok(())
     }
 }
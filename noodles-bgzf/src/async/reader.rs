@@ -211,6 +211,30 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_with_concatenated_streams() -> io::Result<()> {
+        use crate::Writer;
+
+        fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+            use std::io::Write;
+
+            let mut writer = Writer::new(Vec::new());
+            writer.write_all(data)?;
+            writer.finish()
+        }
+
+        let mut data = compress(b"noodles")?;
+        data.extend(compress(b"bgzf")?);
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+
+        assert_eq!(buf, b"noodlesbgzf");
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
         #[rustfmt::skip]
@@ -30,6 +30,13 @@ impl Block {
         self.size = size;
     }
 
+    /// Resets the block to its default state while keeping the data buffer allocation.
+    pub fn clear(&mut self) {
+        self.pos = 0;
+        self.size = 0;
+        self.data.clear();
+    }
+
     /// Returns the virtual position at the current position in the uncompressed data stream.
     pub fn virtual_position(&self) -> VirtualPosition {
         if self.data.has_remaining() {
@@ -27,6 +27,12 @@ impl Data {
         self.buf.len()
     }
 
+    /// Clears the buffer and resets the cursor position.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.pos = 0;
+    }
+
     pub fn resize(&mut self, len: usize) {
         self.buf.resize(len, 0);
     }
@@ -55,6 +61,18 @@ impl AsMut<[u8]> for Data {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_clear() {
+        let mut data = Data::default();
+        data.resize(5);
+        data.set_position(2);
+
+        data.clear();
+
+        assert_eq!(data.len(), 0);
+        assert_eq!(data.position(), 0);
+    }
+
     #[test]
     fn test_has_remaining() {
         let data = Data::default();
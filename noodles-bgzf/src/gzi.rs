@@ -8,15 +8,16 @@
 pub mod r#async;
 
 mod reader;
+mod writer;
 
-pub use self::reader::Reader;
+pub use self::{reader::Reader, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::Reader as AsyncReader;
 
 use std::{
     fs::File,
-    io::{self, BufReader},
+    io::{self, BufReader, BufWriter, Write},
     path::Path,
 };
 
@@ -43,3 +44,72 @@ where
     let mut reader = File::open(src).map(BufReader::new).map(Reader::new)?;
     reader.read_index()
 }
+
+/// Writes a gzip index to a file.
+///
+/// This is a convenience function and is equivalent to creating a file at the given path and
+/// writing the index.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_bgzf::gzi;
+/// let index = vec![(0, 0)];
+/// gzi::write("out.gz.gzi", &index)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write<P>(dst: P, index: &Index) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let mut writer = File::create(dst).map(BufWriter::new).map(Writer::new)?;
+    writer.write_index(index)?;
+    writer.get_mut().flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use super::*;
+    use crate as bgzf;
+
+    #[test]
+    fn test_write_and_read_and_seek() -> io::Result<()> {
+        const BLOCKS: [&[u8]; 3] = [b"noodles", b"-bgzf", b"-gzi"];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        let mut index = vec![(0, 0)];
+        let mut uncompressed_position = 0;
+
+        for (i, block) in BLOCKS.iter().enumerate() {
+            writer.write_all(block)?;
+            writer.flush()?;
+
+            uncompressed_position += block.len() as u64;
+
+            if i < BLOCKS.len() - 1 {
+                index.push((writer.position(), uncompressed_position));
+            }
+        }
+
+        let data = writer.finish()?;
+
+        let mut gzi_writer = Writer::new(Vec::new());
+        gzi_writer.write_index(&index)?;
+
+        let mut gzi_reader = Reader::new(&gzi_writer.get_ref()[..]);
+        let actual = gzi_reader.read_index()?;
+        assert_eq!(actual, index);
+
+        let mut reader = bgzf::Reader::new(io::Cursor::new(data));
+        reader.seek_by_uncompressed_position(&actual, 9)?;
+
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
+        assert_eq!(buf, "gzf-gzi");
+
+        Ok(())
+    }
+}
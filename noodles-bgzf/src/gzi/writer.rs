@@ -0,0 +1,110 @@
+use std::io::{self, Write};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use super::Index;
+
+/// A gzip index (GZI) writer.
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a gzip index (GZI) writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::gzi;
+    /// let writer = gzi::Writer::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::gzi;
+    /// let writer = gzi::Writer::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::gzi;
+    /// let mut writer = gzi::Writer::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Writes a gzip index.
+    ///
+    /// The first offset pair of the index, which is always (0, 0), is implicit and not written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::gzi;
+    /// let mut writer = gzi::Writer::new(Vec::new());
+    /// writer.write_index(&vec![(0, 0)])?;
+    /// assert_eq!(writer.get_ref(), &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_index(&mut self, index: &Index) -> io::Result<()> {
+        let offsets = match index.first() {
+            Some((0, 0)) => &index[1..],
+            _ => &index[..],
+        };
+
+        let len = u64::try_from(offsets.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        self.inner.write_u64::<LittleEndian>(len)?;
+
+        for &(compressed, uncompressed) in offsets {
+            self.inner.write_u64::<LittleEndian>(compressed)?;
+            self.inner.write_u64::<LittleEndian>(uncompressed)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_index() -> io::Result<()> {
+        let index = vec![(0, 0), (4668, 21294), (23810, 86529)];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_index(&index)?;
+
+        let expected = [
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // len = 2
+            0x3c, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // compressed_offset = 4668
+            0x2e, 0x53, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // uncompressed_offset = 21294
+            0x02, 0x5d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // compressed_offset = 23810
+            0x01, 0x52, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // uncompressed_offset = 86529
+        ];
+
+        assert_eq!(writer.get_ref(), &expected);
+
+        Ok(())
+    }
+}
@@ -16,6 +16,9 @@ use super::{gzi, Block, VirtualPosition};
 /// correctly track (virtual) positions, the reader _cannot_ be double buffered (e.g., using
 /// [`std::io::BufReader`]).
 ///
+/// Blocks with no data are skipped. This includes the EOF markers in the middle of concatenated
+/// BGZF streams (e.g., `cat a.gz b.gz`), so the stream only ends at the end of the input.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -30,6 +33,7 @@ pub struct Reader<R> {
     inner: block::Inner<R>,
     position: u64,
     block: Block,
+    stream_len: Option<u64>,
 }
 
 impl<R> Reader<R>
@@ -74,6 +78,8 @@ where
     /// assert!(reader.get_mut().is_empty());
     /// ```
     pub fn get_mut(&mut self) -> &mut R {
+        // The underlying stream can be modified, so its length is measured again on the next seek.
+        self.stream_len = None;
         self.inner.get_mut()
     }
 
@@ -143,6 +149,10 @@ where
     /// The underlying stream's cursor is first moved the the compressed position. A block is read,
     /// decompressed, and has its own cursor moved to the uncompressed position.
     ///
+    /// This returns an error if the compressed position is past the end of the stream, the block
+    /// at the compressed position is truncated, or the uncompressed position is past the end of
+    /// the block.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -156,12 +166,58 @@ where
     pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
         let (cpos, upos) = pos.into();
 
+        // The stream may have grown since its length was last measured, e.g., when the file is
+        // still being written, so a stale length is only trusted when the position is within it.
+        let stream_len = match self.stream_len {
+            Some(len) if cpos <= len => len,
+            _ => {
+                let len = self.inner.get_mut().seek(SeekFrom::End(0))?;
+                self.stream_len = Some(len);
+                len
+            }
+        };
+
+        if cpos > stream_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid virtual position: compressed position ({cpos}) is past the end of \
+                    the stream ({stream_len})"
+                ),
+            ));
+        }
+
         self.inner.get_mut().seek(SeekFrom::Start(cpos))?;
         self.position = cpos;
 
-        self.read_block()?;
+        self.block.clear();
+        self.block.set_position(cpos);
+
+        self.read_block().map_err(|e| {
+            if e.kind() == io::ErrorKind::UnexpectedEof {
+                io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!("truncated block at compressed position {cpos}"),
+                )
+            } else {
+                e
+            }
+        })?;
+
+        let upos = usize::from(upos);
+        let block_len = self.block.data().len();
+
+        if upos > block_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid virtual position: uncompressed position ({upos}) is past the end of \
+                    the block ({block_len})"
+                ),
+            ));
+        }
 
-        self.block.data_mut().set_position(usize::from(upos));
+        self.block.data_mut().set_position(upos);
 
         Ok(pos)
     }
@@ -287,6 +343,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_with_concatenated_streams() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Write, num::NonZeroUsize};
+
+        use crate::Writer;
+
+        fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+            let mut writer = Writer::new(Vec::new());
+            writer.write_all(data)?;
+            writer.finish()
+        }
+
+        let a = compress(b"noodles")?;
+        let b = compress(b"bgzf")?;
+
+        let mut data = a.clone();
+        data.extend(&b);
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodlesbgzf");
+
+        let mut reader = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2)?)
+            .build_from_reader(&data[..]);
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodlesbgzf");
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        reader.seek(VirtualPosition::try_from((a.len() as u64, 0))?)?;
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"bgzf");
+
+        Ok(())
+    }
+
     #[test]
     fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
         #[rustfmt::skip]
@@ -320,6 +415,92 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_seek_with_invalid_virtual_position() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(Cursor::new(&data));
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::try_from((89, 0))?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::try_from((63, 1))?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::try_from((0, 8))?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let eof = VirtualPosition::try_from((63, 0))?;
+        reader.seek(eof)?;
+        assert_eq!(reader.virtual_position(), eof);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert!(buf.is_empty());
+
+        reader.seek(VirtualPosition::try_from((0, 7))?)?;
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert!(buf.is_empty());
+
+        let mut reader = Reader::new(Cursor::new(&data[..21]));
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::from(0)),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_after_stream_grows() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        static BLOCK: [u8; 35] = [
+            // b"noodles"
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(Cursor::new(BLOCK.to_vec()));
+        reader.seek(VirtualPosition::from(0))?;
+
+        reader.get_mut().get_mut().extend_from_slice(&BLOCK);
+        reader.seek(VirtualPosition::try_from((35, 3))?)?;
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"dles");
+
+        let mut reader = Reader::new(Cursor::new(BLOCK.to_vec()));
+        reader.seek(VirtualPosition::from(0))?;
+
+        reader.inner.get_mut().get_mut().extend_from_slice(&BLOCK);
+        reader.seek(VirtualPosition::try_from((35, 3))?)?;
+
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"dles");
+
+        Ok(())
+    }
+
     #[test]
     fn test_seek_by_uncompressed_position() -> io::Result<()> {
         #[rustfmt::skip]
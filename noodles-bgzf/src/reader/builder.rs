@@ -84,6 +84,7 @@ impl Builder {
             inner: block_reader,
             position: 0,
             block: Block::default(),
+            stream_len: None,
         }
     }
 }
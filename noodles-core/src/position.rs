@@ -44,8 +44,31 @@ impl Position {
         }
     }
 
+    /// Creates a position from a 0-based value.
+    ///
+    /// This returns `None` if the 1-based position would overflow, i.e., if the given value is
+    /// [`usize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// assert_eq!(Position::from_zero_based(0), Some(Position::MIN));
+    /// assert_eq!(Position::from_zero_based(7), Position::new(8));
+    /// assert!(Position::from_zero_based(usize::MAX).is_none());
+    /// ```
+    pub const fn from_zero_based(n: usize) -> Option<Self> {
+        if let Some(m) = n.checked_add(1) {
+            Self::new(m)
+        } else {
+            None
+        }
+    }
+
     /// Returns the inner value.
     ///
+    /// This is the 1-based value.
+    ///
     /// # Examples
     ///
     /// ```
@@ -56,6 +79,20 @@ impl Position {
         self.0.get()
     }
 
+    /// Returns the 0-based value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// assert_eq!(Position::MIN.to_zero_based(), 0);
+    /// assert_eq!(Position::MAX.to_zero_based(), usize::MAX - 1);
+    /// ```
+    pub const fn to_zero_based(self) -> usize {
+        // SAFETY: The inner value is guaranteed to be > 0.
+        self.0.get() - 1
+    }
+
     /// Adds an unsigned integer to a 1-based position.
     ///
     /// This returns `None` if the operation overflowed.
@@ -110,3 +147,36 @@ impl From<Position> for usize {
         position.0.get()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_zero_based() {
+        assert_eq!(Position::from_zero_based(0), Some(Position::MIN));
+        assert_eq!(Position::from_zero_based(7), Position::new(8));
+        assert_eq!(
+            Position::from_zero_based(usize::MAX - 1),
+            Some(Position::MAX)
+        );
+        assert!(Position::from_zero_based(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn test_to_zero_based() {
+        assert_eq!(Position::MIN.to_zero_based(), 0);
+        assert_eq!(Position::new(8).map(Position::to_zero_based), Some(7));
+        assert_eq!(Position::MAX.to_zero_based(), usize::MAX - 1);
+    }
+
+    #[test]
+    fn test_zero_based_round_trip() {
+        for n in [0, 1, 7, usize::MAX - 1] {
+            assert_eq!(
+                Position::from_zero_based(n).map(Position::to_zero_based),
+                Some(n)
+            );
+        }
+    }
+}
@@ -124,7 +124,13 @@ impl fmt::Display for Region {
         f.write_str(self.name())?;
 
         match (self.interval.start_bound(), self.interval.end_bound()) {
-            (Bound::Unbounded, Bound::Unbounded) => {}
+            // A trailing delimiter disambiguates a name that contains the delimiter, e.g.,
+            // `HLA-DRB1*12:17`, from a name with an interval.
+            (Bound::Unbounded, Bound::Unbounded) => {
+                if self.name().contains(':') {
+                    f.write_str(":")?;
+                }
+            }
             (_, _) => write!(f, ":{}", self.interval)?,
         }
 
@@ -196,6 +202,43 @@ mod tests {
         assert_eq!(Region::new("sq0", start..).to_string(), "sq0:5");
         assert_eq!(Region::new("sq0", start..=end).to_string(), "sq0:5-8");
 
+        assert_eq!(
+            Region::new("HLA-DRB1*12:17", ..).to_string(),
+            "HLA-DRB1*12:17:"
+        );
+        assert_eq!(
+            Region::new("HLA-DRB1*12:17", start..=end).to_string(),
+            "HLA-DRB1*12:17:5-8"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_is_inverse_of_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(s: &str, expected: &str) -> Result<(), ParseError> {
+            let region: Region = s.parse()?;
+            let actual = region.to_string();
+            assert_eq!(actual, expected);
+            assert_eq!(actual.parse(), Ok(region));
+            Ok(())
+        }
+
+        t("sq0", "sq0")?;
+        t("sq0:", "sq0")?;
+        t("sq0:5", "sq0:5")?;
+        t("sq0:5-8", "sq0:5-8")?;
+        t("HLA-DRB1*12:17:", "HLA-DRB1*12:17:")?;
+        t("HLA-DRB1*12:17:5", "HLA-DRB1*12:17:5")?;
+        t("HLA-DRB1*12:17:5-8", "HLA-DRB1*12:17:5-8")?;
+
+        let end = Position::try_from(8)?;
+        let region = Region::new("sq0", ..=end);
+        let actual: Region = region.to_string().parse()?;
+        assert_eq!(actual.name(), region.name());
+        assert_eq!(actual.interval().start(), Some(Position::MIN));
+        assert_eq!(actual.interval().end(), Some(end));
+
         Ok(())
     }
 
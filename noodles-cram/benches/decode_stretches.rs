@@ -0,0 +1,73 @@
+//! Compares converting stretches of bases and quality scores element-by-element with the batch
+//! conversions used when reading CRAM records.
+//!
+//! Run with `cargo bench -p noodles-cram --bench decode_stretches`.
+
+use std::time::Instant;
+
+use noodles_sam::record::{quality_scores::Score, sequence::Base, QualityScores, Sequence};
+
+const STRETCH_COUNT: usize = 1 << 14;
+const STRETCH_LENGTH: usize = 151;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_bases: Vec<u8> = b"ACGTN=acgtn"
+        .iter()
+        .copied()
+        .cycle()
+        .take(STRETCH_LENGTH)
+        .collect();
+
+    let raw_scores: Vec<u8> = (0..=Score::MAX.get())
+        .cycle()
+        .take(STRETCH_LENGTH)
+        .collect();
+
+    let start = Instant::now();
+    let mut base_count = 0;
+    let mut score_count = 0;
+
+    for _ in 0..STRETCH_COUNT {
+        let bases = raw_bases
+            .clone()
+            .into_iter()
+            .map(Base::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let scores = raw_scores
+            .clone()
+            .into_iter()
+            .map(Score::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        base_count += bases.len();
+        score_count += scores.len();
+    }
+
+    let per_element_elapsed = start.elapsed();
+    assert_eq!(base_count, STRETCH_COUNT * STRETCH_LENGTH);
+    assert_eq!(score_count, STRETCH_COUNT * STRETCH_LENGTH);
+
+    let start = Instant::now();
+    let mut base_count = 0;
+    let mut score_count = 0;
+
+    for _ in 0..STRETCH_COUNT {
+        let bases = Sequence::try_from(raw_bases.clone()).map(Vec::from)?;
+        let scores = QualityScores::try_from(raw_scores.clone()).map(Vec::from)?;
+
+        base_count += bases.len();
+        score_count += scores.len();
+    }
+
+    let batch_elapsed = start.elapsed();
+    assert_eq!(base_count, STRETCH_COUNT * STRETCH_LENGTH);
+    assert_eq!(score_count, STRETCH_COUNT * STRETCH_LENGTH);
+
+    println!(
+        "{STRETCH_COUNT} stretches of {STRETCH_LENGTH}: per-element = {per_element_elapsed:?}, \
+         batch = {batch_elapsed:?}"
+    );
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+//! Async CRAM reader.
+
+mod builder;
 mod crc_reader;
 mod data_container;
 mod header_container;
@@ -5,7 +8,8 @@ mod num;
 mod query;
 mod records;
 
-pub use self::crc_reader::CrcReader;
+pub use self::builder::Builder;
+pub(crate) use self::crc_reader::CrcReader;
 
 use bytes::BytesMut;
 use futures::Stream;
@@ -14,11 +18,14 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, SeekFrom};
 
-use crate::{crai, file_definition::Version, DataContainer, FileDefinition, Record};
+use crate::{
+    crai, file_definition::Version, reader::Options, DataContainer, FileDefinition, Record,
+};
 
 /// An async CRAM reader.
 pub struct Reader<R> {
     inner: R,
+    options: Options,
     buf: BytesMut,
 }
 
@@ -36,15 +43,17 @@ where
     /// let reader = cram::AsyncReader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self {
-            inner,
-            buf: BytesMut::new(),
-        }
+        Builder::default().build_with_reader(inner)
+    }
+
+    pub(crate) fn options(&self) -> &Options {
+        &self.options
     }
 
     /// Reads the CRAM file definition.
     ///
-    /// This also checks the magic number.
+    /// This also checks the magic number. CRAM 3.0 and 3.1 are supported; other format versions
+    /// return an [`io::ErrorKind::Unsupported`] error.
     ///
     /// The position of the stream is expected to be at the start.
     ///
@@ -299,7 +308,17 @@ where
 {
     let major = reader.read_u8().await?;
     let minor = reader.read_u8().await?;
-    Ok(Version::new(major, minor))
+
+    let version = Version::new(major, minor);
+
+    if version.is_supported() {
+        Ok(version)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("unsupported CRAM version: {version}"),
+        ))
+    }
 }
 
 async fn read_file_id<R>(reader: &mut R) -> io::Result<[u8; 20]>
@@ -341,6 +360,18 @@ mod tests {
         let data = [0x03, 0x00];
         let mut reader = &data[..];
         assert_eq!(read_format(&mut reader).await?, Version::new(3, 0));
+
+        let data = [0x03, 0x01];
+        let mut reader = &data[..];
+        assert_eq!(read_format(&mut reader).await?, Version::new(3, 1));
+
+        let data = [0x04, 0x00];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_format(&mut reader).await,
+            Err(ref e) if e.kind() == io::ErrorKind::Unsupported
+        ));
+
         Ok(())
     }
 
@@ -356,4 +387,84 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_records_with_options() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use futures::TryStreamExt;
+        use noodles_core::Position;
+        use sam::{
+            alignment::Record,
+            header::record::value::{map::ReferenceSequence, Map},
+            record::{data::field::tag, Flags},
+            AlignmentWriter,
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let mut writer = crate::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .preserve_read_names(false)
+            .build_with_writer(Vec::new());
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for (segment_flag, data) in [
+            (Flags::FIRST_SEGMENT, "NM:i:1\tCO:Z:noodles"),
+            (Flags::LAST_SEGMENT, "CO:Z:cram"),
+        ] {
+            let record = Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::SEGMENTED | segment_flag)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar("4M".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_data(data.parse()?)
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Builder::default()
+            .set_tag_filter([tag::EDIT_DISTANCE])
+            .set_read_name_prefix("sample".parse()?)
+            .build_with_reader(&data[..]);
+
+        reader.read_file_definition().await?;
+        reader.read_file_header().await?;
+
+        let records: Vec<_> = reader.records(&repository, &header).try_collect().await?;
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| (record.read_name().cloned(), record.data().clone()))
+            .collect();
+
+        let expected = [
+            (Some("sample:0".parse()?), "NM:i:1".parse()?),
+            (Some("sample:0".parse()?), sam::record::Data::default()),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,72 @@
+use bytes::BytesMut;
+use noodles_sam::record::data::field::Tag;
+use tokio::io::AsyncRead;
+
+use super::Reader;
+use crate::reader::{Options, ReadNamePrefix};
+
+/// An async CRAM reader builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    options: Options,
+}
+
+impl Builder {
+    /// Sets the tags to decode.
+    ///
+    /// See [`crate::reader::Builder::set_tag_filter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// use noodles_sam::record::data::field::tag;
+    ///
+    /// let builder = cram::r#async::reader::Builder::default()
+    ///     .set_tag_filter([tag::EDIT_DISTANCE]);
+    /// ```
+    pub fn set_tag_filter<I>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        self.options.tag_filter = Some(tags.into_iter().collect());
+        self
+    }
+
+    /// Sets the prefix of generated read names.
+    ///
+    /// See [`crate::reader::Builder::set_read_name_prefix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::r#async::reader::Builder::default()
+    ///     .set_read_name_prefix("sample".parse()?);
+    /// # Ok::<_, cram::reader::read_name_prefix::ParseError>(())
+    /// ```
+    pub fn set_read_name_prefix(mut self, read_name_prefix: ReadNamePrefix) -> Self {
+        self.options.read_name_prefix = Some(read_name_prefix);
+        self
+    }
+
+    /// Builds an async CRAM reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let reader = cram::r#async::reader::Builder::default().build_with_reader(&data[..]);
+    /// ```
+    pub fn build_with_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: AsyncRead + Unpin,
+    {
+        Reader {
+            inner: reader,
+            options: self.options,
+            buf: BytesMut::new(),
+        }
+    }
+}
@@ -52,12 +52,10 @@ where
         loop {
             match ctx.records.next() {
                 Some(r) => {
-                    if let (Some(start), Some(end)) = (r.alignment_start(), r.alignment_end()) {
-                        let alignment_interval = (start..=end).into();
-
-                        if ctx.interval.intersects(alignment_interval) {
-                            return Ok(Some((r, ctx)));
-                        }
+                    if r.reference_sequence_id() == Some(ctx.reference_sequence_id)
+                        && r.overlaps(ctx.interval)
+                    {
+                        return Ok(Some((r, ctx)));
                     }
                 }
                 None => match read_next_container(&mut ctx).await {
@@ -100,16 +98,12 @@ where
         .map(|slice| {
             let compression_header = container.compression_header();
 
-            slice.records(compression_header).and_then(|mut records| {
-                slice.resolve_records(
-                    ctx.reference_sequence_repository,
-                    ctx.header,
-                    compression_header,
-                    &mut records,
-                )?;
-
-                Ok(records)
-            })
+            slice.decode_records(
+                ctx.reference_sequence_repository,
+                ctx.header,
+                compression_header,
+                ctx.reader.options(),
+            )
         })
         .collect::<Result<Vec<_>, _>>();
 
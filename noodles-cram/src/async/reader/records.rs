@@ -67,16 +67,12 @@ where
         .map(|slice| {
             let compression_header = container.compression_header();
 
-            slice.records(compression_header).and_then(|mut records| {
-                slice.resolve_records(
-                    ctx.reference_sequence_repository,
-                    ctx.header,
-                    compression_header,
-                    &mut records,
-                )?;
-
-                Ok(records)
-            })
+            slice.decode_records(
+                ctx.reference_sequence_repository,
+                ctx.header,
+                compression_header,
+                ctx.reader.options(),
+            )
         })
         .collect::<Result<Vec<_>, _>>();
 
@@ -73,23 +73,26 @@ struct TokenReader {
 }
 
 impl TokenReader {
-    fn get(&self, ty: Type) -> &Cursor<Vec<u8>> {
-        match ty {
+    fn get(&self, ty: Type) -> io::Result<&Cursor<Vec<u8>>> {
+        let reader = match ty {
             Type::Type => &self.type_reader,
             Type::String => &self.string_reader,
             Type::Char => &self.char_reader,
             Type::Digits0 => &self.digits0_reader,
             Type::Dup => &self.dup_reader,
             Type::Diff => &self.diff_reader,
+            Type::DZLen => &self.dz_len_reader,
             Type::Digits => &self.digits_reader,
             Type::Delta => &self.delta_reader,
             Type::Delta0 => &self.delta0_reader,
-            _ => unimplemented!("unhandled ty: {:?}", ty),
-        }
+            _ => return Err(unsupported_token_type_error(ty)),
+        };
+
+        Ok(reader)
     }
 
-    fn get_mut(&mut self, ty: Type) -> &mut Cursor<Vec<u8>> {
-        match ty {
+    fn get_mut(&mut self, ty: Type) -> io::Result<&mut Cursor<Vec<u8>>> {
+        let reader = match ty {
             Type::Type => &mut self.type_reader,
             Type::String => &mut self.string_reader,
             Type::Char => &mut self.char_reader,
@@ -100,11 +103,13 @@ impl TokenReader {
             Type::Digits => &mut self.digits_reader,
             Type::Delta => &mut self.delta_reader,
             Type::Delta0 => &mut self.delta0_reader,
-            _ => unimplemented!("unhandled ty: {:?}", ty),
-        }
+            _ => return Err(unsupported_token_type_error(ty)),
+        };
+
+        Ok(reader)
     }
 
-    fn set(&mut self, ty: Type, buf: Vec<u8>) {
+    fn set(&mut self, ty: Type, buf: Vec<u8>) -> io::Result<()> {
         match ty {
             Type::Type => *self.type_reader.get_mut() = buf,
             Type::String => *self.string_reader.get_mut() = buf,
@@ -116,8 +121,10 @@ impl TokenReader {
             Type::Digits => *self.digits_reader.get_mut() = buf,
             Type::Delta => *self.delta_reader.get_mut() = buf,
             Type::Delta0 => *self.delta0_reader.get_mut() = buf,
-            _ => unimplemented!("unhandled ty: {:?}", ty),
+            _ => return Err(unsupported_token_type_error(ty)),
         }
+
+        Ok(())
     }
 
     fn read_type(&mut self) -> io::Result<Type> {
@@ -129,7 +136,7 @@ impl TokenReader {
     fn read_distance(&mut self, ty: Type) -> io::Result<usize> {
         assert!(matches!(ty, Type::Dup | Type::Diff));
 
-        self.get_mut(ty).read_u32::<LittleEndian>().and_then(|n| {
+        self.get_mut(ty)?.read_u32::<LittleEndian>().and_then(|n| {
             usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
         })
     }
@@ -190,6 +197,13 @@ impl TokenReader {
     }
 }
 
+fn unsupported_token_type_error(ty: Type) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("name tokenizer (tok3): unsupported token type: {ty:?}"),
+    )
+}
+
 fn decode_token_byte_streams<R>(
     reader: &mut R,
     use_arith: bool,
@@ -222,7 +236,7 @@ where
             if ty != Type::Type {
                 let mut buf = vec![u8::from(Type::Match); n_names];
                 buf[0] = u8::from(ty);
-                b[t as usize].set(Type::Type, buf);
+                b[t as usize].set(Type::Type, buf)?;
             }
         }
 
@@ -233,8 +247,8 @@ where
                 Type::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
             })?;
 
-            let buf = b[dup_pos].get(dup_type).get_ref().clone();
-            b[t as usize].set(ty, buf);
+            let buf = b[dup_pos].get(dup_type)?.get_ref().clone();
+            b[t as usize].set(ty, buf)?;
         } else {
             let clen = read_uint7(reader).and_then(|n| {
                 usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -251,7 +265,7 @@ where
                 rans_nx16::decode(&mut data_reader, 0)?
             };
 
-            b[t as usize].set(ty, buf);
+            b[t as usize].set(ty, buf)?;
         }
     }
 
@@ -430,4 +444,16 @@ I17_08765:2:124:45613:16161#9\0\
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_token_byte_streams_with_unsupported_token_type() {
+        // new, duplicate `Match` token of token 0, type `Match`
+        let data = [0xca, 0x00, 0x0a];
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            decode_token_byte_streams(&mut reader, false, 1),
+            Err(e) if e.kind() == io::ErrorKind::Unsupported
+        ));
+    }
 }
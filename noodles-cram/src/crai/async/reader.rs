@@ -14,6 +14,8 @@ where
 {
     /// Creates an async CRAM index reader.
     ///
+    /// The input is expected to be gzip-compressed and may consist of multiple gzip members.
+    ///
     /// # Examples
     ///
     /// ```
@@ -22,8 +24,11 @@ where
     /// let reader = crai::AsyncReader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
+        let mut decoder = GzipDecoder::new(BufReader::new(inner));
+        decoder.multiple_members(true);
+
         Self {
-            inner: BufReader::new(GzipDecoder::new(BufReader::new(inner))),
+            inner: BufReader::new(decoder),
         }
     }
 
@@ -126,4 +131,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_index_with_multiple_gzip_members() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use std::io::Write;
+
+        use flate2::write::GzEncoder;
+
+        let mut compressed_data = Vec::new();
+
+        for data in [
+            &b"0\t10946\t6765\t17711\t233\t317811\n"[..],
+            &b"0\t17711\t121393\t317811\t233\t317811\n"[..],
+        ] {
+            let mut writer = GzEncoder::new(Vec::new(), Default::default());
+            writer.write_all(data)?;
+            compressed_data.extend(writer.finish()?);
+        }
+
+        let mut reader = Reader::new(&compressed_data[..]);
+        let actual = reader.read_index().await?;
+
+        let expected = vec![
+            Record::new(Some(0), Position::new(10946), 6765, 17711, 233, 317811),
+            Record::new(Some(0), Position::new(17711), 121393, 317811, 233, 317811),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -1,12 +1,12 @@
 use std::io::{self, BufRead, BufReader, Read};
 
-use flate2::read::GzDecoder;
+use flate2::read::MultiGzDecoder;
 
 use super::Index;
 
 /// A CRAM index reader.
 pub struct Reader<R> {
-    inner: BufReader<GzDecoder<R>>,
+    inner: BufReader<MultiGzDecoder<R>>,
 }
 
 impl<R> Reader<R>
@@ -15,6 +15,8 @@ where
 {
     /// Creates a CRAM index reader.
     ///
+    /// The input is expected to be gzip-compressed and may consist of multiple gzip members.
+    ///
     /// # Examples
     ///
     /// ```
@@ -24,7 +26,7 @@ where
     /// ```
     pub fn new(inner: R) -> Self {
         Self {
-            inner: BufReader::new(GzDecoder::new(inner)),
+            inner: BufReader::new(MultiGzDecoder::new(inner)),
         }
     }
 
@@ -114,4 +116,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_index_with_multiple_gzip_members() -> Result<(), Box<dyn std::error::Error>> {
+        let mut compressed_data = Vec::new();
+
+        for data in [
+            &b"0\t10946\t6765\t17711\t233\t317811\n"[..],
+            &b"0\t17711\t121393\t317811\t233\t317811\n"[..],
+        ] {
+            let mut writer = GzEncoder::new(Vec::new(), Default::default());
+            writer.write_all(data)?;
+            compressed_data.extend(writer.finish()?);
+        }
+
+        let mut reader = Reader::new(&compressed_data[..]);
+
+        let actual = reader.read_index()?;
+
+        let expected = vec![
+            Record::new(Some(0), Position::new(10946), 6765, 17711, 233, 317811),
+            Record::new(Some(0), Position::new(17711), 121393, 317811, 233, 317811),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
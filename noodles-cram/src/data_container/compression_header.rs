@@ -10,10 +10,16 @@ pub(crate) use self::{
     builder::Builder,
     data_series_encoding_map::DataSeriesEncodingMap,
     encoding::Encoding,
-    preservation_map::{PreservationMap, SubstitutionMatrix, TagIdsDictionary},
+    preservation_map::{SubstitutionMatrix, TagIdsDictionary},
     tag_encoding_map::TagEncodingMap,
 };
 
+pub use self::{
+    data_series_encoding_map::{DataSeries, DataSeriesEncoding},
+    encoding::Kind as EncodingKind,
+    preservation_map::PreservationMap,
+};
+
 /// A CRAM data container compression header.
 ///
 /// The compression header has three maps with information about how the data is compressed: a
@@ -42,7 +48,8 @@ impl CompressionHeader {
         }
     }
 
-    pub(crate) fn preservation_map(&self) -> &PreservationMap {
+    /// Returns the preservation map.
+    pub fn preservation_map(&self) -> &PreservationMap {
         &self.preservation_map
     }
 
@@ -50,6 +57,15 @@ impl CompressionHeader {
         &self.data_series_encoding_map
     }
 
+    /// Returns an iterator over the data series and their encodings.
+    ///
+    /// This only includes data series that have an encoding in the data series encoding map.
+    pub fn data_series_encodings(
+        &self,
+    ) -> impl Iterator<Item = (DataSeries, DataSeriesEncoding<'_>)> {
+        self.data_series_encoding_map.encodings()
+    }
+
     pub(crate) fn tag_encoding_map(&self) -> &TagEncodingMap {
         &self.tag_encoding_map
     }
@@ -6,8 +6,12 @@ pub(crate) mod data_series;
 pub(crate) use self::builder::Builder;
 pub use self::data_series::DataSeries;
 
+use self::data_series::STANDARD_DATA_SERIES;
 use super::{
-    encoding::codec::{Byte, ByteArray, Integer},
+    encoding::{
+        codec::{Byte, ByteArray, Integer},
+        Kind,
+    },
     Encoding,
 };
 use crate::container::block;
@@ -148,6 +152,101 @@ impl DataSeriesEncodingMap {
         n
     }
 
+    /// Returns an iterator over the data series and their encodings.
+    ///
+    /// Data series without an encoding are skipped.
+    pub fn encodings(&self) -> impl Iterator<Item = (DataSeries, DataSeriesEncoding<'_>)> {
+        STANDARD_DATA_SERIES.iter().filter_map(move |&data_series| {
+            self.codec(data_series)
+                .map(|codec| (data_series, DataSeriesEncoding(codec)))
+        })
+    }
+
+    /// Returns the data series that use an encoding the record decoder does not support.
+    ///
+    /// The kind is that of the unsupported encoding, which may be nested, e.g., the length
+    /// encoding of a byte array length encoding.
+    pub(crate) fn unsupported_decoding_kinds(&self) -> Vec<(DataSeries, Kind)> {
+        self.encodings()
+            .filter_map(|(data_series, encoding)| {
+                encoding
+                    .0
+                    .unsupported_decoding_kind()
+                    .map(|kind| (data_series, kind))
+            })
+            .collect()
+    }
+
+    fn codec(&self, data_series: DataSeries) -> Option<Codec<'_>> {
+        match data_series {
+            DataSeries::BamBitFlags => Some(Codec::Integer(self.bam_bit_flags_encoding().get())),
+            DataSeries::CramBitFlags => Some(Codec::Integer(self.cram_bit_flags_encoding().get())),
+            DataSeries::ReferenceId => self
+                .reference_id_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::ReadLengths => Some(Codec::Integer(self.read_lengths_encoding().get())),
+            DataSeries::InSeqPositions => {
+                Some(Codec::Integer(self.in_seq_positions_encoding().get()))
+            }
+            DataSeries::ReadGroups => Some(Codec::Integer(self.read_groups_encoding().get())),
+            DataSeries::ReadNames => self
+                .read_names_encoding()
+                .map(|e| Codec::ByteArray(e.get())),
+            DataSeries::NextMateBitFlags => self
+                .next_mate_bit_flags_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::NextFragmentReferenceSequenceId => self
+                .next_fragment_reference_sequence_id_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::NextMateAlignmentStart => self
+                .next_mate_alignment_start_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::TemplateSize => self
+                .template_size_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::DistanceToNextFragment => self
+                .distance_to_next_fragment_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::TagIds => Some(Codec::Integer(self.tag_ids_encoding().get())),
+            DataSeries::NumberOfReadFeatures => self
+                .number_of_read_features_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::ReadFeaturesCodes => self
+                .read_features_codes_encoding()
+                .map(|e| Codec::Byte(e.get())),
+            DataSeries::InReadPositions => self
+                .in_read_positions_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::DeletionLengths => self
+                .deletion_lengths_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::StretchesOfBases => self
+                .stretches_of_bases_encoding()
+                .map(|e| Codec::ByteArray(e.get())),
+            DataSeries::StretchesOfQualityScores => self
+                .stretches_of_quality_scores_encoding()
+                .map(|e| Codec::ByteArray(e.get())),
+            DataSeries::BaseSubstitutionCodes => self
+                .base_substitution_codes_encoding()
+                .map(|e| Codec::Byte(e.get())),
+            DataSeries::Insertion => self.insertion_encoding().map(|e| Codec::ByteArray(e.get())),
+            DataSeries::ReferenceSkipLength => self
+                .reference_skip_length_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::Padding => self.padding_encoding().map(|e| Codec::Integer(e.get())),
+            DataSeries::HardClip => self.hard_clip_encoding().map(|e| Codec::Integer(e.get())),
+            DataSeries::SoftClip => self.soft_clip_encoding().map(|e| Codec::ByteArray(e.get())),
+            DataSeries::MappingQualities => self
+                .mapping_qualities_encoding()
+                .map(|e| Codec::Integer(e.get())),
+            DataSeries::Bases => self.bases_encoding().map(|e| Codec::Byte(e.get())),
+            DataSeries::QualityScores => {
+                self.quality_scores_encoding().map(|e| Codec::Byte(e.get()))
+            }
+            DataSeries::ReservedTc | DataSeries::ReservedTn => None,
+        }
+    }
+
     pub fn bam_bit_flags_encoding(&self) -> &Encoding<Integer> {
         &self.bam_bit_flags_encoding
     }
@@ -261,6 +360,38 @@ impl DataSeriesEncodingMap {
     }
 }
 
+/// A data series encoding in a data series encoding map.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DataSeriesEncoding<'a>(Codec<'a>);
+
+impl DataSeriesEncoding<'_> {
+    /// Returns the kind of encoding.
+    pub fn kind(&self) -> Kind {
+        match self.0 {
+            Codec::Byte(codec) => codec.kind(),
+            Codec::ByteArray(codec) => codec.kind(),
+            Codec::Integer(codec) => codec.kind(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Codec<'a> {
+    Byte(&'a Byte),
+    ByteArray(&'a ByteArray),
+    Integer(&'a Integer),
+}
+
+impl Codec<'_> {
+    fn unsupported_decoding_kind(&self) -> Option<Kind> {
+        match self {
+            Self::Byte(_) => None,
+            Self::ByteArray(codec) => codec.unsupported_decoding_kind(),
+            Self::Integer(codec) => codec.unsupported_decoding_kind(),
+        }
+    }
+}
+
 impl Default for DataSeriesEncodingMap {
     fn default() -> Self {
         Self {
@@ -385,4 +516,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_encodings() -> Result<(), builder::BuildError> {
+        let map = DataSeriesEncodingMap::default();
+        let kinds: Vec<_> = map
+            .encodings()
+            .map(|(data_series, encoding)| (data_series, encoding.kind()))
+            .collect();
+        assert_eq!(kinds.len(), 28);
+        assert_eq!(kinds[0], (DataSeries::BamBitFlags, Kind::External));
+        assert_eq!(kinds[6], (DataSeries::ReadNames, Kind::ByteArrayStop));
+        assert_eq!(
+            kinds[18],
+            (DataSeries::StretchesOfQualityScores, Kind::ByteArrayLen)
+        );
+
+        let map = DataSeriesEncodingMap::builder()
+            .set_bam_bit_flags_encoding(Encoding::new(Integer::External(block::ContentId::from(1))))
+            .set_cram_bit_flags_encoding(Encoding::new(Integer::External(block::ContentId::from(
+                2,
+            ))))
+            .set_read_lengths_encoding(Encoding::new(Integer::Golomb(1, 0)))
+            .set_in_seq_positions_encoding(Encoding::new(Integer::External(
+                block::ContentId::from(5),
+            )))
+            .set_read_groups_encoding(Encoding::new(Integer::External(block::ContentId::from(6))))
+            .set_tag_ids_encoding(Encoding::new(Integer::External(block::ContentId::from(13))))
+            .build()?;
+
+        let kinds: Vec<_> = map
+            .encodings()
+            .map(|(data_series, encoding)| (data_series, encoding.kind()))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            [
+                (DataSeries::BamBitFlags, Kind::External),
+                (DataSeries::CramBitFlags, Kind::External),
+                (DataSeries::ReadLengths, Kind::Golomb),
+                (DataSeries::InSeqPositions, Kind::External),
+                (DataSeries::ReadGroups, Kind::External),
+                (DataSeries::TagIds, Kind::External),
+            ]
+        );
+
+        Ok(())
+    }
 }
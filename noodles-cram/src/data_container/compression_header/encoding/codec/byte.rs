@@ -5,7 +5,7 @@ use bytes::Buf;
 
 use crate::{
     container::block,
-    data_container::compression_header::encoding::{Decode, Encode},
+    data_container::compression_header::encoding::{Decode, Encode, Kind},
     huffman::CanonicalHuffmanDecoder,
     io::{BitReader, BitWriter},
     reader::record::ExternalDataReaders,
@@ -19,10 +19,19 @@ pub enum Byte {
     Huffman(Vec<i32>, Vec<u32>),
 }
 
+impl Byte {
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::External(_) => Kind::External,
+            Self::Huffman(..) => Kind::Huffman,
+        }
+    }
+}
+
 impl Byte {
     pub fn decode_exact<R, S>(
         &self,
-        _core_data_reader: &mut BitReader<R>,
+        core_data_reader: &mut BitReader<R>,
         external_data_readers: &mut ExternalDataReaders<S>,
         dst: &mut [u8],
     ) -> io::Result<()>
@@ -47,7 +56,11 @@ impl Byte {
 
                 src.copy_to_slice(dst);
             }
-            Byte::Huffman(..) => todo!(),
+            Byte::Huffman(..) => {
+                for value in dst {
+                    *value = self.decode(core_data_reader, external_data_readers)?;
+                }
+            }
         }
 
         Ok(())
@@ -146,6 +159,12 @@ mod tests {
 
         assert_eq!(dst, external_data);
 
+        let codec = Byte::Huffman(vec![0x4e], vec![0]);
+        let mut dst = vec![0; 2];
+        codec.decode_exact(&mut core_data_reader, &mut external_data_readers, &mut dst)?;
+
+        assert_eq!(dst, b"NN");
+
         Ok(())
     }
 
@@ -11,7 +11,7 @@ use crate::{
     data_container::compression_header::{
         encoding::{
             codec::{Byte, Integer},
-            Decode, Encode,
+            Decode, Encode, Kind,
         },
         Encoding,
     },
@@ -27,6 +27,68 @@ pub enum ByteArray {
     ByteArrayStop(u8, block::ContentId),
 }
 
+impl ByteArray {
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::ByteArrayLen(..) => Kind::ByteArrayLen,
+            Self::ByteArrayStop(..) => Kind::ByteArrayStop,
+        }
+    }
+
+    pub fn unsupported_decoding_kind(&self) -> Option<Kind> {
+        match self {
+            Self::ByteArrayLen(len_encoding, _) => len_encoding.get().unsupported_decoding_kind(),
+            Self::ByteArrayStop(..) => None,
+        }
+    }
+
+    /// Advances the readers past a value without allocating it.
+    pub fn skip<R, S>(
+        &self,
+        core_data_reader: &mut BitReader<R>,
+        external_data_readers: &mut ExternalDataReaders<S>,
+    ) -> io::Result<()>
+    where
+        R: Buf,
+        S: Buf,
+    {
+        match self {
+            ByteArray::ByteArrayLen(len_encoding, value_encoding) => {
+                let len = len_encoding.decode(core_data_reader, external_data_readers)?;
+
+                for _ in 0..len {
+                    value_encoding.decode(core_data_reader, external_data_readers)?;
+                }
+
+                Ok(())
+            }
+            ByteArray::ByteArrayStop(stop_byte, block_content_id) => {
+                let src = external_data_readers
+                    .get_mut(block_content_id)
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("missing external block: {block_content_id}"),
+                        )
+                    })?;
+
+                let len = src
+                    .chunk()
+                    .iter()
+                    .position(|&b| b == *stop_byte)
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "missing byte array stop byte")
+                    })?;
+
+                // Include the stop byte.
+                src.advance(len + 1);
+
+                Ok(())
+            }
+        }
+    }
+}
+
 impl Decode for ByteArray {
     type Value = Vec<u8>;
 
@@ -177,6 +239,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_skip() -> io::Result<()> {
+        fn t(external_data: &[u8], codec: &ByteArray, expected_remaining: &[u8]) -> io::Result<()> {
+            let core_data = [];
+            let mut core_data_reader = BitReader::new(&core_data[..]);
+
+            let mut external_data_readers = ExternalDataReaders::new();
+            external_data_readers.insert(block::ContentId::from(1), external_data);
+
+            codec.skip(&mut core_data_reader, &mut external_data_readers)?;
+
+            let actual_remaining = external_data_readers
+                .get_mut(&block::ContentId::from(1))
+                .map(|reader| &reader[..]);
+
+            assert_eq!(actual_remaining, Some(expected_remaining));
+
+            Ok(())
+        }
+
+        let len_encoding = Encoding::new(Integer::External(block::ContentId::from(1)));
+        let value_encoding = Encoding::new(Byte::External(block::ContentId::from(1)));
+        t(
+            &[0x04, 0x6e, 0x64, 0x6c, 0x73, 0x08],
+            &ByteArray::ByteArrayLen(len_encoding, value_encoding),
+            &[0x08],
+        )?;
+
+        t(
+            &[0x6e, 0x64, 0x6c, 0x73, 0x00, 0x08],
+            &ByteArray::ByteArrayStop(0x00, block::ContentId::from(1)),
+            &[0x08],
+        )?;
+
+        assert!(matches!(
+            t(
+                &[0x6e, 0x64, 0x6c, 0x73],
+                &ByteArray::ByteArrayStop(0x00, block::ContentId::from(1)),
+                &[]
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_encode() -> io::Result<()> {
         fn t(
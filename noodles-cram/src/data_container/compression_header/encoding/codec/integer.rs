@@ -7,7 +7,7 @@ use bytes::Buf;
 
 use crate::{
     container::block,
-    data_container::compression_header::encoding::{Decode, Encode},
+    data_container::compression_header::encoding::{Decode, Encode, Kind},
     huffman::CanonicalHuffmanDecoder,
     io::{BitReader, BitWriter},
     reader::{num::get_itf8, record::ExternalDataReaders},
@@ -32,6 +32,27 @@ pub enum Integer {
     Gamma(i32),
 }
 
+impl Integer {
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::External(_) => Kind::External,
+            Self::Golomb(..) => Kind::Golomb,
+            Self::Huffman(..) => Kind::Huffman,
+            Self::Beta(..) => Kind::Beta,
+            Self::Subexp(..) => Kind::Subexp,
+            Self::GolombRice(..) => Kind::GolombRice,
+            Self::Gamma(_) => Kind::Gamma,
+        }
+    }
+
+    pub fn unsupported_decoding_kind(&self) -> Option<Kind> {
+        match self {
+            Self::Golomb(..) | Self::Subexp(..) | Self::GolombRice(..) => Some(self.kind()),
+            _ => None,
+        }
+    }
+}
+
 impl Decode for Integer {
     type Value = i32;
 
@@ -1,13 +1,24 @@
+/// A CRAM encoding kind.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Kind {
+    /// Null (0).
     Null,
+    /// External (1).
     External,
+    /// Golomb (2).
     Golomb,
+    /// Huffman (3).
     Huffman,
+    /// Byte array length (4).
     ByteArrayLen,
+    /// Byte array stop (5).
     ByteArrayStop,
+    /// Beta (6).
     Beta,
+    /// Subexponential (7).
     Subexp,
+    /// Golomb-Rice (8).
     GolombRice,
+    /// Elias gamma (9).
     Gamma,
 }
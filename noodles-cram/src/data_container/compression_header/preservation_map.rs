@@ -2,16 +2,16 @@
 
 mod builder;
 pub(crate) mod key;
-pub(crate) mod substitution_matrix;
+pub mod substitution_matrix;
 pub mod tag_ids_dictionary;
 
-pub(crate) use {
-    builder::Builder, key::Key, substitution_matrix::SubstitutionMatrix,
-    tag_ids_dictionary::TagIdsDictionary,
-};
+pub use self::substitution_matrix::SubstitutionMatrix;
 
+pub(crate) use {builder::Builder, key::Key, tag_ids_dictionary::TagIdsDictionary};
+
+/// A CRAM data container preservation map.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct PreservationMap {
+pub struct PreservationMap {
     read_names_included: bool,
     ap_data_series_delta: bool,
     is_reference_required: bool,
@@ -20,7 +20,7 @@ pub(crate) struct PreservationMap {
 }
 
 impl PreservationMap {
-    pub fn new(
+    pub(crate) fn new(
         read_names_included: bool,
         ap_data_series_delta: bool,
         is_reference_required: bool,
@@ -36,23 +36,27 @@ impl PreservationMap {
         }
     }
 
+    /// Returns whether read names are preserved.
     pub fn read_names_included(&self) -> bool {
         self.read_names_included
     }
 
+    /// Returns whether alignment positions are delta encoded.
     pub fn ap_data_series_delta(&self) -> bool {
         self.ap_data_series_delta
     }
 
+    /// Returns whether the reference sequence is required to decode records.
     pub fn is_reference_required(&self) -> bool {
         self.is_reference_required
     }
 
+    /// Returns the substitution matrix.
     pub fn substitution_matrix(&self) -> &SubstitutionMatrix {
         &self.substitution_matrix
     }
 
-    pub fn tag_ids_dictionary(&self) -> &TagIdsDictionary {
+    pub(crate) fn tag_ids_dictionary(&self) -> &TagIdsDictionary {
         &self.tag_ids_dictionary
     }
 }
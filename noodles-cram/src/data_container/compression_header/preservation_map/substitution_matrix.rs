@@ -1,7 +1,9 @@
+//! CRAM data container preservation map substitution matrix.
+
 mod builder;
 mod histogram;
 
-pub use self::builder::Builder;
+pub(crate) use self::builder::Builder;
 
 use std::{cmp, error, fmt};
 
@@ -10,21 +12,25 @@ use crate::record::feature::substitution::Base;
 
 type Substitutions = [[Base; 4]; 5];
 
+/// A CRAM data container preservation map substitution matrix.
+///
+/// The substitution matrix maps a reference base and a substitution code (0-3) to a read base.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SubstitutionMatrix {
     substitutions: Substitutions,
 }
 
 impl SubstitutionMatrix {
-    pub fn builder() -> Builder {
-        Builder::default()
-    }
-
+    /// Returns the read base for the given reference base and substitution code.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the substitution code is > 3.
     pub fn get(&self, reference_base: Base, substitution_code: u8) -> Base {
         self.substitutions[reference_base as usize][substitution_code as usize]
     }
 
-    pub fn find_code(&self, reference_base: Base, read_base: Base) -> u8 {
+    pub(crate) fn find_code(&self, reference_base: Base, read_base: Base) -> u8 {
         for code in [0b00, 0b01, 0b10, 0b11] {
             if self.get(reference_base, code) == read_base {
                 return code;
@@ -49,6 +55,29 @@ impl Default for SubstitutionMatrix {
     }
 }
 
+impl fmt::Display for SubstitutionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const BASES: [Base; 5] = [Base::A, Base::C, Base::G, Base::T, Base::N];
+
+        write!(f, " ")?;
+
+        for code in 0..4 {
+            write!(f, " {code}")?;
+        }
+
+        for (reference_base, read_bases) in BASES.iter().zip(&self.substitutions) {
+            writeln!(f)?;
+            write!(f, "{}", char::from(u8::from(*reference_base)))?;
+
+            for &read_base in read_bases {
+                write!(f, " {}", char::from(u8::from(read_base)))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl From<Histogram> for SubstitutionMatrix {
     fn from(histogram: Histogram) -> Self {
         const BASES: [Base; 5] = [Base::A, Base::C, Base::G, Base::T, Base::N];
@@ -77,6 +106,7 @@ impl From<Histogram> for SubstitutionMatrix {
     }
 }
 
+/// An error returned when a substitution matrix fails to convert from a byte array.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TryFromByteArrayError([u8; 5]);
 
@@ -231,6 +261,20 @@ mod tests {
         assert_eq!(<[u8; 5]>::from(matrix), [0x93, 0x1b, 0x6c, 0xb1, 0xc6]);
     }
 
+    #[test]
+    fn test_fmt() {
+        let matrix = SubstitutionMatrix::default();
+
+        let expected = "  0 1 2 3
+A C G T N
+C A G T N
+G A C T N
+T A C G N
+N A C G T";
+
+        assert_eq!(matrix.to_string(), expected);
+    }
+
     #[test]
     fn test_from_histogram() {
         let histogram = Histogram::new([
@@ -4,6 +4,7 @@ pub use self::builder::Builder;
 
 use super::ReferenceSequenceContext;
 
+/// A CRAM data container header.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Header {
     length: usize,
@@ -17,34 +18,41 @@ pub struct Header {
 
 #[allow(clippy::len_without_is_empty)]
 impl Header {
-    pub fn builder() -> Builder {
+    pub(crate) fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the length of the container body, i.e., the number of bytes following the header.
     pub fn len(&self) -> usize {
         self.length
     }
 
+    /// Returns the reference sequence context.
     pub fn reference_sequence_context(&self) -> ReferenceSequenceContext {
         self.reference_sequence_context
     }
 
+    /// Returns the number of records in the container.
     pub fn record_count(&self) -> i32 {
         self.record_count
     }
 
+    /// Returns the index of the first record in the container relative to the start of the stream.
     pub fn record_counter(&self) -> u64 {
         self.record_counter
     }
 
+    /// Returns the number of read bases in the container.
     pub fn base_count(&self) -> u64 {
         self.base_count
     }
 
+    /// Returns the number of blocks in the container.
     pub fn block_count(&self) -> usize {
         self.block_count
     }
 
+    /// Returns the byte offsets of the slices relative to the start of the container body.
     pub fn landmarks(&self) -> &[usize] {
         &self.landmarks
     }
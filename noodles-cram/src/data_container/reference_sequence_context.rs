@@ -1,7 +1,10 @@
+//! CRAM data container reference sequence context.
+
 use std::cmp;
 
 use noodles_core::Position;
 
+/// A CRAM data container reference sequence context for a single reference sequence.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Context {
     reference_sequence_id: usize,
@@ -22,32 +25,40 @@ impl Context {
         }
     }
 
+    /// Returns the reference sequence ID.
     pub fn reference_sequence_id(&self) -> usize {
         self.reference_sequence_id
     }
 
+    /// Returns the alignment start.
     pub fn alignment_start(&self) -> Position {
         self.alignment_start
     }
 
+    /// Returns the alignment span.
     pub fn alignment_span(&self) -> usize {
         usize::from(self.alignment_end) - usize::from(self.alignment_start) + 1
     }
 
+    /// Returns the alignment end.
     pub fn alignment_end(&self) -> Position {
         self.alignment_end
     }
 }
 
+/// A CRAM data container reference sequence context.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ReferenceSequenceContext {
+    /// All records are placed on the same reference sequence.
     Some(Context),
+    /// All records are unplaced.
     None,
+    /// Records are placed on multiple reference sequences or are a mix of placed and unplaced.
     Many,
 }
 
 impl ReferenceSequenceContext {
-    pub fn some(
+    pub(crate) fn some(
         reference_sequence_id: usize,
         alignment_start: Position,
         alignment_end: Position,
@@ -59,11 +70,13 @@ impl ReferenceSequenceContext {
         ))
     }
 
+    /// Returns whether records are placed on multiple reference sequences or are a mix of placed
+    /// and unplaced.
     pub fn is_many(&self) -> bool {
         matches!(self, Self::Many)
     }
 
-    pub fn update(
+    pub(crate) fn update(
         &mut self,
         reference_sequence_id: Option<usize>,
         alignment_start: Option<Position>,
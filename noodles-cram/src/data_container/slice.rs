@@ -3,16 +3,20 @@ pub(crate) mod header;
 
 pub use self::{builder::Builder, header::Header};
 
-use std::io;
+use std::{collections::HashSet, io};
 
 use noodles_core::Position;
 use noodles_fasta as fasta;
-use noodles_sam as sam;
+use noodles_sam::{
+    self as sam, header::record::value::map::reference_sequence::Md5Checksum,
+    record::data::field::Tag,
+};
 
 use super::{CompressionHeader, ReferenceSequenceContext};
 use crate::{
     container::Block,
     io::BitReader,
+    reader::{Options, ReadNamePrefix},
     record::{resolve, Features},
     Record,
 };
@@ -75,6 +79,15 @@ impl Slice {
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn records(&self, compression_header: &CompressionHeader) -> io::Result<Vec<Record>> {
+        self.read_records(compression_header, None)
+    }
+
+    // Only tags in `tag_filter`, if set, are decoded. The data of other tags is skipped.
+    fn read_records(
+        &self,
+        compression_header: &CompressionHeader,
+        tag_filter: Option<&HashSet<Tag>>,
+    ) -> io::Result<Vec<Record>> {
         use crate::reader::record::ExternalDataReaders;
 
         let core_data_reader = self
@@ -94,6 +107,7 @@ impl Slice {
             core_data_reader,
             external_data_readers,
             self.header.reference_sequence_context(),
+            tag_filter,
         );
 
         let record_count = self.header().record_count();
@@ -122,7 +136,46 @@ impl Slice {
         compression_header: &CompressionHeader,
         records: &mut [Record],
     ) -> io::Result<()> {
-        resolve_mates(records)?;
+        self.resolve(
+            reference_sequence_repository,
+            header,
+            compression_header,
+            records,
+            None,
+        )
+    }
+
+    // Decodes and resolves records using the given reader options.
+    pub(crate) fn decode_records(
+        &self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+        compression_header: &CompressionHeader,
+        options: &Options,
+    ) -> io::Result<Vec<Record>> {
+        let mut records = self.read_records(compression_header, options.tag_filter.as_ref())?;
+
+        self.resolve(
+            reference_sequence_repository,
+            header,
+            compression_header,
+            &mut records,
+            options.read_name_prefix.as_ref(),
+        )?;
+
+        Ok(records)
+    }
+
+    // Generated read names are prefixed with `read_name_prefix`, if set.
+    fn resolve(
+        &self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+        compression_header: &CompressionHeader,
+        records: &mut [Record],
+        read_name_prefix: Option<&ReadNamePrefix>,
+    ) -> io::Result<()> {
+        resolve_mates(records, read_name_prefix)?;
 
         resolve_bases(
             reference_sequence_repository,
@@ -138,7 +191,10 @@ impl Slice {
     }
 }
 
-fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
+fn resolve_mates(
+    records: &mut [Record],
+    read_name_prefix: Option<&ReadNamePrefix>,
+) -> io::Result<()> {
     let mut mate_indices: Vec<_> = records
         .iter()
         .enumerate()
@@ -149,8 +205,7 @@ fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
         let record = &mut records[i];
 
         if record.read_name().is_none() {
-            // SAFETY: `u64::to_string` is always a valid read name.
-            let read_name = record.id().to_string().parse().unwrap();
+            let read_name = generate_read_name(read_name_prefix, record.id());
             record.read_name = Some(read_name);
         }
 
@@ -199,6 +254,14 @@ fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
     Ok(())
 }
 
+fn generate_read_name(prefix: Option<&ReadNamePrefix>, id: u64) -> sam::record::ReadName {
+    match prefix {
+        Some(prefix) => prefix.generate_read_name(id),
+        // SAFETY: `u64::to_string` is always a valid read name.
+        None => id.to_string().parse().unwrap(),
+    }
+}
+
 fn set_mate(record: &mut Record, mate: &mut Record) {
     set_mate_chunk(
         &mut record.bam_bit_flags,
@@ -324,7 +387,7 @@ fn resolve_bases(
             let start = context.alignment_start();
             let end = context.alignment_end();
 
-            let actual_md5 = builder::calculate_normalized_sequence_digest(&sequence[start..=end]);
+            let actual_md5: [u8; 16] = Md5Checksum::from_sequence(&sequence[start..=end]).into();
             let expected_md5 = slice.header().reference_md5();
 
             if actual_md5 != expected_md5 {
@@ -465,7 +528,7 @@ mod tests {
                 .build(),
         ];
 
-        resolve_mates(&mut records)?;
+        resolve_mates(&mut records, None)?;
 
         let read_name_1 = ReadName::try_from(b"1".to_vec())?;
 
@@ -508,6 +571,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_generate_read_name() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(generate_read_name(None, 8), "8".parse()?);
+
+        let prefix = "sample".parse()?;
+        assert_eq!(generate_read_name(Some(&prefix), 8), "sample:8".parse()?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_template_size() -> Result<(), noodles_core::position::TryFromIntError> {
         use sam::record::Flags;
@@ -599,7 +672,7 @@ mod tests {
         let start = Position::try_from(1)?;
         let end = Position::try_from(2)?;
         let sequence = fasta::record::Sequence::from(b"ACGT".to_vec());
-        let reference_md5 = builder::calculate_normalized_sequence_digest(&sequence[start..=end]);
+        let reference_md5: [u8; 16] = Md5Checksum::from_sequence(&sequence[start..=end]).into();
 
         let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
             fasta::record::Definition::new("sq0", None),
@@ -1,9 +1,8 @@
 use std::{collections::HashMap, io};
 
 use bytes::Bytes;
-use md5::{Digest, Md5};
 use noodles_fasta as fasta;
-use noodles_sam as sam;
+use noodles_sam::{self as sam, header::record::value::map::reference_sequence::Md5Checksum};
 
 use crate::{
     codecs::Encoder,
@@ -114,7 +113,7 @@ impl Builder {
                 let (start, end) = (context.alignment_start(), context.alignment_end());
                 let sequence = &reference_sequence[start..=end];
 
-                calculate_normalized_sequence_digest(sequence)
+                Md5Checksum::from_sequence(sequence).into()
             }
             _ => [0; 16],
         };
@@ -281,52 +280,3 @@ fn set_downstream_mate(i: usize, record: &mut Record, j: usize, mate: &mut Recor
 fn set_detached(record: &mut Record) {
     record.cram_bit_flags.insert(Flags::DETACHED);
 }
-
-// _Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2 "Reference MD5 calculation"
-pub(crate) fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16] {
-    let mut hasher = Md5::new();
-
-    for &b in sequence {
-        // "All characters outside of the inclusive range 33 ('!') to 126 ('~') are stripped out."
-        if b.is_ascii_graphic() {
-            // "All lowercase characters are converted to uppercase."
-            hasher.update([b.to_ascii_uppercase()]);
-        }
-    }
-
-    hasher.finalize().into()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_calculate_normalized_sequence_digest() {
-        assert_eq!(
-            calculate_normalized_sequence_digest(b"ACGT"),
-            [
-                0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad, 0x13, 0x57, 0x22, 0xaa, 0x45, 0x91,
-                0x04, 0x3e
-            ]
-        );
-
-        assert_eq!(
-            calculate_normalized_sequence_digest(b"ACgt"),
-            [
-                0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad, 0x13, 0x57, 0x22, 0xaa, 0x45, 0x91,
-                0x04, 0x3e
-            ]
-        );
-
-        // _Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2 "Reference MD5
-        // calculation"
-        assert_eq!(
-            calculate_normalized_sequence_digest(b"ACGTACGTACGTACGTACGTACGT...12345!!!"),
-            [
-                0xdf, 0xab, 0xdb, 0xb3, 0x6e, 0x23, 0x9a, 0x6d, 0xa8, 0x89, 0x57, 0x84, 0x1f, 0x32,
-                0xb8, 0xe4
-            ]
-        );
-    }
-}
@@ -1,7 +1,10 @@
+use std::fmt;
+
 /// A CRAM file definition version.
 ///
-/// This is also called the format number.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// This is also called the format number. Versions are ordered by major version and then minor
+/// version.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Version {
     major: u8,
     minor: u8,
@@ -16,7 +19,7 @@ impl Version {
     /// use noodles_cram::file_definition::Version;
     /// let version = Version::new(3, 0);
     /// ```
-    pub fn new(major: u8, minor: u8) -> Self {
+    pub const fn new(major: u8, minor: u8) -> Self {
         Self { major, minor }
     }
 
@@ -45,6 +48,11 @@ impl Version {
     pub fn minor(&self) -> u8 {
         self.minor
     }
+
+    // Returns whether records in this version of the format can be decoded, i.e., CRAM 3.0 or 3.1.
+    pub(crate) fn is_supported(&self) -> bool {
+        (Self::new(3, 0)..=Self::new(3, 1)).contains(self)
+    }
 }
 
 impl Default for Version {
@@ -53,6 +61,12 @@ impl Default for Version {
     }
 }
 
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,4 +75,24 @@ mod tests {
     fn test_default() {
         assert_eq!(Version::default(), Version::new(3, 0));
     }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Version::new(2, 1) < Version::new(3, 0));
+        assert!(Version::new(3, 0) < Version::new(3, 1));
+        assert!(Version::new(3, 1) < Version::new(4, 0));
+    }
+
+    #[test]
+    fn test_is_supported() {
+        assert!(!Version::new(2, 1).is_supported());
+        assert!(Version::new(3, 0).is_supported());
+        assert!(Version::new(3, 1).is_supported());
+        assert!(!Version::new(4, 0).is_supported());
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Version::new(3, 1).to_string(), "3.1");
+    }
 }
@@ -5,11 +5,16 @@ pub(crate) mod container;
 pub(crate) mod data_container;
 pub(crate) mod header_container;
 pub(crate) mod num;
+mod options;
 mod query;
+pub mod read_name_prefix;
 pub(crate) mod record;
 mod records;
 
-pub use self::{builder::Builder, query::Query, records::Records};
+pub(crate) use self::options::Options;
+pub use self::{
+    builder::Builder, query::Query, read_name_prefix::ReadNamePrefix, records::Records,
+};
 
 use std::io::{self, Read, Seek, SeekFrom};
 
@@ -48,6 +53,7 @@ use crate::data_container::DataContainer;
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    options: Options,
     buf: BytesMut,
 }
 
@@ -115,9 +121,14 @@ where
         &self.reference_sequence_repository
     }
 
+    pub(crate) fn options(&self) -> &Options {
+        &self.options
+    }
+
     /// Reads the CRAM file definition.
     ///
-    /// The CRAM magic number is also checked.
+    /// The CRAM magic number is also checked. CRAM 3.0 and 3.1 are supported; other format versions
+    /// return an [`io::ErrorKind::Unsupported`] error.
     ///
     /// The position of the stream is expected to be at the start.
     ///
@@ -161,6 +172,37 @@ where
         read_header_container(&mut self.inner, &mut self.buf)
     }
 
+    /// Reads the SAM header.
+    ///
+    /// This reads the file definition, checking the CRAM magic number, and the file header. It is
+    /// equivalent to calling [`Self::read_file_definition`] and then [`Self::read_file_header`].
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::builder().add_comment("noodles-cram").build();
+    ///
+    /// let mut writer = cram::Writer::new(Vec::new());
+    /// writer.write_file_definition()?;
+    /// writer.write_file_header(&header)?;
+    /// writer.try_finish(&header)?;
+    ///
+    /// let data = writer.get_ref();
+    /// let mut reader = cram::Reader::new(&data[..]);
+    /// assert_eq!(reader.read_header()?, header);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_header(&mut self) -> io::Result<sam::Header> {
+        self.read_file_definition()?;
+        self.read_file_header()
+    }
+
     pub(crate) fn read_data_container_with_container_header(
         &mut self,
     ) -> io::Result<Option<(crate::data_container::Header, DataContainer)>> {
@@ -194,6 +236,139 @@ where
         read_data_container(&mut self.inner, &mut self.buf)
     }
 
+    /// Reads a data container header.
+    ///
+    /// This returns `None` if the container header is the EOF container header, which signals the
+    /// end of the stream.
+    ///
+    /// The stream is left positioned at the start of the container body, which must then be
+    /// either read using [`Self::read_data_container_body`] or skipped using
+    /// [`Self::skip_data_container_body`]. This allows scanning containers without decoding their
+    /// records.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram::{self as cram, data_container::ReferenceSequenceContext};
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// while let Some(header) = reader.read_data_container_header()? {
+    ///     match header.reference_sequence_context() {
+    ///         ReferenceSequenceContext::Some(context) if context.reference_sequence_id() == 0 => {
+    ///             let container = reader.read_data_container_body(&header)?;
+    ///             // ...
+    ///         }
+    ///         _ => reader.skip_data_container_body(&header)?,
+    ///     }
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_data_container_header(
+        &mut self,
+    ) -> io::Result<Option<crate::data_container::Header>> {
+        use self::data_container::header::read_header;
+        read_header(&mut self.inner)
+    }
+
+    /// Reads the body of a data container with the given header.
+    ///
+    /// The stream is expected to be directly after the data container header, i.e., after a call
+    /// to [`Self::read_data_container_header`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// if let Some(header) = reader.read_data_container_header()? {
+    ///     let container = reader.read_data_container_body(&header)?;
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_data_container_body(
+        &mut self,
+        header: &crate::data_container::Header,
+    ) -> io::Result<DataContainer> {
+        use self::data_container::read_data_container_body;
+        read_data_container_body(&mut self.inner, &mut self.buf, header)
+    }
+
+    /// Skips the body of a data container with the given header.
+    ///
+    /// The stream is expected to be directly after the data container header, i.e., after a call
+    /// to [`Self::read_data_container_header`]. After skipping, the stream is positioned at the
+    /// start of the next container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// if let Some(header) = reader.read_data_container_header()? {
+    ///     reader.skip_data_container_body(&header)?;
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn skip_data_container_body(
+        &mut self,
+        header: &crate::data_container::Header,
+    ) -> io::Result<()> {
+        use self::data_container::skip_data_container_body;
+        skip_data_container_body(&mut self.inner, header)
+    }
+
+    /// Skips a data container.
+    ///
+    /// This reads the data container header and skips the container body by its length, i.e.,
+    /// without reading or decompressing any of its blocks. The stream is left positioned at the
+    /// start of the next container.
+    ///
+    /// This returns the header of the skipped container or `None` if the container header is the
+    /// EOF container header, which signals the end of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// let mut container_count = 0;
+    ///
+    /// while reader.skip_data_container()?.is_some() {
+    ///     container_count += 1;
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn skip_data_container(&mut self) -> io::Result<Option<crate::data_container::Header>> {
+        let header = match self.read_data_container_header()? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+
+        self.skip_data_container_body(&header)?;
+
+        Ok(Some(header))
+    }
+
     /// Returns a iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be at the start of a data container.
@@ -219,6 +394,39 @@ where
     pub fn records<'r>(&'r mut self, header: &'r sam::Header) -> Records<'r, R> {
         Records::new(self, header)
     }
+
+    /// Returns an iterator over records as alignment records starting from the current stream
+    /// position.
+    ///
+    /// This is like [`Self::records`] but converts each CRAM record to an alignment record. Any
+    /// error from reading or converting a record is returned by the iterator.
+    ///
+    /// The stream is expected to be at the start of a data container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    ///
+    /// let header = reader.read_file_header()?;
+    ///
+    /// for result in reader.sam_records(&header) {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn sam_records<'r>(
+        &'r mut self,
+        header: &'r sam::Header,
+    ) -> impl Iterator<Item = io::Result<sam::alignment::Record>> + 'r {
+        self.records(header)
+            .map(|result| result.and_then(|record| record.try_into_alignment_record(header)))
+    }
 }
 
 impl<R> Reader<R>
@@ -315,18 +523,14 @@ where
     R: Read,
 {
     fn read_alignment_header(&mut self) -> io::Result<sam::Header> {
-        self.read_file_definition()?;
-        self.read_file_header()
+        self.read_header()
     }
 
     fn alignment_records<'a>(
         &'a mut self,
         header: &'a sam::Header,
     ) -> Box<dyn Iterator<Item = io::Result<sam::alignment::Record>> + 'a> {
-        Box::new(
-            self.records(header)
-                .map(|result| result.and_then(|record| record.try_into_alignment_record(header))),
-        )
+        Box::new(self.sam_records(header))
     }
 }
 
@@ -353,7 +557,17 @@ where
 {
     let mut buf = [0; 2];
     reader.read_exact(&mut buf)?;
-    Ok(Version::new(buf[0], buf[1]))
+
+    let version = Version::new(buf[0], buf[1]);
+
+    if version.is_supported() {
+        Ok(version)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("unsupported CRAM version: {version}"),
+        ))
+    }
 }
 
 fn read_file_id<R>(reader: &mut R) -> io::Result<[u8; 20]>
@@ -389,6 +603,546 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_file_definition_with_cram_3_1() -> Result<(), Box<dyn std::error::Error>> {
+        let data = [
+            0x43, 0x52, 0x41, 0x4d, // magic number = b"CRAM"
+            0x03, 0x01, // format version = (3, 1)
+            0x00, 0x68, 0xac, 0xf3, 0x06, 0x4d, 0xaa, 0x1e, 0x29, 0xa4, 0xa0, 0x8c, 0x56, 0xee,
+            0x91, 0x9b, 0x91, 0x04, 0x21, 0x1f, // file ID
+        ];
+
+        let mut reader = Reader::new(&data[..]);
+        let actual = reader.read_file_definition()?;
+
+        let file_id = <[u8; 20]>::try_from(&data[6..])?;
+        let expected = FileDefinition::new(Version::new(3, 1), file_id);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_format() -> io::Result<()> {
+        let mut reader = &[0x03, 0x00][..];
+        assert_eq!(read_format(&mut reader)?, Version::new(3, 0));
+
+        let mut reader = &[0x03, 0x01][..];
+        assert_eq!(read_format(&mut reader)?, Version::new(3, 1));
+
+        for data in [[0x02, 0x01], [0x04, 0x00]] {
+            let mut reader = &data[..];
+            assert!(matches!(
+                read_format(&mut reader),
+                Err(e) if e.kind() == io::ErrorKind::Unsupported
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::record::value::{map::ReadGroup, Map};
+
+        use crate::Writer;
+
+        let header = sam::Header::builder()
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_comment("noodles-cram")
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        assert_eq!(reader.read_header()?, header);
+
+        let mut reader = Reader::new(&data[4..]);
+        assert!(matches!(
+            reader.read_header(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sam_records() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{alignment::Record, AlignmentWriter};
+
+        use crate::Writer;
+
+        let header = sam::Header::default();
+
+        let records = [
+            Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            Record::builder().set_read_name("r1".parse()?).build(),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let actual: Vec<_> = reader.sam_records(&header).collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, records);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sam_records_with_decode_error() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{alignment::Record, AlignmentWriter};
+
+        use crate::{writer::container::EOF, Writer};
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_alignment_record(&header, &Record::default())?;
+        writer.try_finish(&header)?;
+
+        let mut data = writer.get_ref().clone();
+
+        // Corrupt the CRC32 of the last block in the data container.
+        let i = data.len() - EOF.len() - 1;
+        data[i] ^= 0xff;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut records = reader.sam_records(&header);
+
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sam_records_with_conversion_error() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{
+            alignment::Record,
+            header::record::value::{map::ReadGroup, Map},
+            record::data::field::{tag, Value},
+            AlignmentWriter,
+        };
+
+        use crate::Writer;
+
+        let header = sam::Header::builder()
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .build();
+
+        let data = [(tag::READ_GROUP, Value::String(String::from("rg0")))]
+            .into_iter()
+            .collect();
+        let record = Record::builder().set_data(data).build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        // The CRAM record references read group 0, which does not exist in this header.
+        let header = sam::Header::default();
+        let mut records = reader.sam_records(&header);
+
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData && e.to_string() == "invalid read group ID"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_read_name_prefix() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{collections::HashSet, num::NonZeroUsize};
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::Record,
+            header::record::value::{map::ReferenceSequence, Map},
+            record::Flags,
+            AlignmentWriter,
+        };
+
+        use crate::writer;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .preserve_read_names(false)
+            .build_with_writer(Vec::new());
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        // Read names are only omitted for records whose mates are in the same slice.
+        for name in ["r0", "r1"] {
+            for segment_flag in [Flags::FIRST_SEGMENT, Flags::LAST_SEGMENT] {
+                let record = Record::builder()
+                    .set_read_name(name.parse()?)
+                    .set_flags(Flags::SEGMENTED | segment_flag)
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::MIN)
+                    .set_cigar("4M".parse()?)
+                    .set_sequence("ACGT".parse()?)
+                    .set_quality_scores("NDLS".parse()?)
+                    .build();
+
+                writer.write_alignment_record(&header, &record)?;
+            }
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let read_name_prefix: ReadNamePrefix = "sample".parse()?;
+
+        let read_names = || -> io::Result<Vec<_>> {
+            let mut reader = Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .set_read_name_prefix(read_name_prefix.clone())
+                .build_from_reader(&data[..]);
+
+            reader.read_file_definition()?;
+            reader.read_file_header()?;
+
+            reader
+                .records(&header)
+                .map(|result| result.map(|record| record.read_name().cloned()))
+                .collect()
+        };
+
+        let actual = read_names()?;
+
+        let expected = vec![
+            Some("sample:0".parse()?),
+            Some("sample:0".parse()?),
+            Some("sample:2".parse()?),
+            Some("sample:2".parse()?),
+        ];
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual.iter().collect::<HashSet<_>>().len(), 2);
+        assert_eq!(read_names()?, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sam_records_with_tag_filter() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{
+            alignment::Record,
+            header::record::value::{map::ReadGroup, Map},
+            record::data::field::tag,
+            AlignmentWriter,
+        };
+
+        use crate::Writer;
+
+        let header = sam::Header::builder()
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_read_group("rg1", Map::<ReadGroup>::default())
+            .build();
+
+        let records = [
+            Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_data("RG:Z:rg0\tNM:i:1\tCO:Z:noodles".parse()?)
+                .build(),
+            Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_sequence("TGCA".parse()?)
+                .set_data("CO:Z:cram\tRG:Z:rg1\tXA:B:S,8,13".parse()?)
+                .build(),
+            Record::builder().set_read_name("r2".parse()?).build(),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Builder::default()
+            .set_tag_filter([tag::EDIT_DISTANCE])
+            .build_from_reader(&data[..]);
+
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let actual: Vec<_> = reader.sam_records(&header).collect::<io::Result<_>>()?;
+
+        assert_eq!(actual.len(), records.len());
+
+        // The read group is a data series in CRAM and is not subject to the tag filter.
+        let expected_data: [sam::record::Data; 3] = [
+            "RG:Z:rg0\tNM:i:1".parse()?,
+            "RG:Z:rg1".parse()?,
+            sam::record::Data::default(),
+        ];
+
+        for (actual_record, (expected_record, expected_data)) in
+            actual.iter().zip(records.iter().zip(expected_data.iter()))
+        {
+            assert_eq!(actual_record.read_name(), expected_record.read_name());
+            assert_eq!(actual_record.sequence(), expected_record.sequence());
+            assert_eq!(
+                actual_record.quality_scores(),
+                expected_record.quality_scores()
+            );
+
+            let actual_data = actual_record.data();
+            assert_eq!(actual_data.len(), expected_data.len());
+
+            for (tag, value) in expected_data.iter() {
+                assert_eq!(actual_data.get(&tag), Some(value));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_container_header() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{alignment::Record, AlignmentWriter};
+
+        use crate::{data_container::ReferenceSequenceContext, Writer};
+
+        // The writer starts a new container every 10240 records.
+        const RECORD_COUNT: usize = 10241;
+
+        let header = sam::Header::default();
+        let record = Record::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for _ in 0..RECORD_COUNT {
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let container_header = reader
+            .read_data_container_header()?
+            .ok_or("missing container header")?;
+        assert_eq!(container_header.record_count(), 10240);
+        assert_eq!(container_header.record_counter(), 0);
+        assert_eq!(
+            container_header.reference_sequence_context(),
+            ReferenceSequenceContext::None
+        );
+        reader.skip_data_container_body(&container_header)?;
+
+        let container_header = reader
+            .read_data_container_header()?
+            .ok_or("missing container header")?;
+        assert_eq!(container_header.record_count(), 1);
+        assert_eq!(container_header.record_counter(), 10240);
+
+        let container = reader.read_data_container_body(&container_header)?;
+        let records = container.slices()[0].records(container.compression_header())?;
+        assert_eq!(records.len(), 1);
+
+        assert!(reader.read_data_container_header()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_data_container() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{alignment::Record, AlignmentWriter};
+
+        use crate::Writer;
+
+        // The writer starts a new container every 10240 records.
+        const RECORD_COUNT: usize = 10241;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for i in 0..RECORD_COUNT {
+            let record = Record::builder()
+                .set_read_name(format!("r{i}").parse()?)
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let container_header = reader
+            .skip_data_container()?
+            .ok_or("missing container header")?;
+        assert_eq!(container_header.record_count(), 10240);
+
+        let actual: Vec<_> = reader.sam_records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), 1);
+        assert_eq!(
+            actual[0].read_name().map(|name| name.as_ref()),
+            Some("r10240")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_indexed_reference_sequence_repository(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Cursor, num::NonZeroUsize};
+
+        use fasta::{fai, repository::adapters::IndexedReader};
+        use noodles_core::Position;
+        use sam::{
+            alignment::Record,
+            header::record::value::{map::ReferenceSequence, Map},
+            AlignmentWriter,
+        };
+
+        const FASTA_DATA: &[u8] = b">sq0\nACGTACGT\n>sq1\nTTCACCCA\n>sq2\nGATCTTAC\n";
+
+        let build_repository = || {
+            let index = vec![
+                fai::Record::new("sq0", 8, 5, 8, 9),
+                fai::Record::new("sq1", 8, 19, 8, 9),
+                fai::Record::new("sq2", 8, 33, 8, 9),
+            ];
+
+            let reader = fasta::IndexedReader::new(Cursor::new(FASTA_DATA), index);
+            fasta::Repository::new(IndexedReader::new(reader))
+        };
+
+        let reference_sequence_length = NonZeroUsize::try_from(8)?;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(reference_sequence_length),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(reference_sequence_length),
+            )
+            .add_reference_sequence(
+                "sq2".parse()?,
+                Map::<ReferenceSequence>::new(reference_sequence_length),
+            )
+            .build();
+
+        let record = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar("4M".parse()?)
+            .set_sequence("TCAC".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build();
+
+        let mut writer = crate::writer::Builder::default()
+            .set_reference_sequence_repository(build_repository())
+            .build_with_writer(Vec::new());
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let repository = build_repository();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_reader(&data[..]);
+
+        reader.read_file_definition()?;
+        let header = reader.read_file_header()?;
+
+        let actual: Vec<_> = reader.sam_records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(actual, [record]);
+
+        assert_eq!(repository.len(), 1);
+        assert_eq!(
+            repository.get("sq1").transpose()?,
+            Some(fasta::record::Sequence::from(b"TTCACCCA".to_vec()))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_magic_number() {
         let data = b"CRAM";
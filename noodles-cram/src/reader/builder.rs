@@ -6,13 +6,15 @@ use std::{
 
 use bytes::BytesMut;
 use noodles_fasta as fasta;
+use noodles_sam::record::data::field::Tag;
 
-use super::Reader;
+use super::{Options, ReadNamePrefix, Reader};
 
 /// A CRAM reader builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    options: Options,
 }
 
 impl Builder {
@@ -37,6 +39,49 @@ impl Builder {
         self
     }
 
+    /// Sets the tags to decode.
+    ///
+    /// When set, only these tags are decoded into record data. The values of all other tags are
+    /// skipped. By default, all tags are decoded.
+    ///
+    /// The read group (`RG`) is not subject to the filter. CRAM stores it as a data series rather
+    /// than a tag, and it is always added to the record data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// use noodles_sam::record::data::field::tag;
+    ///
+    /// let builder = cram::reader::Builder::default().set_tag_filter([tag::EDIT_DISTANCE]);
+    /// ```
+    pub fn set_tag_filter<I>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        self.options.tag_filter = Some(tags.into_iter().collect());
+        self
+    }
+
+    /// Sets the prefix of generated read names.
+    ///
+    /// When read names are not stored in the file, a name is generated for each record from its
+    /// record counter, which is unique and deterministic within a file. By default, the name is
+    /// only the record counter, e.g., `8`. If a prefix is set, the name is
+    /// `<prefix>:<record counter>`, e.g., `sample:8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::reader::Builder::default().set_read_name_prefix("sample".parse()?);
+    /// # Ok::<_, cram::reader::read_name_prefix::ParseError>(())
+    /// ```
+    pub fn set_read_name_prefix(mut self, read_name_prefix: ReadNamePrefix) -> Self {
+        self.options.read_name_prefix = Some(read_name_prefix);
+        self
+    }
+
     /// Builds a CRAM reader from a path.
     ///
     /// # Examples
@@ -70,6 +115,7 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            options: self.options,
             buf: BytesMut::new(),
         }
     }
@@ -81,9 +81,9 @@ where
         6 => Ok(CompressionMethod::AdaptiveArithmeticCoding),
         7 => Ok(CompressionMethod::Fqzcomp),
         8 => Ok(CompressionMethod::NameTokenizer),
-        _ => Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "invalid compression method",
+        n => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("unsupported compression method: {n}"),
         )),
     }
 }
@@ -200,7 +200,7 @@ mod tests {
         let mut src = &[0x09][..];
         assert!(matches!(
             get_compression_method(&mut src),
-            Err(e) if e.kind() == io::ErrorKind::InvalidData
+            Err(e) if e.kind() == io::ErrorKind::Unsupported
         ));
 
         Ok(())
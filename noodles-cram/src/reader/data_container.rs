@@ -23,21 +23,7 @@ where
         None => return Ok(None),
     };
 
-    buf.resize(header.len(), 0);
-    reader.read_exact(buf)?;
-    let mut buf = buf.split().freeze();
-
-    let compression_header = read_compression_header_from_block(&mut buf)?;
-
-    let slice_count = header.landmarks().len();
-    let mut slices = Vec::with_capacity(slice_count);
-
-    for _ in 0..slice_count {
-        let slice = read_slice(&mut buf)?;
-        slices.push(slice);
-    }
-
-    Ok(Some(DataContainer::new(compression_header, slices)))
+    read_data_container_body(reader, buf, &header).map(Some)
 }
 
 pub fn read_data_container_with_container_header<R>(
@@ -52,6 +38,19 @@ where
         None => return Ok(None),
     };
 
+    let data_container = read_data_container_body(reader, buf, &header)?;
+
+    Ok(Some((header, data_container)))
+}
+
+pub fn read_data_container_body<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    header: &crate::data_container::Header,
+) -> io::Result<DataContainer>
+where
+    R: Read,
+{
     buf.resize(header.len(), 0);
     reader.read_exact(buf)?;
     let mut buf = buf.split().freeze();
@@ -66,13 +65,30 @@ where
         slices.push(slice);
     }
 
-    let data_container = DataContainer::new(compression_header, slices);
+    Ok(DataContainer::new(compression_header, slices))
+}
 
-    Ok(Some((header, data_container)))
+pub fn skip_data_container_body<R>(
+    reader: &mut R,
+    header: &crate::data_container::Header,
+) -> io::Result<()>
+where
+    R: Read,
+{
+    let len =
+        u64::try_from(header.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let n = io::copy(&mut reader.take(len), &mut io::sink())?;
+
+    if n == len {
+        Ok(())
+    } else {
+        Err(io::Error::from(io::ErrorKind::UnexpectedEof))
+    }
 }
 
 pub(crate) fn read_compression_header_from_block(src: &mut Bytes) -> io::Result<CompressionHeader> {
-    use super::container::read_block;
+    use super::{container::read_block, record::validate_data_series_encodings};
 
     let block = read_block(src)?;
 
@@ -88,5 +104,9 @@ pub(crate) fn read_compression_header_from_block(src: &mut Bytes) -> io::Result<
     }
 
     let mut data = block.decompressed_data()?;
-    get_compression_header(&mut data)
+    let compression_header = get_compression_header(&mut data)?;
+
+    validate_data_series_encodings(&compression_header)?;
+
+    Ok(compression_header)
 }
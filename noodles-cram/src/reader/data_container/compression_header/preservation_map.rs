@@ -195,6 +195,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_preservation_map_with_substitution_matrix() -> io::Result<()> {
+        use crate::record::feature::substitution::Base;
+
+        let mut data = Bytes::from_static(&[
+            0x0c, // data.len = 12
+            0x02, // map.len = 2
+            0x53, 0x4d, // key = "SM"
+            // [[T, G, C, N], [A, G, T, N], [N, A, C, T], [G, N, A, C], [C, G, T, A]]
+            0x93, 0x1b, 0x6c, 0xb1, 0xc6, // substitution matrix
+            0x54, 0x44, // key = "TD"
+            0x01, 0x00, // tag IDs dictionary = []
+        ]);
+
+        let preservation_map = get_preservation_map(&mut data)?;
+        let substitution_matrix = preservation_map.substitution_matrix();
+
+        assert_eq!(substitution_matrix.get(Base::A, 0), Base::T);
+        assert_eq!(substitution_matrix.get(Base::G, 0), Base::N);
+        assert_eq!(substitution_matrix.get(Base::N, 3), Base::A);
+
+        let expected = "  0 1 2 3
+A T G C N
+C A G T N
+G N A C T
+T G N A C
+N C G T A";
+
+        assert_eq!(substitution_matrix.to_string(), expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_preservation_map_with_no_substitution_matrix() {
         let mut data = Bytes::from_static(&[
@@ -24,12 +24,17 @@ where
     reader.read_exact(buf)?;
     let mut buf = buf.split().freeze();
 
-    read_raw_sam_header_from_block(&mut buf).and_then(|s| {
-        s.parse()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })
+    read_sam_header_from_block(&mut buf)
 }
 
+fn read_sam_header_from_block(src: &mut Bytes) -> io::Result<sam::Header> {
+    use super::container::read_block;
+
+    let block = read_block(src)?;
+    read_sam_header(&block)
+}
+
+#[cfg(feature = "async")]
 pub fn read_raw_sam_header_from_block(src: &mut Bytes) -> io::Result<String> {
     use super::container::read_block;
 
@@ -37,7 +42,27 @@ pub fn read_raw_sam_header_from_block(src: &mut Bytes) -> io::Result<String> {
     read_raw_sam_header(&block)
 }
 
+#[cfg(any(feature = "async", test))]
 fn read_raw_sam_header(block: &Block) -> io::Result<String> {
+    let data = read_raw_sam_header_data(block)?;
+
+    str::from_utf8(&data[..])
+        .map(|s| s.into())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+// Parses the header directly from the decompressed block data rather than first copying it to an
+// owned `String`.
+fn read_sam_header(block: &Block) -> io::Result<sam::Header> {
+    let data = read_raw_sam_header_data(block)?;
+
+    str::from_utf8(&data[..])
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn read_raw_sam_header_data(block: &Block) -> io::Result<Bytes> {
     const EXPECTED_CONTENT_TYPE: ContentType = ContentType::FileHeader;
 
     if !matches!(
@@ -73,9 +98,7 @@ fn read_raw_sam_header(block: &Block) -> io::Result<String> {
 
     data.truncate(len);
 
-    str::from_utf8(&data[..])
-        .map(|s| s.into())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    Ok(data)
 }
 
 #[cfg(test)]
@@ -109,6 +132,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_sam_header_with_many_reference_sequences() -> io::Result<()> {
+        use std::fmt::Write;
+
+        const REFERENCE_SEQUENCE_COUNT: usize = 200_000;
+
+        let mut raw_header = String::from("@HD\tVN:1.6\n");
+
+        for i in 0..REFERENCE_SEQUENCE_COUNT {
+            writeln!(raw_header, "@SQ\tSN:sq{i}\tLN:8")
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        }
+
+        let header_data = raw_header.into_bytes();
+        let header_data_len = i32::try_from(header_data.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let mut data = Vec::new();
+        data.put_i32_le(header_data_len);
+        data.extend(&header_data);
+
+        let block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(data.into())
+            .build();
+
+        let header = read_sam_header(&block)?;
+
+        assert_eq!(header.reference_sequences().len(), REFERENCE_SEQUENCE_COUNT);
+        assert!(header.reference_sequences().contains_key("sq199999"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_raw_sam_header_with_invalid_compression_method() {
         let block = Block::builder()
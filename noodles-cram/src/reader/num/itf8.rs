@@ -110,6 +110,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_itf8_with_truncated_input() {
+        fn t(mut data: &[u8]) {
+            assert!(matches!(
+                get_itf8(&mut data),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+            ));
+        }
+
+        t(&[]);
+        t(&[0x87]);
+        t(&[0xc7, 0x55]);
+        t(&[0xe7, 0x55, 0x99]);
+        t(&[0xf7, 0x55, 0x99, 0x66]);
+    }
+
     #[test]
     fn test_read_itf8() -> io::Result<()> {
         fn t(mut data: &[u8], expected: i32) -> io::Result<()> {
@@ -130,4 +146,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_itf8_with_truncated_input() {
+        fn t(mut data: &[u8]) {
+            assert!(matches!(
+                read_itf8(&mut data),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+            ));
+        }
+
+        t(&[]);
+        t(&[0x87]);
+        t(&[0xc7, 0x55]);
+        t(&[0xe7, 0x55, 0x99]);
+        t(&[0xf7, 0x55, 0x99, 0x66]);
+    }
 }
@@ -161,6 +161,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_ltf8_with_truncated_input() {
+        fn t(mut data: &[u8]) {
+            assert!(matches!(
+                get_ltf8(&mut data),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+            ));
+        }
+
+        t(&[]);
+        t(&[0x80]);
+        t(&[0xf8, 0x55, 0xaa, 0xcc, 0x33]);
+        t(&[0xff, 0x55, 0xaa, 0xcc, 0x33, 0xe3, 0x1c, 0xf0]);
+    }
+
+    #[test]
+    fn test_get_ltf8_consumes_only_the_encoded_length() -> io::Result<()> {
+        // The number of leading ones in the first byte fixes the encoded length to at most 9
+        // bytes, so unlike a continuation bit encoding (e.g., uint7), LTF8 cannot be over-long.
+        let mut data = &[
+            0xff, 0x55, 0xaa, 0xcc, 0x33, 0xe3, 0x1c, 0xf0, 0x0f, 0xff, 0x80,
+        ][..];
+        assert_eq!(get_ltf8(&mut data)?, 6172970762490408975);
+        assert_eq!(data, [0xff, 0x80]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_ltf8() -> io::Result<()> {
         fn t(mut data: &[u8], expected: i64) -> io::Result<()> {
@@ -191,4 +219,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_ltf8_with_truncated_input() {
+        fn t(mut data: &[u8]) {
+            assert!(matches!(
+                read_ltf8(&mut data),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+            ));
+        }
+
+        t(&[]);
+        t(&[0x80]);
+        t(&[0xf8, 0x55, 0xaa, 0xcc, 0x33]);
+        t(&[0xff, 0x55, 0xaa, 0xcc, 0x33, 0xe3, 0x1c, 0xf0]);
+    }
+
+    #[test]
+    fn test_read_ltf8_consumes_only_the_encoded_length() -> io::Result<()> {
+        let mut data = &[
+            0xff, 0x55, 0xaa, 0xcc, 0x33, 0xe3, 0x1c, 0xf0, 0x0f, 0xff, 0x80,
+        ][..];
+        assert_eq!(read_ltf8(&mut data)?, 6172970762490408975);
+        assert_eq!(data, [0xff, 0x80]);
+
+        Ok(())
+    }
 }
@@ -6,20 +6,33 @@ pub fn read_uint7<R>(reader: &mut R) -> io::Result<u32>
 where
     R: Read,
 {
+    // ceil(32 / 7)
+    const MAX_LEN: usize = 5;
+
     let mut n = 0;
 
-    loop {
+    for _ in 0..MAX_LEN {
         let b = reader.read_u8().map(u32::from)?;
 
+        if n > u32::MAX >> 7 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "uint7 value overflows u32",
+            ));
+        }
+
         n <<= 7;
         n |= b & 0x7f;
 
         if b & 0x80 == 0 {
-            break;
+            return Ok(n);
         }
     }
 
-    Ok(n)
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "uint7 value is too long",
+    ))
 }
 
 #[cfg(test)]
@@ -44,7 +57,32 @@ mod tests {
         t(&[0x81, 0x80, 0x80, 0x00], 2097152)?;
         t(&[0xc0, 0x80, 0x80, 0x00], 134217728)?;
         t(&[0xff, 0xff, 0xff, 0x7f], 268435455)?;
+        t(&[0x8f, 0xff, 0xff, 0xff, 0x7f], u32::MAX)?;
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_uint7_with_invalid_input() {
+        let data = [0x81, 0x80];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_uint7(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let data = [0x90, 0x80, 0x80, 0x80, 0x00];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_uint7(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = [0x80; 16];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_uint7(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
 }
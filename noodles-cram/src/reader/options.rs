@@ -0,0 +1,11 @@
+use std::collections::HashSet;
+
+use noodles_sam::record::data::field::Tag;
+
+use super::ReadNamePrefix;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    pub tag_filter: Option<HashSet<Tag>>,
+    pub read_name_prefix: Option<ReadNamePrefix>,
+}
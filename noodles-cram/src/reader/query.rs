@@ -76,16 +76,12 @@ where
             .map(|slice| {
                 let compression_header = container.compression_header();
 
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
+                slice.decode_records(
+                    self.reader.reference_sequence_repository(),
+                    self.header,
+                    compression_header,
+                    self.reader.options(),
+                )
             })
             .collect::<Result<Vec<_>, _>>();
 
@@ -114,12 +110,10 @@ where
         loop {
             match self.records.next() {
                 Some(r) => {
-                    if let (Some(start), Some(end)) = (r.alignment_start(), r.alignment_end()) {
-                        let alignment_interval = (start..=end).into();
-
-                        if self.interval.intersects(alignment_interval) {
-                            return Some(Ok(r));
-                        }
+                    if r.reference_sequence_id() == Some(self.reference_sequence_id)
+                        && r.overlaps(self.interval)
+                    {
+                        return Some(Ok(r));
                     }
                 }
                 None => match self.read_next_container() {
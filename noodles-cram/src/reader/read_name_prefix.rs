@@ -0,0 +1,117 @@
+//! CRAM reader read name prefix.
+
+use std::{error, fmt, str::FromStr};
+
+use noodles_sam::record::ReadName;
+
+const DELIMITER: char = ':';
+
+// § 1.4 "The alignment section: mandatory fields" (2021-06-03): "`[!-?A-~]{1,254}`".
+const MAX_READ_NAME_LENGTH: usize = 254;
+
+// The number of digits of `u64::MAX`.
+const MAX_RECORD_COUNTER_LENGTH: usize = 20;
+
+const MAX_LENGTH: usize = MAX_READ_NAME_LENGTH - 1 - MAX_RECORD_COUNTER_LENGTH;
+
+/// A prefix of generated read names.
+///
+/// A generated read name is `<prefix>:<record counter>`, e.g., `sample:8`. The prefix is
+/// validated when it is created such that every generated read name is a valid SAM read name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadNamePrefix(String);
+
+impl ReadNamePrefix {
+    pub(crate) fn generate_read_name(&self, record_counter: u64) -> ReadName {
+        // SAFETY: The prefix is valid, and the delimiter and record counter are valid read name
+        // characters that fit within the maximum read name length.
+        format!("{}{DELIMITER}{record_counter}", self.0)
+            .parse()
+            .unwrap()
+    }
+}
+
+impl AsRef<str> for ReadNamePrefix {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// An error returned when a read name prefix fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is too long.
+    TooLong(usize),
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::TooLong(len) => write!(f, "input too long: expected <= {MAX_LENGTH}, got {len}"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for ReadNamePrefix {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Err(ParseError::Empty)
+        } else if s.len() > MAX_LENGTH {
+            Err(ParseError::TooLong(s.len()))
+        } else if !s.bytes().all(is_valid_name_char) {
+            Err(ParseError::Invalid)
+        } else {
+            Ok(Self(s.into()))
+        }
+    }
+}
+
+fn is_valid_name_char(b: u8) -> bool {
+    b.is_ascii_graphic() && b != b'@'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_read_name() -> Result<(), Box<dyn std::error::Error>> {
+        let prefix: ReadNamePrefix = "sample".parse()?;
+        assert_eq!(prefix.generate_read_name(8), "sample:8".parse()?);
+
+        let prefix: ReadNamePrefix = "n".repeat(MAX_LENGTH).parse()?;
+        let read_name = prefix.generate_read_name(u64::MAX);
+        assert_eq!(read_name.len(), MAX_READ_NAME_LENGTH);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("sample".parse(), Ok(ReadNamePrefix(String::from("sample"))));
+
+        assert_eq!("".parse::<ReadNamePrefix>(), Err(ParseError::Empty));
+        assert_eq!(
+            "n".repeat(MAX_LENGTH + 1).parse::<ReadNamePrefix>(),
+            Err(ParseError::TooLong(MAX_LENGTH + 1))
+        );
+        assert_eq!(
+            "sample 0".parse::<ReadNamePrefix>(),
+            Err(ParseError::Invalid)
+        );
+        assert_eq!(
+            "@sample".parse::<ReadNamePrefix>(),
+            Err(ParseError::Invalid)
+        );
+    }
+}
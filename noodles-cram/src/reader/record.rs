@@ -2,21 +2,22 @@ mod external_data_readers;
 
 pub use external_data_readers::ExternalDataReaders;
 
-use std::{error, fmt, io};
+use std::{collections::HashSet, error, fmt, io};
 
 use bytes::Buf;
 use noodles_bam as bam;
 use noodles_core::Position;
 use noodles_sam::{
     self as sam,
-    record::{quality_scores::Score, sequence::Base},
+    record::{data::field::Tag, quality_scores::Score, sequence::Base},
 };
 
 use crate::{
     container::block,
     data_container::{
         compression_header::{
-            data_series_encoding_map::DataSeries, preservation_map::tag_ids_dictionary,
+            data_series_encoding_map::DataSeries, encoding::Kind as EncodingKind,
+            preservation_map::tag_ids_dictionary,
         },
         CompressionHeader, ReferenceSequenceContext,
     },
@@ -33,6 +34,8 @@ use crate::{
 pub enum ReadRecordError {
     MissingDataSeriesEncoding(DataSeries),
     MissingTagEncoding(tag_ids_dictionary::Key),
+    UnsupportedDataSeriesEncodings(Vec<(DataSeries, EncodingKind)>),
+    UnsupportedTagEncodings(Vec<(tag_ids_dictionary::Key, EncodingKind)>),
 }
 
 impl error::Error for ReadRecordError {}
@@ -44,8 +47,90 @@ impl fmt::Display for ReadRecordError {
                 write!(f, "missing data series encoding: {data_series:?}")
             }
             Self::MissingTagEncoding(key) => write!(f, "missing tag encoding: {key:?}"),
+            Self::UnsupportedDataSeriesEncodings(data_series_encodings) => {
+                f.write_str("unsupported data series encodings: ")?;
+
+                for (i, (data_series, kind)) in data_series_encodings.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "{data_series:?} ({kind:?})")?;
+                }
+
+                Ok(())
+            }
+            Self::UnsupportedTagEncodings(tag_encodings) => {
+                f.write_str("unsupported tag encodings: ")?;
+
+                for (i, (key, kind)) in tag_encodings.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "{key:?} ({kind:?})")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Checks that every data series and tag encoding in the compression header can be decoded.
+///
+/// This is checked once per data container, when its compression header is read, so that reading
+/// fails early with a list of unsupported encodings rather than partway through a record. Only the
+/// tag encodings of keys in the tag IDs dictionary are checked, as these are the only tags that are
+/// decoded.
+pub fn validate_data_series_encodings(compression_header: &CompressionHeader) -> io::Result<()> {
+    let unsupported_kinds = compression_header
+        .data_series_encoding_map()
+        .unsupported_decoding_kinds();
+
+    if !unsupported_kinds.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            ReadRecordError::UnsupportedDataSeriesEncodings(unsupported_kinds),
+        ));
+    }
+
+    let unsupported_tag_kinds = unsupported_tag_decoding_kinds(compression_header);
+
+    if unsupported_tag_kinds.is_empty() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            ReadRecordError::UnsupportedTagEncodings(unsupported_tag_kinds),
+        ))
+    }
+}
+
+fn unsupported_tag_decoding_kinds(
+    compression_header: &CompressionHeader,
+) -> Vec<(tag_ids_dictionary::Key, EncodingKind)> {
+    let tag_ids_dictionary = compression_header.preservation_map().tag_ids_dictionary();
+    let tag_encoding_map = compression_header.tag_encoding_map();
+
+    let mut kinds: Vec<(tag_ids_dictionary::Key, EncodingKind)> = Vec::new();
+
+    for &key in tag_ids_dictionary.iter().flatten() {
+        if kinds.iter().any(|(k, _)| *k == key) {
+            continue;
+        }
+
+        let id = block::ContentId::from(key);
+
+        if let Some(kind) = tag_encoding_map
+            .get(&id)
+            .and_then(|encoding| encoding.get().unsupported_decoding_kind())
+        {
+            kinds.push((key, kind));
         }
     }
+
+    kinds
 }
 
 pub struct Reader<'a, CDR, EDR>
@@ -57,6 +142,7 @@ where
     core_data_reader: BitReader<CDR>,
     external_data_readers: ExternalDataReaders<EDR>,
     reference_sequence_context: ReferenceSequenceContext,
+    tag_filter: Option<&'a HashSet<Tag>>,
     prev_alignment_start: Option<Position>,
 }
 
@@ -70,6 +156,7 @@ where
         core_data_reader: BitReader<CDR>,
         external_data_readers: ExternalDataReaders<EDR>,
         reference_sequence_context: ReferenceSequenceContext,
+        tag_filter: Option<&'a HashSet<Tag>>,
     ) -> Self {
         let initial_alignment_start = match reference_sequence_context {
             ReferenceSequenceContext::Some(context) => Some(context.alignment_start()),
@@ -81,6 +168,7 @@ where
             core_data_reader,
             external_data_readers,
             reference_sequence_context,
+            tag_filter,
             prev_alignment_start: initial_alignment_start,
         }
     }
@@ -408,6 +496,16 @@ where
                 )
             })?;
 
+            if let Some(tag_filter) = self.tag_filter {
+                if !tag_filter.contains(&key.tag()) {
+                    encoding
+                        .get()
+                        .skip(&mut self.core_data_reader, &mut self.external_data_readers)?;
+
+                    continue;
+                }
+            }
+
             let data =
                 encoding.decode(&mut self.core_data_reader, &mut self.external_data_readers)?;
 
@@ -585,10 +683,7 @@ where
             })?
             .decode(&mut self.core_data_reader, &mut self.external_data_readers)?;
 
-        raw_bases
-            .into_iter()
-            .map(|n| Base::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
-            .collect()
+        decode_bases(raw_bases)
     }
 
     fn read_stretches_of_quality_scores(&mut self) -> io::Result<Vec<Score>> {
@@ -606,10 +701,7 @@ where
             })?
             .decode(&mut self.core_data_reader, &mut self.external_data_readers)?;
 
-        scores
-            .into_iter()
-            .map(|n| Score::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
-            .collect()
+        decode_scores(scores)
     }
 
     fn read_base(&mut self) -> io::Result<Base> {
@@ -668,10 +760,7 @@ where
             })?
             .decode(&mut self.core_data_reader, &mut self.external_data_readers)?;
 
-        raw_bases
-            .into_iter()
-            .map(|n| Base::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
-            .collect()
+        decode_bases(raw_bases)
     }
 
     fn read_deletion_length(&mut self) -> io::Result<usize> {
@@ -719,10 +808,7 @@ where
             })?
             .decode(&mut self.core_data_reader, &mut self.external_data_readers)?;
 
-        raw_bases
-            .into_iter()
-            .map(|n| Base::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
-            .collect()
+        decode_bases(raw_bases)
     }
 
     fn read_padding(&mut self) -> io::Result<usize> {
@@ -827,3 +913,189 @@ where
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     }
 }
+
+fn decode_bases(raw_bases: Vec<u8>) -> io::Result<Vec<Base>> {
+    sam::record::Sequence::try_from(raw_bases)
+        .map(Vec::from)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn decode_scores(raw_scores: Vec<u8>) -> io::Result<Vec<Score>> {
+    sam::record::QualityScores::try_from(raw_scores)
+        .map(Vec::from)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_data_series_encodings() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::data_container::compression_header::{
+            encoding::codec::{Byte, ByteArray, Integer},
+            DataSeriesEncodingMap, Encoding,
+        };
+
+        let compression_header = CompressionHeader::builder().build();
+        assert!(validate_data_series_encodings(&compression_header).is_ok());
+
+        let data_series_encoding_map = DataSeriesEncodingMap::builder()
+            .set_bam_bit_flags_encoding(Encoding::new(Integer::External(block::ContentId::from(1))))
+            .set_cram_bit_flags_encoding(Encoding::new(Integer::External(block::ContentId::from(
+                2,
+            ))))
+            .set_read_lengths_encoding(Encoding::new(Integer::Subexp(0, 1)))
+            .set_in_seq_positions_encoding(Encoding::new(Integer::External(
+                block::ContentId::from(5),
+            )))
+            .set_read_groups_encoding(Encoding::new(Integer::External(block::ContentId::from(6))))
+            .set_read_names_encoding(Encoding::new(ByteArray::ByteArrayLen(
+                Encoding::new(Integer::GolombRice(0, 1)),
+                Encoding::new(Byte::External(block::ContentId::from(7))),
+            )))
+            .set_tag_ids_encoding(Encoding::new(Integer::External(block::ContentId::from(13))))
+            .build()?;
+
+        let compression_header = CompressionHeader::new(
+            compression_header.preservation_map().clone(),
+            data_series_encoding_map,
+            compression_header.tag_encoding_map().clone(),
+        );
+
+        let result = validate_data_series_encodings(&compression_header);
+
+        assert!(matches!(
+            result,
+            Err(ref e) if e.kind() == io::ErrorKind::Unsupported
+        ));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unsupported data series encodings: ReadLengths (Subexp), ReadNames (GolombRice)"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_data_series_encodings_with_unsupported_tag_encodings() {
+        use std::collections::HashMap;
+
+        use sam::record::data::field::{tag, Type};
+
+        use crate::data_container::compression_header::{
+            encoding::codec::{Byte, ByteArray, Integer},
+            preservation_map::{SubstitutionMatrix, TagIdsDictionary},
+            Encoding, PreservationMap,
+        };
+
+        let nm = tag_ids_dictionary::Key::new(tag::EDIT_DISTANCE, Type::UInt8);
+        let co = tag_ids_dictionary::Key::new(tag::COMMENT, Type::String);
+        let md = tag_ids_dictionary::Key::new(tag::MISMATCHED_POSITIONS, Type::String);
+        let rg = tag_ids_dictionary::Key::new(tag::READ_GROUP, Type::String);
+
+        let preservation_map = PreservationMap::new(
+            true,
+            true,
+            true,
+            SubstitutionMatrix::default(),
+            TagIdsDictionary::from(vec![vec![nm, co], vec![co, md], vec![rg]]),
+        );
+
+        let byte_array_len = |len_encoding| {
+            Encoding::new(ByteArray::ByteArrayLen(
+                Encoding::new(len_encoding),
+                Encoding::new(Byte::External(block::ContentId::from(1))),
+            ))
+        };
+
+        let tag_encoding_map: HashMap<_, _> = [
+            (
+                nm,
+                byte_array_len(Integer::External(block::ContentId::from(1))),
+            ),
+            (co, byte_array_len(Integer::Golomb(0, 1))),
+            (md, byte_array_len(Integer::GolombRice(0, 1))),
+            (rg, byte_array_len(Integer::Subexp(0, 1))),
+        ]
+        .into_iter()
+        .map(|(key, encoding)| (block::ContentId::from(key), encoding))
+        .collect();
+
+        let compression_header = CompressionHeader::new(
+            preservation_map,
+            CompressionHeader::builder()
+                .build()
+                .data_series_encoding_map()
+                .clone(),
+            tag_encoding_map.into(),
+        );
+
+        let result = validate_data_series_encodings(&compression_header);
+
+        assert!(matches!(
+            result,
+            Err(ref e) if e.kind() == io::ErrorKind::Unsupported
+        ));
+
+        let e = result.unwrap_err();
+
+        assert_eq!(
+            e.get_ref()
+                .and_then(|e| e.downcast_ref::<ReadRecordError>()),
+            Some(&ReadRecordError::UnsupportedTagEncodings(vec![
+                (co, EncodingKind::Golomb),
+                (md, EncodingKind::GolombRice),
+                (rg, EncodingKind::Subexp),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_decode_bases() -> io::Result<()> {
+        let raw_bases: Vec<_> = b"ACGTN=acgtn"
+            .iter()
+            .copied()
+            .cycle()
+            .take(1 << 16)
+            .collect();
+
+        let expected = raw_bases
+            .iter()
+            .map(|&n| Base::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(decode_bases(raw_bases)?, expected);
+
+        assert!(decode_bases(Vec::new())?.is_empty());
+
+        assert!(matches!(
+            decode_bases(vec![b'A', b'*', b'C']),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_scores() -> io::Result<()> {
+        let raw_scores: Vec<_> = (0..=Score::MAX.get()).cycle().take(1 << 16).collect();
+
+        let expected = raw_scores
+            .iter()
+            .map(|&n| Score::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(decode_scores(raw_scores)?, expected);
+
+        assert!(decode_scores(Vec::new())?.is_empty());
+
+        assert!(matches!(
+            decode_scores(vec![8, 0xff, 13]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
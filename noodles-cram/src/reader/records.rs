@@ -44,16 +44,12 @@ where
             .map(|slice| {
                 let compression_header = container.compression_header();
 
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
+                slice.decode_records(
+                    self.reader.reference_sequence_repository(),
+                    self.header,
+                    compression_header,
+                    self.reader.options(),
+                )
             })
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
@@ -13,9 +13,9 @@ pub use self::{
     next_mate_flags::NextMateFlags,
 };
 
-use std::io;
+use std::{fmt, io};
 
-use noodles_core::Position;
+use noodles_core::{region::Interval, Position};
 use noodles_sam::{
     self as sam,
     header::record::value::{
@@ -120,6 +120,17 @@ impl Record {
         })
     }
 
+    /// Returns whether the alignment span of the record intersects the given interval.
+    ///
+    /// See [`sam::alignment::Record::overlaps`]. This does not check the reference sequence of the
+    /// record.
+    pub fn overlaps(&self, interval: Interval) -> bool {
+        match (self.alignment_start(), self.alignment_end()) {
+            (Some(start), Some(end)) => interval.intersects((start..=end).into()),
+            _ => false,
+        }
+    }
+
     /// Returns the read group ID.
     ///
     /// This is also simply called the read group. It is the position of the read group in the SAM
@@ -247,6 +258,22 @@ pub(crate) fn calculate_alignment_span(read_length: usize, features: &Features)
         })
 }
 
+// Writes the names of set flags delimited by ` | `. Nothing is written if no flags are set.
+fn fmt_flag_names<'a, I>(f: &mut fmt::Formatter<'_>, names: I) -> fmt::Result
+where
+    I: Iterator<Item = &'a str>,
+{
+    for (i, name) in names.enumerate() {
+        if i > 0 {
+            f.write_str(" | ")?;
+        }
+
+        f.write_str(name)?;
+    }
+
+    Ok(())
+}
+
 fn get_reference_sequence(
     reference_sequences: &sam::header::ReferenceSequences,
     reference_sequence_id: Option<usize>,
@@ -262,6 +289,29 @@ fn get_reference_sequence(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_overlaps() -> Result<(), noodles_core::position::TryFromIntError> {
+        let interval = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+
+        let build_record = |alignment_start| {
+            Position::try_from(alignment_start).map(|position| {
+                Record::builder()
+                    .set_alignment_start(position)
+                    .set_read_length(4)
+                    .build()
+            })
+        };
+
+        assert!(build_record(9)?.overlaps(interval));
+        assert!(build_record(5)?.overlaps(interval));
+        assert!(build_record(13)?.overlaps(interval));
+        assert!(!build_record(4)?.overlaps(interval));
+        assert!(!build_record(14)?.overlaps(interval));
+        assert!(!Record::default().overlaps(interval));
+
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_alignment_span() -> Result<(), noodles_core::position::TryFromIntError> {
         use noodles_core::Position;
@@ -1,5 +1,6 @@
 use std::io;
 
+use noodles_bam as bam;
 use noodles_sam::{
     self as sam,
     header::{record::value::map, ReferenceSequences},
@@ -93,6 +94,22 @@ impl Record {
         Ok(builder.set_flags(flags).build())
     }
 
+    /// Converts this CRAM record to a BAM record.
+    ///
+    /// The fields are encoded using the BAM record layout, which is the same as converting to an
+    /// alignment record and writing it with a BAM writer (excluding the block size).
+    ///
+    /// If the CIGAR has more than 65535 operations, the BAM record uses a placeholder CIGAR
+    /// (`<read length>S<reference length>N`), and the real CIGAR is stored in the `CG` data field.
+    pub fn try_into_bam_record(self, header: &sam::Header) -> io::Result<bam::lazy::Record> {
+        let record = self.try_into_alignment_record(header)?;
+
+        let mut buf = Vec::new();
+        bam::record::codec::encode(&mut buf, header, &record)?;
+
+        bam::lazy::Record::try_from(buf)
+    }
+
     /// Converts this CRAM record to an alignment record.
     pub fn try_into_alignment_record(
         self,
@@ -209,3 +226,145 @@ fn maybe_insert_read_group(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_into_bam_record() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{
+            alignment::Record as AlignmentRecord,
+            record::data::field::{tag, Value},
+            AlignmentWriter,
+        };
+
+        use crate::{Reader, Writer};
+
+        let header = sam::Header::default();
+
+        let records = [
+            AlignmentRecord::builder()
+                .set_read_name("r0".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            AlignmentRecord::builder()
+                .set_read_name("r1".parse()?)
+                .set_sequence("TTGCA".parse()?)
+                .set_data(
+                    [(tag::COMMENT, Value::String(String::from("noodles")))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            AlignmentRecord::builder()
+                .set_read_name("r2".parse()?)
+                .build(),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let cram_records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(cram_records.len(), records.len());
+
+        for cram_record in cram_records {
+            let actual = cram_record.clone().try_into_bam_record(&header)?;
+
+            // CRAM -> SAM -> BAM
+            let mut sam_writer = sam::Writer::new(Vec::new());
+            let alignment_record = cram_record.try_into_alignment_record(&header)?;
+            sam_writer.write_record(&header, &alignment_record)?;
+
+            let mut sam_reader = sam::Reader::new(&sam_writer.get_ref()[..]);
+            let mut sam_record = AlignmentRecord::default();
+            sam_reader.read_record(&header, &mut sam_record)?;
+
+            let mut expected = Vec::new();
+            bam::record::codec::encode(&mut expected, &header, &sam_record)?;
+
+            assert_eq!(actual.as_ref(), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_into_bam_record_with_oversized_cigar() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            header::record::value::{map::ReferenceSequence, Map},
+            record::{
+                cigar::{op::Kind, Op},
+                data::field::tag,
+                sequence::Base,
+                Cigar, Sequence,
+            },
+        };
+
+        use crate::record::Feature;
+
+        const READ_LENGTH: usize = 65536;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(131072)?),
+            )
+            .build();
+
+        // 1M1I1M1I...
+        let features: Vec<_> = (2..=READ_LENGTH)
+            .step_by(2)
+            .map(|i| Position::try_from(i).map(|position| Feature::InsertBase(position, Base::A)))
+            .collect::<Result<_, _>>()?;
+
+        let sequence: Sequence = "A".repeat(READ_LENGTH).parse()?;
+
+        let record = Record::builder()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_read_length(READ_LENGTH)
+            .set_bases(sequence)
+            .set_features(Features::from(features))
+            .build();
+
+        let expected_cigar = Cigar::try_from(
+            [Op::new(Kind::Match, 1), Op::new(Kind::Insertion, 1)].repeat(READ_LENGTH / 2),
+        )?;
+        assert!(expected_cigar.len() > usize::from(u16::MAX));
+
+        let bam_record = record.try_into_bam_record(&header)?;
+
+        let cigar = bam_record.cigar();
+        assert_eq!(cigar.len(), 2);
+
+        let placeholder_cigar = Cigar::try_from(vec![
+            Op::new(Kind::SoftClip, READ_LENGTH),
+            Op::new(Kind::Skip, expected_cigar.alignment_span()),
+        ])?;
+        assert_eq!(sam::record::Cigar::try_from(cigar)?, placeholder_cigar);
+
+        let alignment_record = sam::alignment::Record::try_from(bam_record)?;
+        assert_eq!(alignment_record.cigar(), &expected_cigar);
+        assert!(alignment_record.data().get(&tag::CIGAR).is_none());
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 bitflags::bitflags! {
     /// CRAM record flags.
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -66,6 +68,12 @@ impl Flags {
     }
 }
 
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_flag_names(f, self.iter_names().map(|(name, _)| name))
+    }
+}
+
 impl From<u8> for Flags {
     fn from(value: u8) -> Self {
         Self::from_bits_truncate(value)
@@ -117,4 +125,25 @@ mod tests {
         assert_eq!(u8::from(Flags::from(0x04)), 0x04);
         assert_eq!(u8::from(Flags::from(0x08)), 0x08);
     }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Flags::empty().to_string(), "");
+        assert_eq!(Flags::DETACHED.to_string(), "DETACHED");
+        assert_eq!(
+            (Flags::QUALITY_SCORES_STORED_AS_ARRAY | Flags::HAS_MATE_DOWNSTREAM).to_string(),
+            "QUALITY_SCORES_STORED_AS_ARRAY | HAS_MATE_DOWNSTREAM"
+        );
+    }
+
+    #[test]
+    fn test_fmt_debug() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                Flags::QUALITY_SCORES_STORED_AS_ARRAY | Flags::HAS_MATE_DOWNSTREAM
+            ),
+            "Flags(QUALITY_SCORES_STORED_AS_ARRAY | HAS_MATE_DOWNSTREAM)"
+        );
+    }
 }
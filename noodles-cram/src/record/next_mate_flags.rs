@@ -1,3 +1,5 @@
+use std::fmt;
+
 bitflags::bitflags! {
     /// CRAM record next mate flags.
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -35,6 +37,12 @@ impl NextMateFlags {
     }
 }
 
+impl fmt::Display for NextMateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_flag_names(f, self.iter_names().map(|(name, _)| name))
+    }
+}
+
 impl From<u8> for NextMateFlags {
     fn from(value: u8) -> Self {
         Self::from_bits_truncate(value)
@@ -78,4 +86,25 @@ mod tests {
         assert_eq!(u8::from(NextMateFlags::from(0x01)), 0x01);
         assert_eq!(u8::from(NextMateFlags::from(0x02)), 0x02);
     }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(NextMateFlags::empty().to_string(), "");
+        assert_eq!(NextMateFlags::UNMAPPED.to_string(), "UNMAPPED");
+        assert_eq!(
+            (NextMateFlags::ON_NEGATIVE_STRAND | NextMateFlags::UNMAPPED).to_string(),
+            "ON_NEGATIVE_STRAND | UNMAPPED"
+        );
+    }
+
+    #[test]
+    fn test_fmt_debug() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                NextMateFlags::ON_NEGATIVE_STRAND | NextMateFlags::UNMAPPED
+            ),
+            "NextMateFlags(ON_NEGATIVE_STRAND | UNMAPPED)"
+        );
+    }
 }
@@ -47,8 +47,7 @@ pub(crate) fn resolve_bases(
             Feature::Substitution(_, substitution::Value::Code(code)) => {
                 if let Some(reference_sequence) = reference_sequence {
                     let base = reference_sequence[reference_position];
-                    let reference_base = SubstitutionBase::try_from(base).unwrap_or_default();
-                    let read_base = substitution_matrix.get(reference_base, *code);
+                    let read_base = resolve_substitution(substitution_matrix, base, *code)?;
                     buf[read_position] = Base::from(read_base);
                 } else {
                     return Err(io::Error::new(
@@ -97,6 +96,34 @@ pub(crate) fn resolve_bases(
     Ok(())
 }
 
+fn resolve_substitution(
+    substitution_matrix: &SubstitutionMatrix,
+    raw_reference_base: u8,
+    code: u8,
+) -> io::Result<SubstitutionBase> {
+    const MAX_CODE: u8 = 0b11;
+
+    let reference_base = SubstitutionBase::try_from(raw_reference_base.to_ascii_uppercase())
+        .map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "cannot resolve base substitution with reference base {:?}",
+                    char::from(raw_reference_base)
+                ),
+            )
+        })?;
+
+    if code > MAX_CODE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid substitution code: {code}"),
+        ));
+    }
+
+    Ok(substitution_matrix.get(reference_base, code))
+}
+
 fn copy_from_bases(dst: &mut [Base], src: &[Base]) {
     for (&base, b) in src.iter().zip(dst.iter_mut()) {
         *b = base;
@@ -275,6 +302,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_substitution() -> Result<(), Box<dyn std::error::Error>> {
+        // [[T, G, C, N], [A, G, T, N], [N, A, C, T], [G, N, A, C], [C, G, T, A]]
+        let substitution_matrix = SubstitutionMatrix::try_from([0x93, 0x1b, 0x6c, 0xb1, 0xc6])?;
+
+        let t = |raw_reference_base: u8, code: u8, expected: SubstitutionBase| {
+            let actual = resolve_substitution(&substitution_matrix, raw_reference_base, code)?;
+            assert_eq!(actual, expected);
+            Ok::<_, io::Error>(())
+        };
+
+        t(b'A', 0, SubstitutionBase::T)?;
+        t(b'A', 3, SubstitutionBase::N)?;
+        t(b'C', 1, SubstitutionBase::G)?;
+        t(b'G', 2, SubstitutionBase::C)?;
+        t(b'T', 0, SubstitutionBase::G)?;
+        t(b'N', 3, SubstitutionBase::A)?;
+        t(b'g', 3, SubstitutionBase::T)?;
+
+        assert!(matches!(
+            resolve_substitution(&substitution_matrix, b'R', 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            resolve_substitution(&substitution_matrix, b'A', 4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
         use sam::record::{quality_scores::Score, QualityScores};
@@ -291,8 +291,6 @@ pub(crate) fn add_missing_reference_sequence_checksums(
 ) -> io::Result<()> {
     use sam::header::record::value::map::reference_sequence::Md5Checksum;
 
-    use crate::data_container::slice::builder::calculate_normalized_sequence_digest;
-
     for (name, reference_sequence) in reference_sequences {
         if reference_sequence.md5_checksum().is_none() {
             let sequence = reference_sequence_repository
@@ -300,9 +298,8 @@ pub(crate) fn add_missing_reference_sequence_checksums(
                 .transpose()?
                 .expect("missing reference sequence");
 
-            let checksum = calculate_normalized_sequence_digest(&sequence[..]);
-
-            *reference_sequence.md5_checksum_mut() = Some(Md5Checksum::from(checksum));
+            let checksum = Md5Checksum::from_sequence(&sequence[..]);
+            *reference_sequence.md5_checksum_mut() = Some(checksum);
         }
     }
 
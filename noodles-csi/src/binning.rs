@@ -0,0 +1,250 @@
+//! Binning scheme functions.
+//!
+//! These compute bin IDs from 1-based, inclusive positions using the same bin numbering as htslib,
+//! where bin 0 spans the entire reference sequence. See `CSIv1.pdf` (2020-07-21).
+
+use std::io;
+
+use noodles_core::Position;
+
+/// Returns the ID of the smallest bin that contains the given region.
+///
+/// This returns an error if `min_shift + 3 * depth` is not less than the number of bits in a
+/// `usize`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning::reg2bin;
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// assert_eq!(reg2bin(start, end, 14, 5)?, 4681);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> io::Result<usize> {
+    validate_binning_scheme(min_shift, depth)?;
+    Ok(reg2bin_unchecked(start, end, min_shift, depth))
+}
+
+// The binning scheme is expected to be valid.
+pub(crate) fn reg2bin_unchecked(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
+    // [beg, end), 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end);
+
+    let end = end - 1;
+    let mut l = depth;
+    let mut s = min_shift;
+    let mut t = ((1 << (depth * 3)) - 1) / 7;
+
+    while l > 0 {
+        if beg >> s == end >> s {
+            return t + (beg >> s);
+        }
+
+        l -= 1;
+        s += 3;
+        t -= 1 << (l * 3);
+    }
+
+    0
+}
+
+/// Returns an iterator over the IDs of all bins that overlap the given region.
+///
+/// Bin IDs are ordered by level, starting from the root bin (0). As in htslib, an end past the
+/// max position of the binning scheme, i.e., `2^(min_shift + 3 * depth)`, is clamped to it.
+///
+/// This returns an error if `min_shift + 3 * depth` is not less than the number of bits in a
+/// `usize`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning::region_to_bins;
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// let bins: Vec<_> = region_to_bins(start, end, 14, 5)?.collect();
+/// assert_eq!(bins, [0, 1, 9, 73, 585, 4681]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn region_to_bins(
+    start: Position,
+    end: Position,
+    min_shift: u8,
+    depth: u8,
+) -> io::Result<impl Iterator<Item = usize>> {
+    let max_position = validate_binning_scheme(min_shift, depth)?;
+
+    // [beg, end), 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end).min(max_position);
+
+    let end = end - 1;
+
+    Ok((0..=depth).flat_map(move |l| {
+        let s = usize::from(min_shift) + usize::from(depth - l) * 3;
+        let t = ((1 << (usize::from(l) * 3)) - 1) / 7;
+        (t + (beg >> s))..=(t + (end >> s))
+    }))
+}
+
+// Returns the max position (`2^(min_shift + 3 * depth)`) of a valid binning scheme.
+fn validate_binning_scheme(min_shift: u8, depth: u8) -> io::Result<usize> {
+    let max_shift = u32::from(min_shift) + 3 * u32::from(depth);
+
+    1usize.checked_shl(max_shift).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid binning scheme: min shift ({min_shift}) + 3 * depth ({depth}) must be < {}",
+                usize::BITS
+            ),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reg2bin() -> Result<(), Box<dyn std::error::Error>> {
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        let start = Position::try_from(8)?;
+        let end = start;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 9);
+
+        let end = Position::try_from(13)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 9);
+
+        let end = Position::try_from(16)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 9);
+
+        let end = Position::try_from(17)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 1);
+
+        let end = Position::try_from(143)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg2bin_with_bai_binning_scheme() -> Result<(), Box<dyn std::error::Error>> {
+        // These are the bin IDs htslib uses for BAI (min shift = 14, depth = 5).
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 5;
+
+        fn t(start: usize, end: usize, expected: usize) -> Result<(), Box<dyn std::error::Error>> {
+            let start = Position::try_from(start)?;
+            let end = Position::try_from(end)?;
+            assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, expected);
+            Ok(())
+        }
+
+        t(1, 1, 4681)?;
+        t(1, 16384, 4681)?;
+        t(16385, 16385, 4682)?;
+        t(16384, 16385, 585)?;
+        t(131073, 131073, 4689)?;
+        t(1, 131073, 73)?;
+        t(1, 536870912, 0)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_to_bins() -> Result<(), Box<dyn std::error::Error>> {
+        // +------------------------------------------------------------------------------------...
+        // | 0                                                                                  ...
+        // | 0-1023                                                                             ...
+        // +-------------------------------------------------------------------------+----------...
+        // | 1                                                                       | 2        ...
+        // | 0-127                                                                   | 128-255  ...
+        // +--------+--------+--------+--------+--------+--------+---------+---------+---------+...
+        // | 9      | 10     | 11     | 12     | 13     | 14     | 15      | 16      | 17      |...
+        // | 0-15   | 16-31  | 32-47  | 48-63  | 64-79  | 80-95  | 96-111  | 112-127 | 128-143 |...
+        // +--------+--------+--------+--------+--------+--------+---------+---------+---------+...
+
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        fn t(start: Position, end: Position, expected: &[usize]) -> io::Result<()> {
+            let actual: Vec<_> = region_to_bins(start, end, MIN_SHIFT, DEPTH)?.collect();
+            assert_eq!(actual, expected);
+            Ok(())
+        }
+
+        t(Position::try_from(1)?, Position::try_from(16)?, &[0, 1, 9])?;
+        t(Position::try_from(9)?, Position::try_from(13)?, &[0, 1, 9])?;
+
+        t(
+            Position::try_from(36)?,
+            Position::try_from(67)?,
+            &[0, 1, 11, 12, 13],
+        )?;
+
+        t(
+            Position::try_from(49)?,
+            Position::try_from(143)?,
+            &[0, 1, 2, 12, 13, 14, 15, 16, 17],
+        )?;
+
+        // The end is clamped to the max position (1024).
+        let expected: Vec<_> = [0, 1, 2, 3, 4, 5, 6, 7, 8]
+            .into_iter()
+            .chain(9..=72)
+            .collect();
+        t(Position::try_from(1)?, Position::try_from(2000)?, &expected)?;
+        t(Position::try_from(1)?, Position::try_from(1024)?, &expected)?;
+
+        t(
+            Position::try_from(1009)?,
+            Position::try_from(2000)?,
+            &[0, 8, 72],
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg2bin_with_max_binning_scheme() -> Result<(), Box<dyn std::error::Error>> {
+        let depth = ((usize::BITS - 1) / 3) as u8;
+        let min_shift = (usize::BITS - 1) as u8 - depth * 3;
+
+        let start = Position::try_from(1)?;
+        let end = Position::MAX;
+        assert_eq!(reg2bin(start, end, min_shift, depth)?, 0);
+
+        let bins: Vec<_> = region_to_bins(start, start, min_shift, depth)?.collect();
+        assert_eq!(bins.len(), usize::from(depth) + 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg2bin_with_invalid_binning_scheme() {
+        let depth = ((usize::BITS - 1) / 3) as u8;
+        let min_shift = usize::BITS as u8 - depth * 3;
+
+        assert!(matches!(
+            reg2bin(Position::MIN, Position::MIN, min_shift, depth),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_region_to_bins_with_invalid_binning_scheme() {
+        assert!(matches!(
+            region_to_bins(Position::MIN, Position::MIN, 0, u8::MAX),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
@@ -9,6 +9,8 @@ pub use self::{
     builder::Builder, header::Header, indexer::Indexer, reference_sequence::ReferenceSequence,
 };
 
+use self::header::ReferenceSequenceNames;
+
 use std::io;
 
 use noodles_bgzf as bgzf;
@@ -78,6 +80,50 @@ impl Index {
         self.header.as_ref()
     }
 
+    /// Returns the reference sequence names from the tabix header.
+    ///
+    /// This is `None` if the index does not have a tabix header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// assert!(index.reference_sequence_names().is_none());
+    /// ```
+    pub fn reference_sequence_names(&self) -> Option<&ReferenceSequenceNames> {
+        self.header()
+            .map(|header| header.reference_sequence_names())
+    }
+
+    /// Resolves a reference sequence name to its reference sequence ID.
+    ///
+    /// This is `None` if the index does not have a tabix header or the name is not in its list of
+    /// reference sequence names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, index::{header::ReferenceSequenceNames, Header}};
+    ///
+    /// let reference_sequence_names: ReferenceSequenceNames = [String::from("sq0")]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let header = Header::builder()
+    ///     .set_reference_sequence_names(reference_sequence_names)
+    ///     .build();
+    ///
+    /// let index = csi::Index::builder().set_header(header).build();
+    ///
+    /// assert_eq!(index.name_to_id("sq0"), Some(0));
+    /// assert!(index.name_to_id("sq1").is_none());
+    /// ```
+    pub fn name_to_id(&self, name: &str) -> Option<usize> {
+        self.reference_sequence_names()
+            .and_then(|names| names.get_index_of(name))
+    }
+
     /// Returns a list of indexed reference sequences.
     ///
     /// # Examples
@@ -140,6 +186,52 @@ impl Index {
         Ok(merged_chunks)
     }
 
+    /// Returns the chunks that overlap with any of the given regions.
+    ///
+    /// This queries each region and merges the chunks of all queries into a sorted list of
+    /// non-overlapping chunks. Chunks shared by overlapping regions are only included once, so a
+    /// reader can read the union of the regions in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::{reference_sequence::bin::Chunk, Indexer};
+    ///
+    /// let mut indexer = Indexer::new(14, 5);
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// let chunk = Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// );
+    /// indexer.add_record(Some((0, start, end, true)), chunk)?;
+    ///
+    /// let index = indexer.build(1);
+    ///
+    /// let regions = [
+    ///     (0, (Position::try_from(1)?..=Position::try_from(10)?).into()),
+    ///     (0, (Position::try_from(5)?..=Position::try_from(21)?).into()),
+    /// ];
+    ///
+    /// assert_eq!(index.query_many(&regions)?, [chunk]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_many(&self, regions: &[(usize, Interval)]) -> io::Result<Vec<Chunk>> {
+        use super::binning_index::merge_chunks;
+
+        let mut chunks = Vec::new();
+
+        for &(reference_sequence_id, interval) in regions {
+            let region_chunks = self.query(reference_sequence_id, interval)?;
+            chunks.extend(region_chunks);
+        }
+
+        Ok(merge_chunks(&chunks))
+    }
+
     /// Returns the start position of the first record in the last linear bin.
     ///
     /// This is the closest position to the unplaced, unmapped records, if any, that is available
@@ -186,3 +278,83 @@ where
         Ok((start, end))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_many() -> Result<(), Box<dyn std::error::Error>> {
+        use super::Indexer;
+
+        fn build_chunk(compressed_position: u64) -> Chunk {
+            let start = bgzf::VirtualPosition::from(compressed_position << 16);
+            let end = bgzf::VirtualPosition::from(compressed_position << 16 | 0x10);
+            Chunk::new(start, end)
+        }
+
+        let records = [
+            (Position::try_from(1)?, Position::try_from(100)?),
+            (Position::try_from(20000)?, Position::try_from(20100)?),
+            (Position::try_from(50000)?, Position::try_from(50100)?),
+        ];
+
+        let mut indexer = Indexer::new(14, 5);
+
+        for (i, &(start, end)) in records.iter().enumerate() {
+            let chunk = build_chunk(i as u64 + 1);
+            indexer.add_record(Some((0, start, end, true)), chunk)?;
+        }
+
+        let index = indexer.build(1);
+
+        let regions = [
+            (
+                0,
+                (Position::try_from(1)?..=Position::try_from(25000)?).into(),
+            ),
+            (
+                0,
+                (Position::try_from(20000)?..=Position::try_from(60000)?).into(),
+            ),
+        ];
+
+        assert_eq!(index.query(regions[0].0, regions[0].1)?.len(), 2);
+        assert_eq!(index.query(regions[1].0, regions[1].1)?.len(), 2);
+
+        let actual = index.query_many(&regions)?;
+        let expected = [build_chunk(1), build_chunk(2), build_chunk(3)];
+        assert_eq!(actual, expected);
+
+        assert!(index.query_many(&[])?.is_empty());
+
+        let regions = [(1, Interval::from(..))];
+        assert!(matches!(
+            index.query_many(&regions),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_to_id() {
+        let reference_sequence_names: ReferenceSequenceNames =
+            [String::from("sq0"), String::from("sq1")]
+                .into_iter()
+                .collect();
+
+        let header = Header::builder()
+            .set_reference_sequence_names(reference_sequence_names)
+            .build();
+
+        let index = Index::builder().set_header(header).build();
+
+        assert_eq!(index.name_to_id("sq0"), Some(0));
+        assert_eq!(index.name_to_id("sq1"), Some(1));
+        assert!(index.name_to_id("sq2").is_none());
+
+        let index = Index::default();
+        assert!(index.name_to_id("sq0").is_none());
+    }
+}
@@ -38,6 +38,48 @@ impl Indexer {
         }
     }
 
+    /// Creates a CSI indexer with a depth large enough to bin the given max position.
+    ///
+    /// This is typically the length of the longest reference sequence.
+    ///
+    /// This returns an error if the min shift is 0 or if the binning scheme needed to bin the max
+    /// position does not fit in a `usize`, i.e., `min_shift + 3 * depth` >= `usize::BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::Indexer;
+    ///
+    /// let max_position = Position::try_from(1 << 30)?;
+    /// let indexer = Indexer::from_max_position(14, max_position)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_max_position(min_shift: u8, max_position: Position) -> io::Result<Self> {
+        if min_shift == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid min shift: expected > 0, got 0",
+            ));
+        }
+
+        let depth = calculate_depth(min_shift, max_position);
+        let max_shift = u32::from(min_shift) + 3 * u32::from(depth);
+
+        if max_shift >= usize::BITS {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid binning scheme: min shift ({min_shift}) + 3 * depth ({depth}) must \
+                     be < {}",
+                    usize::BITS
+                ),
+            ));
+        }
+
+        Ok(Self::new(min_shift, depth))
+    }
+
     /// Sets a tabix header.
     ///
     /// # Examples
@@ -90,6 +132,19 @@ impl Indexer {
             return Ok(());
         };
 
+        let max_position = ReferenceSequence::max_position(self.min_shift, self.depth)?;
+
+        if end > max_position {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "record end position ({end}) exceeds the max position ({max_position}) \
+                     for min shift {} and depth {}",
+                    self.min_shift, self.depth
+                ),
+            ));
+        }
+
         match reference_sequence_id.cmp(&self.current_reference_sequence_id()) {
             Ordering::Less => {
                 return Err(io::Error::new(
@@ -125,15 +180,17 @@ impl Indexer {
     pub fn build(mut self, reference_sequence_count: usize) -> Index {
         if reference_sequence_count == 0 {
             return Index::builder()
+                .set_min_shift(self.min_shift)
+                .set_depth(self.depth)
                 .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count)
                 .build();
         }
 
-        // SAFETY: `reference_sequence_count` is > 0.
-        let last_reference_sequence_id = reference_sequence_count - 1;
-        self.add_reference_sequences_builders_until(last_reference_sequence_id);
+        self.add_reference_sequences_builders_until(reference_sequence_count);
 
         let mut builder = Index::builder()
+            .set_min_shift(self.min_shift)
+            .set_depth(self.depth)
             .set_reference_sequences(self.reference_sequences)
             .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count);
 
@@ -157,6 +214,21 @@ impl Indexer {
     }
 }
 
+// Returns the smallest depth such that `max_position` <= 2^(min_shift + 3 * depth) - 1.
+fn calculate_depth(min_shift: u8, max_position: Position) -> u8 {
+    let n = usize::from(max_position);
+    let bit_count = usize::BITS - n.leading_zeros();
+    let min_shift = u32::from(min_shift);
+
+    if bit_count <= min_shift {
+        0
+    } else {
+        let depth = (bit_count - min_shift + 2) / 3;
+        // `depth` <= `usize::BITS` / 3, which always fits in a `u8`.
+        depth as u8
+    }
+}
+
 impl Default for Indexer {
     fn default() -> Self {
         Self {
@@ -184,4 +256,192 @@ mod tests {
         assert!(indexer.reference_sequences.is_empty());
         assert_eq!(indexer.unplaced_unmapped_record_count, 0);
     }
+
+    #[test]
+    fn test_from_max_position() -> Result<(), Box<dyn std::error::Error>> {
+        let indexer = Indexer::from_max_position(14, Position::try_from((1 << 29) - 1)?)?;
+        assert_eq!(indexer.min_shift, 14);
+        assert_eq!(indexer.depth, 5);
+
+        let indexer = Indexer::from_max_position(14, Position::try_from(1 << 29)?)?;
+        assert_eq!(indexer.depth, 6);
+
+        assert!(matches!(
+            Indexer::from_max_position(0, Position::MIN),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            Indexer::from_max_position(14, Position::try_from(usize::MAX)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            Indexer::from_max_position(u8::MAX, Position::MIN),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_depth() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(calculate_depth(14, Position::MIN), 0);
+        assert_eq!(calculate_depth(14, Position::try_from((1 << 14) - 1)?), 0);
+        assert_eq!(calculate_depth(14, Position::try_from(1 << 14)?), 1);
+        assert_eq!(calculate_depth(14, Position::try_from((1 << 29) - 1)?), 5);
+        assert_eq!(calculate_depth(14, Position::try_from(1 << 29)?), 6);
+        assert_eq!(calculate_depth(14, Position::try_from(248956422)?), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_position_out_of_range() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bgzf as bgzf;
+
+        let start = Position::try_from(1 << 29)?;
+        let end = Position::try_from((1 << 29) + 8)?;
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(144),
+            bgzf::VirtualPosition::from(233),
+        );
+
+        let mut indexer = Indexer::default();
+
+        assert!(matches!(
+            indexer.add_record(Some((0, start, end, true)), chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut indexer = Indexer::from_max_position(14, end)?;
+        indexer.add_record(Some((0, start, end, true)), chunk)?;
+
+        let index = indexer.build(1);
+        let chunks = index.query(0, start..=end)?;
+        assert_eq!(chunks, [chunk]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_invalid_binning_scheme() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bgzf as bgzf;
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(144),
+            bgzf::VirtualPosition::from(233),
+        );
+
+        let mut indexer = Indexer::new(0, 5);
+
+        assert!(matches!(
+            indexer.add_record(Some((0, start, end, true)), chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut indexer = Indexer::new(u8::MAX, 5);
+
+        assert!(matches!(
+            indexer.add_record(Some((0, start, end, true)), chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bgzf as bgzf;
+
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            Some((0, Position::try_from(1)?, Position::try_from(4)?, true)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(144),
+                bgzf::VirtualPosition::from(233),
+            ),
+        )?;
+
+        let index = indexer.build(2);
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+        assert!(!reference_sequences[0].bins().is_empty());
+        assert!(reference_sequences[1].bins().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_records_on_the_last_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bgzf as bgzf;
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(4)?;
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(144),
+            bgzf::VirtualPosition::from(233),
+        );
+
+        let mut indexer = Indexer::default();
+        indexer.add_record(Some((1, start, end, true)), chunk)?;
+
+        let index = indexer.build(2);
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+        assert!(reference_sequences[0].bins().is_empty());
+        assert_eq!(index.query(1, start..=end)?, [chunk]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_mergeable_chunks() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bgzf as bgzf;
+
+        let chunks = [
+            // block 0
+            (0, 144),
+            (144, 233),
+            (233, 377),
+            // block 1
+            (65536, 65680),
+            (65680, 65769),
+        ]
+        .map(|(start, end)| {
+            Chunk::new(
+                bgzf::VirtualPosition::from(start),
+                bgzf::VirtualPosition::from(end),
+            )
+        });
+
+        let mut indexer = Indexer::default();
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            let start = Position::try_from(i * 8 + 1)?;
+            let end = Position::try_from(i * 8 + 5)?;
+            indexer.add_record(Some((0, start, end, true)), *chunk)?;
+        }
+
+        let index = indexer.build(1);
+
+        let bins = index.reference_sequences()[0].bins();
+        let chunk_count: usize = bins.values().map(|bin| bin.chunks().len()).sum();
+        assert_eq!(chunk_count, 2);
+
+        let query_chunks = index.query(0, Position::try_from(1)?..=Position::try_from(40)?)?;
+
+        for chunk in &chunks {
+            assert!(query_chunks
+                .iter()
+                .any(|c| c.start() <= chunk.start() && chunk.end() <= c.end()));
+        }
+
+        Ok(())
+    }
 }
@@ -11,14 +11,18 @@ pub struct Builder {
 
 impl Builder {
     /// Adds or merges a chunk.
+    ///
+    /// The chunk is merged with the last chunk if they overlap, are adjacent, or if the chunk
+    /// starts in the same BGZF block that the last chunk ends in.
     pub fn add_chunk(&mut self, chunk: Chunk) {
         if chunk.start() < self.loffset {
             self.loffset = chunk.start();
         }
 
         if let Some(last_chunk) = self.chunks.last_mut() {
-            if chunk.start() <= last_chunk.end() {
-                *last_chunk = Chunk::new(last_chunk.start(), chunk.end());
+            if can_merge(*last_chunk, chunk) {
+                let end = last_chunk.end().max(chunk.end());
+                *last_chunk = Chunk::new(last_chunk.start(), end);
                 return;
             }
         }
@@ -35,6 +39,10 @@ impl Builder {
     }
 }
 
+fn can_merge(a: Chunk, b: Chunk) -> bool {
+    b.start() <= a.end() || b.start().compressed() == a.end().compressed()
+}
+
 impl Default for Builder {
     fn default() -> Self {
         Self {
@@ -88,8 +96,8 @@ mod tests {
         );
 
         builder.add_chunk(Chunk::new(
-            bgzf::VirtualPosition::from(34),
-            bgzf::VirtualPosition::from(55),
+            bgzf::VirtualPosition::from(65570),
+            bgzf::VirtualPosition::from(65591),
         ));
 
         assert_eq!(
@@ -100,13 +108,59 @@ mod tests {
                     bgzf::VirtualPosition::from(21)
                 ),
                 Chunk::new(
-                    bgzf::VirtualPosition::from(34),
-                    bgzf::VirtualPosition::from(55)
+                    bgzf::VirtualPosition::from(65570),
+                    bgzf::VirtualPosition::from(65591)
                 )
             ]
         );
     }
 
+    #[test]
+    fn test_add_chunk_with_mergeable_chunks() {
+        let mut builder = Builder::default();
+
+        builder.add_chunk(Chunk::new(
+            bgzf::VirtualPosition::from(5),
+            bgzf::VirtualPosition::from(21),
+        ));
+
+        // contained
+        builder.add_chunk(Chunk::new(
+            bgzf::VirtualPosition::from(8),
+            bgzf::VirtualPosition::from(13),
+        ));
+
+        assert_eq!(
+            builder.chunks,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(21)
+            )]
+        );
+
+        // same BGZF block
+        builder.add_chunk(Chunk::new(
+            bgzf::VirtualPosition::from(34),
+            bgzf::VirtualPosition::from(55),
+        ));
+
+        assert_eq!(
+            builder.chunks,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(55)
+            )]
+        );
+
+        // different BGZF block
+        builder.add_chunk(Chunk::new(
+            bgzf::VirtualPosition::from(5832704),
+            bgzf::VirtualPosition::from(5832848),
+        ));
+
+        assert_eq!(builder.chunks.len(), 2);
+    }
+
     #[test]
     fn test_build() {
         let mut builder = Builder::default();
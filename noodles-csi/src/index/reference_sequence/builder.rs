@@ -91,9 +91,9 @@ impl Builder {
         end: Position,
         chunk: Chunk,
     ) {
-        use super::reg2bin;
+        use crate::binning::reg2bin_unchecked;
 
-        let bin_id = reg2bin(start, end, min_shift, depth);
+        let bin_id = reg2bin_unchecked(start, end, min_shift, depth);
         let builder = self.bin_builders.entry(bin_id).or_insert(Bin::builder());
         builder.add_chunk(chunk);
     }
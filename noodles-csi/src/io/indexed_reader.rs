@@ -59,15 +59,15 @@ where
             .header()
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
 
-        let reference_sequence_id = header
-            .reference_sequence_names()
-            .get_index_of(region.name())
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "missing reference sequence name",
-                )
-            })?;
+        let reference_sequence_id = self.index.name_to_id(region.name()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "region reference sequence does not exist in reference sequences: {}",
+                    region.name()
+                ),
+            )
+        })?;
 
         let chunks = self.index.query(reference_sequence_id, region.interval())?;
 
@@ -5,6 +5,7 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+pub mod binning;
 pub mod binning_index;
 pub mod index;
 pub mod io;
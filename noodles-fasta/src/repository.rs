@@ -2,34 +2,117 @@
 
 mod adapter;
 pub mod adapters;
+mod builder;
 
-pub use self::adapter::Adapter;
+pub use self::{adapter::Adapter, builder::Builder};
 
 use std::{
     collections::HashMap,
     fmt, io,
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
 };
 
 use super::record::Sequence;
 
+#[derive(Debug)]
+struct CacheEntry {
+    sequence: Sequence,
+    last_access: AtomicU64,
+}
+
 struct AdapterCache {
     adapter: Box<dyn Adapter>,
-    cache: HashMap<String, Sequence>,
+    cache: HashMap<String, CacheEntry>,
+    clock: AtomicU64,
+    byte_count: usize,
+    max_sequence_count: Option<usize>,
+    max_byte_count: Option<usize>,
+}
+
+impl AdapterCache {
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn is_cacheable(&self, sequence: &Sequence) -> bool {
+        self.max_byte_count
+            .map(|n| sequence.len() <= n)
+            .unwrap_or(true)
+    }
+
+    fn is_over_bounds(&self) -> bool {
+        self.max_sequence_count
+            .map(|n| self.cache.len() > n)
+            .unwrap_or_default()
+            || self
+                .max_byte_count
+                .map(|n| self.byte_count > n)
+                .unwrap_or_default()
+    }
+
+    fn evict(&mut self) {
+        while self.is_over_bounds() {
+            let name = match self
+                .cache
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_access.load(Ordering::Relaxed))
+            {
+                Some((name, _)) => name.clone(),
+                None => break,
+            };
+
+            if let Some(entry) = self.cache.remove(&name) {
+                self.byte_count -= entry.sequence.len();
+            }
+        }
+    }
 }
 
 /// A caching sequence repository.
+///
+/// By default, the cache is unbounded. Use a [`Builder`] to limit the number of cached sequences
+/// or their total length, in which case the least recently used sequences are evicted.
 pub struct Repository(Arc<RwLock<AdapterCache>>);
 
 impl Repository {
     /// Creates a sequence repository.
     pub fn new<A>(adapter: A) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        Self::with_bounds(adapter, None, None)
+    }
+
+    /// Returns a builder to create a sequence repository.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let builder = fasta::Repository::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    fn with_bounds<A>(
+        adapter: A,
+        max_sequence_count: Option<usize>,
+        max_byte_count: Option<usize>,
+    ) -> Self
     where
         A: Adapter + 'static,
     {
         Self(Arc::new(RwLock::new(AdapterCache {
             adapter: Box::new(adapter),
             cache: HashMap::new(),
+            clock: AtomicU64::new(0),
+            byte_count: 0,
+            max_sequence_count,
+            max_byte_count,
         })))
     }
 
@@ -38,8 +121,9 @@ impl Repository {
         {
             let lock = self.0.read().unwrap();
 
-            if let Some(sequence) = lock.cache.get(name) {
-                return Some(Ok(sequence.clone()));
+            if let Some(entry) = lock.cache.get(name) {
+                entry.last_access.store(lock.tick(), Ordering::Relaxed);
+                return Some(Ok(entry.sequence.clone()));
             }
         }
 
@@ -50,11 +134,24 @@ impl Repository {
             Err(e) => return Some(Err(e)),
         };
 
-        lock.cache
-            .entry(name.into())
-            .or_insert_with(|| record.sequence().clone());
+        let sequence = record.sequence().clone();
+
+        if !lock.cache.contains_key(name) && lock.is_cacheable(&sequence) {
+            let last_access = AtomicU64::new(lock.tick());
+            lock.byte_count += sequence.len();
 
-        Some(Ok(record.sequence().clone()))
+            lock.cache.insert(
+                name.into(),
+                CacheEntry {
+                    sequence: sequence.clone(),
+                    last_access,
+                },
+            );
+
+            lock.evict();
+        }
+
+        Some(Ok(sequence))
     }
 
     /// Returns the number of cached sequences.
@@ -69,7 +166,9 @@ impl Repository {
 
     /// Clears the sequence cache.
     pub fn clear(&self) {
-        self.0.write().unwrap().cache.clear();
+        let mut lock = self.0.write().unwrap();
+        lock.cache.clear();
+        lock.byte_count = 0;
     }
 }
 
@@ -81,9 +180,15 @@ impl Clone for Repository {
 
 impl fmt::Debug for Repository {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Repository")
-            .field("cache", &self.0.read().unwrap().cache)
-            .finish()
+        let lock = self.0.read().unwrap();
+
+        let cache: HashMap<_, _> = lock
+            .cache
+            .iter()
+            .map(|(name, entry)| (name, &entry.sequence))
+            .collect();
+
+        f.debug_struct("Repository").field("cache", &cache).finish()
     }
 }
 
@@ -117,4 +222,115 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_with_max_sequence_count() -> io::Result<()> {
+        let (adapter, fetch_counts) = CountingAdapter::new(build_records());
+        let repository = Repository::builder()
+            .set_max_sequence_count(2)
+            .build_with_adapter(adapter);
+
+        repository.get("sq0").transpose()?;
+        repository.get("sq1").transpose()?;
+        // sq1 is now the least recently used sequence.
+        repository.get("sq0").transpose()?;
+        // This evicts sq1.
+        repository.get("sq2").transpose()?;
+        assert_eq!(repository.len(), 2);
+
+        // This refetches sq1 and evicts sq0.
+        repository.get("sq1").transpose()?;
+        repository.get("sq2").transpose()?;
+
+        let fetch_counts = fetch_counts.lock().unwrap();
+        assert_eq!(fetch_counts.get("sq0"), Some(&1));
+        assert_eq!(fetch_counts.get("sq1"), Some(&2));
+        assert_eq!(fetch_counts.get("sq2"), Some(&1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_max_byte_count() -> io::Result<()> {
+        let (adapter, fetch_counts) = CountingAdapter::new(build_records());
+        let repository = Repository::builder()
+            .set_max_byte_count(12)
+            .build_with_adapter(adapter);
+
+        // sq0 (4) + sq1 (8) = 12
+        repository.get("sq0").transpose()?;
+        repository.get("sq1").transpose()?;
+        assert_eq!(repository.len(), 2);
+
+        // sq2 (13) exceeds the bound by itself and is not cached.
+        assert_eq!(
+            repository
+                .get("sq2")
+                .transpose()?
+                .map(|sequence| sequence.len()),
+            Some(13)
+        );
+        assert_eq!(repository.len(), 2);
+
+        repository.get("sq0").transpose()?;
+        repository.get("sq1").transpose()?;
+        repository.get("sq2").transpose()?;
+
+        let fetch_counts = fetch_counts.lock().unwrap();
+        assert_eq!(fetch_counts.get("sq0"), Some(&1));
+        assert_eq!(fetch_counts.get("sq1"), Some(&1));
+        assert_eq!(fetch_counts.get("sq2"), Some(&2));
+
+        Ok(())
+    }
+
+    fn build_records() -> Vec<Record> {
+        vec![
+            Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"ACGT".to_vec()),
+            ),
+            Record::new(
+                Definition::new("sq1", None),
+                Sequence::from(b"TTCACCCA".to_vec()),
+            ),
+            Record::new(
+                Definition::new("sq2", None),
+                Sequence::from(b"GATCTTACTTTTT".to_vec()),
+            ),
+        ]
+    }
+
+    type FetchCounts = Arc<std::sync::Mutex<HashMap<String, usize>>>;
+
+    struct CountingAdapter {
+        records: Vec<Record>,
+        fetch_counts: FetchCounts,
+    }
+
+    impl CountingAdapter {
+        fn new(records: Vec<Record>) -> (Self, FetchCounts) {
+            let fetch_counts = FetchCounts::default();
+
+            let adapter = Self {
+                records,
+                fetch_counts: fetch_counts.clone(),
+            };
+
+            (adapter, fetch_counts)
+        }
+    }
+
+    impl Adapter for CountingAdapter {
+        fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+            *self
+                .fetch_counts
+                .lock()
+                .unwrap()
+                .entry(name.into())
+                .or_default() += 1;
+
+            Adapter::get(&mut self.records, name)
+        }
+    }
 }
@@ -5,6 +5,22 @@ use noodles_core::Region;
 use crate::{repository::Adapter, Record};
 
 /// An indexed reader adapter.
+///
+/// This reads sequences on demand from an indexed FASTA reader. When used with a
+/// [`crate::Repository`], only requested sequences are read and cached, rather than loading all
+/// sequences in the FASTA into memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, repository::adapters::IndexedReader};
+///
+/// let reader = fasta::indexed_reader::Builder::default().build_from_path("reference.fa")?;
+/// let adapter = IndexedReader::new(reader);
+/// let repository = fasta::Repository::new(adapter);
+/// # Ok::<_, io::Error>(())
+/// ```
 pub struct IndexedReader<R> {
     reader: crate::IndexedReader<R>,
 }
@@ -34,7 +50,49 @@ where
     R: BufRead + Seek,
 {
     fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+        if !self
+            .reader
+            .index()
+            .iter()
+            .any(|record| record.name() == name)
+        {
+            return None;
+        }
+
         let region = Region::new(name, ..);
         Some(self.reader.query(&region))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{fai, record::Definition};
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1\nNNNNNNNN\n";
+
+        let index = vec![
+            fai::Record::new("sq0", 4, 5, 4, 5),
+            fai::Record::new("sq1", 8, 15, 8, 9),
+        ];
+
+        let reader = crate::IndexedReader::new(Cursor::new(&data[..]), index);
+        let mut adapter = IndexedReader::new(reader);
+
+        assert_eq!(
+            adapter.get("sq1").transpose()?,
+            Some(Record::new(
+                Definition::new("sq1", None),
+                b"NNNNNNNN".to_vec().into()
+            ))
+        );
+
+        assert!(adapter.get("sq2").is_none());
+
+        Ok(())
+    }
+}
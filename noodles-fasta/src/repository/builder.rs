@@ -0,0 +1,65 @@
+use super::{Adapter, Repository};
+
+/// A sequence repository builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    max_sequence_count: Option<usize>,
+    max_byte_count: Option<usize>,
+}
+
+impl Builder {
+    /// Sets the maximum number of sequences to cache.
+    ///
+    /// When the cache exceeds this number of sequences, the least recently used sequences are
+    /// evicted.
+    ///
+    /// By default, the number of cached sequences is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository;
+    /// let builder = repository::Builder::default().set_max_sequence_count(8);
+    /// ```
+    pub fn set_max_sequence_count(mut self, max_sequence_count: usize) -> Self {
+        self.max_sequence_count = Some(max_sequence_count);
+        self
+    }
+
+    /// Sets the maximum total length of cached sequences, in bytes.
+    ///
+    /// When the total length of the cached sequences exceeds this number of bytes, the least
+    /// recently used sequences are evicted. A sequence that is longer than the bound is returned
+    /// but not cached.
+    ///
+    /// By default, the total length of cached sequences is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository;
+    /// let builder = repository::Builder::default().set_max_byte_count(1 << 30);
+    /// ```
+    pub fn set_max_byte_count(mut self, max_byte_count: usize) -> Self {
+        self.max_byte_count = Some(max_byte_count);
+        self
+    }
+
+    /// Builds a sequence repository with the given adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::{self, adapters::Empty};
+    ///
+    /// let repository = repository::Builder::default()
+    ///     .set_max_sequence_count(8)
+    ///     .build_with_adapter(Empty::new());
+    /// ```
+    pub fn build_with_adapter<A>(self, adapter: A) -> Repository
+    where
+        A: Adapter + 'static,
+    {
+        Repository::with_bounds(adapter, self.max_sequence_count, self.max_byte_count)
+    }
+}
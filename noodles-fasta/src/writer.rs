@@ -80,6 +80,15 @@ fn write_record_sequence<W>(
 where
     W: Write,
 {
+    if line_bases == 0 {
+        if !sequence.is_empty() {
+            writer.write_all(sequence.as_ref())?;
+            writeln!(writer)?;
+        }
+
+        return Ok(());
+    }
+
     for bases in sequence.as_ref().chunks(line_bases) {
         writer.write_all(bases)?;
         writeln!(writer)?;
@@ -98,6 +107,31 @@ mod tests {
         assert_eq!(writer.line_base_count, 80);
     }
 
+    #[test]
+    fn test_write_record_with_line_base_count() -> io::Result<()> {
+        use crate::record::Definition;
+
+        let definition = Definition::new("sq0", Some(String::from("LN:130")));
+        let sequence = Sequence::from(b"ACGT".repeat(33)[..130].to_vec());
+        let record = Record::new(definition, sequence);
+
+        let mut writer = Builder::default()
+            .set_line_base_count(60)
+            .build_with_writer(Vec::new());
+
+        writer.write_record(&record)?;
+
+        let mut lines = writer.get_ref().split(|&b| b == b'\n');
+        assert_eq!(lines.next(), Some(&b">sq0 LN:130"[..]));
+        assert_eq!(lines.next().map(|line| line.len()), Some(60));
+        assert_eq!(lines.next().map(|line| line.len()), Some(60));
+        assert_eq!(lines.next().map(|line| line.len()), Some(10));
+        assert_eq!(lines.next(), Some(&b""[..]));
+        assert!(lines.next().is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_record_sequence() -> io::Result<()> {
         let mut writer = Vec::new();
@@ -120,6 +154,15 @@ mod tests {
         write_record_sequence(&mut writer, &sequence, 4)?;
         assert_eq!(writer, b"ACGT\nACGT\nAC\n");
 
+        writer.clear();
+        let sequence = Sequence::from(b"ACGTACGTAC".to_vec());
+        write_record_sequence(&mut writer, &sequence, 0)?;
+        assert_eq!(writer, b"ACGTACGTAC\n");
+
+        writer.clear();
+        write_record_sequence(&mut writer, &Sequence::default(), 0)?;
+        assert!(writer.is_empty());
+
         Ok(())
     }
 }
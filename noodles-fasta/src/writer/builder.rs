@@ -12,7 +12,8 @@ pub struct Builder {
 impl Builder {
     /// Sets the number of bases per line.
     ///
-    /// By default, this is set to 80.
+    /// By default, this is set to 80. If set to 0, sequences are not wrapped, i.e., each sequence
+    /// is written on a single line.
     ///
     /// # Examples
     ///
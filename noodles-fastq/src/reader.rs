@@ -1,9 +1,10 @@
 //! FASTQ reader.
 
+mod builder;
 pub(crate) mod record;
 mod records;
 
-pub use self::records::Records;
+pub use self::{builder::Builder, records::Records};
 
 use std::io::{self, BufRead};
 
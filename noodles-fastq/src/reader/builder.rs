@@ -0,0 +1,169 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+};
+
+use flate2::bufread::MultiGzDecoder;
+use noodles_bgzf as bgzf;
+
+use super::Reader;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum CompressionMethod {
+    Gzip,
+    Bgzf,
+}
+
+/// A FASTQ reader builder.
+#[derive(Debug, Default)]
+pub struct Builder;
+
+impl Builder {
+    /// Builds a FASTQ reader from a path.
+    ///
+    /// The compression method is autodetected from the magic number of the input. Uncompressed,
+    /// gzip, and BGZF streams are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::reader::Builder::default().build_from_path("sample.fastq.gz")?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<Box<dyn BufRead>>>
+    where
+        P: AsRef<Path>,
+    {
+        let reader = File::open(src).map(BufReader::new)?;
+        let reader = decode(reader)?;
+        self.build_from_reader(reader)
+    }
+
+    /// Builds a FASTQ reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::reader::Builder::default().build_from_reader(io::empty())?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> io::Result<Reader<R>>
+    where
+        R: BufRead,
+    {
+        Ok(Reader::new(reader))
+    }
+}
+
+fn decode<R>(mut reader: R) -> io::Result<Box<dyn BufRead>>
+where
+    R: BufRead + 'static,
+{
+    let reader: Box<dyn BufRead> = match detect_compression_method(&mut reader)? {
+        Some(CompressionMethod::Bgzf) => Box::new(bgzf::Reader::new(reader)),
+        Some(CompressionMethod::Gzip) => Box::new(BufReader::new(MultiGzDecoder::new(reader))),
+        None => Box::new(reader),
+    };
+
+    Ok(reader)
+}
+
+fn detect_compression_method<R>(reader: &mut R) -> io::Result<Option<CompressionMethod>>
+where
+    R: BufRead,
+{
+    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+    const FEXTRA: u8 = 0x04;
+    const BGZF_SUBFIELD_ID: [u8; 2] = [b'B', b'C'];
+
+    let src = reader.fill_buf()?;
+
+    if src.get(..2) != Some(&GZIP_MAGIC_NUMBER[..]) {
+        return Ok(None);
+    }
+
+    // § 4.1 "The BGZF compression format" (2021-06-03): a BGZF block is a gzip member with an
+    // extra field containing the subfield `BC`.
+    let is_bgzf = src.get(3).map(|flg| flg & FEXTRA != 0).unwrap_or_default()
+        && src.get(12..14) == Some(&BGZF_SUBFIELD_ID[..]);
+
+    if is_bgzf {
+        Ok(Some(CompressionMethod::Bgzf))
+    } else {
+        Ok(Some(CompressionMethod::Gzip))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+    use crate::Record;
+
+    const DATA: &[u8] = b"@r0\nATCG\n+\nNDLS\n@r1\nGCTA\n+\nSLDN\n";
+
+    fn read_records(reader: Box<dyn BufRead>) -> io::Result<Vec<Record>> {
+        let mut reader = Reader::new(reader);
+        reader.records().collect()
+    }
+
+    #[test]
+    fn test_decode() -> io::Result<()> {
+        let expected = read_records(Box::new(DATA))?;
+        assert_eq!(expected.len(), 2);
+
+        let reader = decode(Cursor::new(DATA))?;
+        assert_eq!(read_records(reader)?, expected);
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let data = writer.finish()?;
+        let reader = decode(Cursor::new(data))?;
+        assert_eq!(read_records(reader)?, expected);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(DATA)?;
+        let data = encoder.finish()?;
+        let reader = decode(Cursor::new(data))?;
+        assert_eq!(read_records(reader)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_compression_method() -> io::Result<()> {
+        let mut src = DATA;
+        assert_eq!(detect_compression_method(&mut src)?, None);
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let data = writer.finish()?;
+        let mut src = &data[..];
+        assert_eq!(
+            detect_compression_method(&mut src)?,
+            Some(CompressionMethod::Bgzf)
+        );
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(DATA)?;
+        let data = encoder.finish()?;
+        let mut src = &data[..];
+        assert_eq!(
+            detect_compression_method(&mut src)?,
+            Some(CompressionMethod::Gzip)
+        );
+
+        let mut src = &[][..];
+        assert_eq!(detect_compression_method(&mut src)?, None);
+
+        Ok(())
+    }
+}
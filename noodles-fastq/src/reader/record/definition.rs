@@ -7,9 +7,10 @@ pub(crate) fn read_definition<R>(reader: &mut R, definition: &mut Definition) ->
 where
     R: BufRead,
 {
-    use memchr::memchr2;
+    use memchr::memchr3;
 
-    const DELIMITER: u8 = b' ';
+    const SPACE: u8 = b' ';
+    const HORIZONTAL_TAB: u8 = b'\t';
     const NAME_PREFIX: u8 = b'@';
 
     match read_u8(reader) {
@@ -35,10 +36,10 @@ where
             break;
         }
 
-        let (matched_needle, n) = match memchr2(DELIMITER, LINE_FEED, src) {
+        let (matched_needle, n) = match memchr3(SPACE, HORIZONTAL_TAB, LINE_FEED, src) {
             Some(i) => {
                 let name_src = match src[i] {
-                    DELIMITER => &src[..i],
+                    SPACE | HORIZONTAL_TAB => &src[..i],
                     LINE_FEED => {
                         is_eol = true;
 
@@ -102,6 +103,13 @@ mod tests {
         assert_eq!(definition.name(), b"r0");
         assert_eq!(definition.description(), b"LN:4");
 
+        let data = b"@r0\tLN:4 SN:sq0\n";
+        let mut reader = &data[..];
+        definition.clear();
+        read_definition(&mut reader, &mut definition)?;
+        assert_eq!(definition.name(), b"r0");
+        assert_eq!(definition.description(), b"LN:4 SN:sq0");
+
         // https://github.com/zaeleus/noodles/issues/166
         let data = b"@\nA\r";
         let mut reader = &data[..];
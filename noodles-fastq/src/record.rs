@@ -4,7 +4,7 @@ mod definition;
 
 pub use self::definition::Definition;
 
-use std::fmt;
+use std::{error, fmt};
 
 /// A FASTQ record.
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
@@ -153,6 +153,79 @@ impl Record {
         &mut self.quality_scores
     }
 
+    /// Reverse complements the record in place.
+    ///
+    /// The sequence is reversed and each base is complemented using the IUPAC complement table
+    /// (e.g., `R` is complemented to `Y`, and `S` and `N` are their own complements). Case is
+    /// preserved, and unknown symbols are left unchanged. The quality scores are reversed but
+    /// otherwise unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    ///
+    /// let mut record = fastq::Record::new(Definition::new("r0", ""), "AACG", "NDLS");
+    /// record.reverse_complement();
+    ///
+    /// assert_eq!(record.sequence(), b"CGTT");
+    /// assert_eq!(record.quality_scores(), b"SLDN");
+    /// ```
+    pub fn reverse_complement(&mut self) {
+        self.sequence.reverse();
+
+        for base in &mut self.sequence {
+            *base = complement(*base);
+        }
+
+        self.quality_scores.reverse();
+    }
+
+    /// Validates the record.
+    ///
+    /// A record is valid when its name is not empty, its sequence and quality scores have the same
+    /// length, and each quality score is a printable Phred+33 encoded score (`!`..=`~`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::{Definition, ValidationError}};
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "NDLS");
+    /// assert!(record.validate().is_ok());
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "NDL");
+    /// assert_eq!(
+    ///     record.validate(),
+    ///     Err(ValidationError::LengthMismatch {
+    ///         sequence_len: 4,
+    ///         quality_scores_len: 3,
+    ///     })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.name().is_empty() {
+            return Err(ValidationError::EmptyName);
+        }
+
+        if self.sequence.len() != self.quality_scores.len() {
+            return Err(ValidationError::LengthMismatch {
+                sequence_len: self.sequence.len(),
+                quality_scores_len: self.quality_scores.len(),
+            });
+        }
+
+        if let Some(&b) = self
+            .quality_scores
+            .iter()
+            .find(|&&b| !is_valid_quality_score(b))
+        {
+            return Err(ValidationError::InvalidQualityScore(b));
+        }
+
+        Ok(())
+    }
+
     // Truncates all field buffers to 0.
     pub(crate) fn clear(&mut self) {
         self.definition.clear();
@@ -197,6 +270,68 @@ impl fmt::Display for Record {
     }
 }
 
+/// An error returned when a FASTQ record is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The name is empty.
+    EmptyName,
+    /// The sequence and quality scores lengths differ.
+    LengthMismatch {
+        /// The sequence length.
+        sequence_len: usize,
+        /// The quality scores length.
+        quality_scores_len: usize,
+    },
+    /// A quality score is outside the Phred+33 range.
+    InvalidQualityScore(u8),
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName => f.write_str("empty name"),
+            Self::LengthMismatch {
+                sequence_len,
+                quality_scores_len,
+            } => write!(
+                f,
+                "sequence and quality scores length mismatch: expected {sequence_len}, got {quality_scores_len}"
+            ),
+            Self::InvalidQualityScore(b) => write!(f, "invalid quality score: {b:#04x}"),
+        }
+    }
+}
+
+fn is_valid_quality_score(b: u8) -> bool {
+    (b'!'..=b'~').contains(&b)
+}
+
+fn complement(base: u8) -> u8 {
+    let complement = match base.to_ascii_uppercase() {
+        b'A' => b'T',
+        b'B' => b'V',
+        b'C' => b'G',
+        b'D' => b'H',
+        b'G' => b'C',
+        b'H' => b'D',
+        b'K' => b'M',
+        b'M' => b'K',
+        b'R' => b'Y',
+        b'T' | b'U' => b'A',
+        b'V' => b'B',
+        b'Y' => b'R',
+        _ => return base,
+    };
+
+    if base.is_ascii_lowercase() {
+        complement.to_ascii_lowercase()
+    } else {
+        complement
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,4 +355,55 @@ mod tests {
         assert!(record.sequence().is_empty());
         assert!(record.quality_scores().is_empty());
     }
+
+    #[test]
+    fn test_validate() {
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "!N~D");
+        assert!(record.validate().is_ok());
+
+        let record = Record::new(Definition::new("r0", ""), "", "");
+        assert!(record.validate().is_ok());
+
+        let record = Record::new(Definition::new("", ""), "AGCT", "NDLS");
+        assert_eq!(record.validate(), Err(ValidationError::EmptyName));
+
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "NDLSN");
+        assert_eq!(
+            record.validate(),
+            Err(ValidationError::LengthMismatch {
+                sequence_len: 4,
+                quality_scores_len: 5,
+            })
+        );
+
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "ND S");
+        assert_eq!(
+            record.validate(),
+            Err(ValidationError::InvalidQualityScore(b' '))
+        );
+
+        let record = Record::new(Definition::new("r0", ""), "AGCT", b"ND\x7fS".to_vec());
+        assert_eq!(
+            record.validate(),
+            Err(ValidationError::InvalidQualityScore(0x7f))
+        );
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        let mut record = Record::new(Definition::new("r0", ""), "ACGT", "NDLS");
+        record.reverse_complement();
+        assert_eq!(record.sequence(), b"ACGT");
+        assert_eq!(record.quality_scores(), b"SLDN");
+
+        let mut record = Record::new(Definition::new("r0", ""), "ANCg", "NDLS");
+        record.reverse_complement();
+        assert_eq!(record.sequence(), b"cGNT");
+        assert_eq!(record.quality_scores(), b"SLDN");
+
+        let mut record = Record::new(Definition::new("r0", ""), "RSKB", "NDLS");
+        record.reverse_complement();
+        assert_eq!(record.sequence(), b"VMSY");
+        assert_eq!(record.quality_scores(), b"SLDN");
+    }
 }
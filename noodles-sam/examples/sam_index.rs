@@ -42,10 +42,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut record = Record::default();
 
     let mut indexer = csi::index::Indexer::default();
-    let mut start_position = reader.get_ref().virtual_position();
+    let mut start_position = reader.virtual_position();
 
     while reader.read_record(&header, &mut record)? != 0 {
-        let end_position = reader.get_ref().virtual_position();
+        let end_position = reader.virtual_position();
         let chunk = Chunk::new(start_position, end_position);
 
         let alignment_context = match (
@@ -6,7 +6,7 @@ pub use self::builder::Builder;
 
 use std::io;
 
-use noodles_core::Position;
+use noodles_core::{region::Interval, Position};
 
 use crate::{
     header::{
@@ -136,6 +136,58 @@ impl Record {
         &mut self.reference_sequence_id
     }
 
+    /// Sets the reference sequence ID, validating it against the header.
+    ///
+    /// This returns an error if the ID is not in the header reference sequence dictionary. The
+    /// record is left unchanged in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0".parse()?,
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+    ///     )
+    ///     .build();
+    ///
+    /// let mut record = sam::alignment::Record::default();
+    ///
+    /// record.set_reference_sequence_id(&header, 0)?;
+    /// assert_eq!(record.reference_sequence_id(), Some(0));
+    ///
+    /// assert!(record.set_reference_sequence_id(&header, 1).is_err());
+    /// assert_eq!(record.reference_sequence_id(), Some(0));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_reference_sequence_id(
+        &mut self,
+        header: &Header,
+        reference_sequence_id: usize,
+    ) -> io::Result<()> {
+        let reference_sequence_count = header.reference_sequences().len();
+
+        if reference_sequence_id < reference_sequence_count {
+            self.reference_sequence_id = Some(reference_sequence_id);
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid reference sequence ID: expected < {reference_sequence_count}, got \
+                    {reference_sequence_id}"
+                ),
+            ))
+        }
+    }
+
     /// Returns the alignment start.
     ///
     /// # Examples
@@ -448,6 +500,94 @@ impl Record {
         )
     }
 
+    /// Returns the name of the associated reference sequence.
+    ///
+    /// This returns `None` if the record is unmapped, does not have a reference sequence ID, or the
+    /// ID is not in the header reference sequence dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let header = sam::Header::default();
+    /// let record = sam::alignment::Record::default();
+    /// assert!(record.reference_sequence_name(&header).is_none());
+    /// ```
+    pub fn reference_sequence_name<'a>(&self, header: &'a Header) -> Option<&'a str> {
+        if self.flags().is_unmapped() {
+            return None;
+        }
+
+        self.reference_sequence(header)
+            .and_then(|result| result.ok())
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the name of the associated mate reference sequence.
+    ///
+    /// This returns `None` if the mate is unmapped, the record does not have a mate reference
+    /// sequence ID, or the ID is not in the header reference sequence dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let header = sam::Header::default();
+    /// let record = sam::alignment::Record::default();
+    /// assert!(record.mate_reference_sequence_name(&header).is_none());
+    /// ```
+    pub fn mate_reference_sequence_name<'a>(&self, header: &'a Header) -> Option<&'a str> {
+        if self.flags().is_mate_unmapped() {
+            return None;
+        }
+
+        self.mate_reference_sequence(header)
+            .and_then(|result| result.ok())
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Validates the record.
+    ///
+    /// This checks that, for a mapped record with a CIGAR and sequence, the CIGAR read length
+    /// (i.e., the sum of the lengths of read-consuming operations) equals the sequence length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::Flags};
+    ///
+    /// let record = sam::alignment::Record::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_cigar("4M".parse()?)
+    ///     .set_sequence("ACGT".parse()?)
+    ///     .build();
+    ///
+    /// assert!(record.validate().is_ok());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate(&self) -> io::Result<()> {
+        if self.flags().is_unmapped() || self.cigar().is_empty() || self.sequence().is_empty() {
+            return Ok(());
+        }
+
+        let read_length = self.cigar().read_length();
+        let sequence_length = self.sequence().len();
+
+        // § 1.4.10 "`SEQ`" (2022-08-22): "If not a '*', the length of the sequence must equal the
+        // sum of lengths of `M`/`I`/`S`/`=`/`X` operations in `CIGAR`."
+        if read_length == sequence_length {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "read length-sequence length mismatch: expected {read_length}, got \
+                    {sequence_length}"
+                ),
+            ))
+        }
+    }
+
     /// Returns the alignment span.
     ///
     /// # Examples
@@ -483,6 +623,127 @@ impl Record {
             Position::new(end)
         })
     }
+
+    /// Calculates the end position, accounting for the topology of the reference sequence.
+    ///
+    /// This is the same as [`Self::alignment_end`] for a record on a linear reference sequence,
+    /// where it is an error for the end position to be greater than the length of the reference
+    /// sequence.
+    ///
+    /// For a record on a circular reference sequence (`TP:circular`), an end position past the
+    /// length of the reference sequence wraps around the origin. The returned end position is
+    /// then less than the alignment start. A reference sequence without a molecule topology is
+    /// treated as linear.
+    ///
+    /// This returns `None` if the record does not have an alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_core::Position;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{
+    ///         map::{reference_sequence::MoleculeTopology, ReferenceSequence},
+    ///         Map,
+    ///     },
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0".parse()?,
+    ///         Map::<ReferenceSequence>::builder()
+    ///             .set_length(NonZeroUsize::try_from(13)?)
+    ///             .set_molecule_topology(MoleculeTopology::Circular)
+    ///             .build()?,
+    ///     )
+    ///     .build();
+    ///
+    /// let record = sam::alignment::Record::builder()
+    ///     .set_reference_sequence_id(0)
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_cigar("10M".parse()?)
+    ///     .build();
+    ///
+    /// assert_eq!(record.alignment_end(), Position::new(17));
+    /// assert_eq!(
+    ///     record.circular_alignment_end(&header).transpose()?,
+    ///     Position::new(4)
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn circular_alignment_end(&self, header: &Header) -> Option<io::Result<Position>> {
+        use crate::header::record::value::map::reference_sequence::MoleculeTopology;
+
+        let end = self.alignment_end()?;
+
+        let result = self
+            .reference_sequence(header)
+            .unwrap_or_else(|| {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "missing reference sequence ID",
+                ))
+            })
+            .and_then(|(_, reference_sequence)| {
+                let length = reference_sequence.length().get();
+                let topology = reference_sequence.molecule_topology().unwrap_or_default();
+
+                if usize::from(end) <= length {
+                    return Ok(end);
+                }
+
+                match topology {
+                    MoleculeTopology::Linear => Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "alignment end is past the end of the reference sequence",
+                    )),
+                    MoleculeTopology::Circular => {
+                        let n = (usize::from(end) - 1) % length + 1;
+                        // SAFETY: `n` is > 0.
+                        Ok(Position::new(n).unwrap())
+                    }
+                }
+            });
+
+        Some(result)
+    }
+
+    /// Returns whether the alignment span of the record intersects the given interval.
+    ///
+    /// The alignment span is the closed interval [alignment start, alignment end], where the
+    /// alignment end is computed from the alignment start and the reference length of the CIGAR
+    /// operations. A record without an alignment start never overlaps an interval.
+    ///
+    /// This does not check the reference sequence of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam as sam;
+    ///
+    /// let record = sam::alignment::Record::builder()
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_cigar("5M".parse()?)
+    ///     .build();
+    ///
+    /// let start = Position::try_from(10)?;
+    /// let end = Position::try_from(21)?;
+    /// assert!(record.overlaps((start..=end).into()));
+    ///
+    /// let start = Position::try_from(13)?;
+    /// assert!(!record.overlaps((start..=end).into()));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn overlaps(&self, interval: Interval) -> bool {
+        match (self.alignment_start(), self.alignment_end()) {
+            (Some(start), Some(end)) => interval.intersects((start..=end).into()),
+            _ => false,
+        }
+    }
 }
 
 impl Default for Record {
@@ -501,3 +762,254 @@ fn get_reference_sequence(
         })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::header::record::value::map::reference_sequence::MoleculeTopology;
+
+    #[test]
+    fn test_overlaps() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+        let interval = Interval::from(start..=end);
+
+        let build_record = |alignment_start: usize| -> Result<Record, Box<dyn std::error::Error>> {
+            Ok(Record::builder()
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_cigar("2M1D1M".parse()?)
+                .build())
+        };
+
+        // fully inside
+        assert!(build_record(9)?.overlaps(interval));
+        // partially overlapping
+        assert!(build_record(5)?.overlaps(interval));
+        assert!(build_record(13)?.overlaps(interval));
+        // entirely before
+        assert!(!build_record(2)?.overlaps(interval));
+        // entirely after
+        assert!(!build_record(14)?.overlaps(interval));
+        // unmapped
+        assert!(!Record::default().overlaps(interval));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_sequence_name() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Flags;
+
+        let length = NonZeroUsize::try_from(8)?;
+
+        let header = Header::builder()
+            .add_reference_sequence("sq0".parse()?, Map::<ReferenceSequence>::new(length))
+            .add_reference_sequence("sq1".parse()?, Map::<ReferenceSequence>::new(length))
+            .build();
+
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::MIN)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::MIN)
+            .build();
+
+        assert_eq!(record.reference_sequence_name(&header), Some("sq1"));
+        assert_eq!(record.mate_reference_sequence_name(&header), Some("sq0"));
+
+        let record = Record::builder()
+            .set_flags(Flags::UNMAPPED | Flags::MATE_UNMAPPED)
+            .build();
+
+        assert!(record.reference_sequence_name(&header).is_none());
+        assert!(record.mate_reference_sequence_name(&header).is_none());
+
+        let record = Record::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::MIN)
+            .build();
+
+        assert!(record.reference_sequence_name(&header).is_none());
+        assert_eq!(record.mate_reference_sequence_name(&header), Some("sq0"));
+
+        let record = Record::builder()
+            .set_flags(Flags::MATE_UNMAPPED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::MIN)
+            .build();
+
+        assert_eq!(record.reference_sequence_name(&header), Some("sq0"));
+        assert!(record.mate_reference_sequence_name(&header).is_none());
+
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(2)
+            .set_mate_reference_sequence_id(2)
+            .build();
+
+        assert!(record.reference_sequence_name(&header).is_none());
+        assert!(record.mate_reference_sequence_name(&header).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_reference_sequence_id() -> Result<(), Box<dyn std::error::Error>> {
+        let length = NonZeroUsize::try_from(8)?;
+
+        let header = Header::builder()
+            .add_reference_sequence("sq0".parse()?, Map::<ReferenceSequence>::new(length))
+            .add_reference_sequence("sq1".parse()?, Map::<ReferenceSequence>::new(length))
+            .build();
+
+        let mut record = Record::default();
+
+        record.set_reference_sequence_id(&header, 1)?;
+        assert_eq!(record.reference_sequence_id(), Some(1));
+
+        assert!(matches!(
+            record.set_reference_sequence_id(&header, 2),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert_eq!(record.reference_sequence_id(), Some(1));
+
+        let mut record = Record::default();
+
+        assert!(matches!(
+            record.set_reference_sequence_id(&Header::default(), 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(record.reference_sequence_id().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Flags;
+
+        let build_record = |flags: Flags, cigar: Cigar, sequence: Sequence| {
+            Record::builder()
+                .set_flags(flags)
+                .set_cigar(cigar)
+                .set_sequence(sequence)
+                .build()
+        };
+
+        let record = build_record(Flags::empty(), "2S3M1I2D".parse()?, "ACGTAC".parse()?);
+        assert!(record.validate().is_ok());
+
+        let record = build_record(Flags::empty(), "4M".parse()?, "ACG".parse()?);
+        assert!(matches!(
+            record.validate(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        // missing sequence
+        let record = build_record(Flags::empty(), "4M".parse()?, Sequence::default());
+        assert!(record.validate().is_ok());
+
+        // missing CIGAR
+        let record = build_record(Flags::empty(), Cigar::default(), "ACG".parse()?);
+        assert!(record.validate().is_ok());
+
+        let record = build_record(Flags::UNMAPPED, "4M".parse()?, "ACG".parse()?);
+        assert!(record.validate().is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_circular_alignment_end() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_reference_sequence(
+            molecule_topology: Option<MoleculeTopology>,
+        ) -> Result<Map<ReferenceSequence>, Box<dyn std::error::Error>> {
+            let mut builder =
+                Map::<ReferenceSequence>::builder().set_length(NonZeroUsize::try_from(13)?);
+
+            if let Some(molecule_topology) = molecule_topology {
+                builder = builder.set_molecule_topology(molecule_topology);
+            }
+
+            Ok(builder.build()?)
+        }
+
+        let header = Header::builder()
+            .add_reference_sequence("sq0".parse()?, build_reference_sequence(None)?)
+            .add_reference_sequence(
+                "sq1".parse()?,
+                build_reference_sequence(Some(MoleculeTopology::Linear))?,
+            )
+            .add_reference_sequence(
+                "sq2".parse()?,
+                build_reference_sequence(Some(MoleculeTopology::Circular))?,
+            )
+            .build();
+
+        let alignment_start = Position::try_from(8)?;
+
+        let build_record = |reference_sequence_id: usize, cigar: &str| {
+            cigar.parse().map(|cigar| {
+                Record::builder()
+                    .set_reference_sequence_id(reference_sequence_id)
+                    .set_alignment_start(alignment_start)
+                    .set_cigar(cigar)
+                    .build()
+            })
+        };
+
+        // within the reference sequence
+        for id in 0..=2 {
+            let record = build_record(id, "6M")?;
+            assert_eq!(
+                record.circular_alignment_end(&header).transpose()?,
+                Position::new(13)
+            );
+        }
+
+        // spanning the origin
+        for id in 0..=1 {
+            let record = build_record(id, "10M")?;
+            assert!(matches!(
+                record.circular_alignment_end(&header),
+                Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        let record = build_record(2, "10M")?;
+        assert_eq!(
+            record.circular_alignment_end(&header).transpose()?,
+            Position::new(4)
+        );
+
+        let record = build_record(2, "19M")?;
+        assert_eq!(
+            record.circular_alignment_end(&header).transpose()?,
+            Position::new(13)
+        );
+
+        // missing alignment start
+        let record = Record::default();
+        assert!(record.circular_alignment_end(&header).is_none());
+
+        // missing reference sequence ID
+        let record = Record::builder()
+            .set_alignment_start(Position::MIN)
+            .set_cigar("4M".parse()?)
+            .build();
+        assert!(matches!(
+            record.circular_alignment_end(&header),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
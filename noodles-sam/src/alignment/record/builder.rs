@@ -14,6 +14,7 @@ pub struct Builder {
     cigar: Cigar,
     mate_reference_sequence_id: Option<usize>,
     mate_alignment_start: Option<Position>,
+    mate_flags: Option<Flags>,
     template_length: i32,
     sequence: Sequence,
     quality_scores: QualityScores,
@@ -173,6 +174,42 @@ impl Builder {
         self
     }
 
+    /// Sets the mate reference sequence ID and alignment start from the mate's fields.
+    ///
+    /// When built, the record is marked as segmented, and the mate unmapped and mate reverse
+    /// complemented flags are set from the mate's flags. This overrides these flags in
+    /// [`Self::set_flags`], regardless of call order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::Flags};
+    ///
+    /// let record = sam::alignment::Record::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_mate(0, Position::MIN, Flags::REVERSE_COMPLEMENTED)
+    ///     .build();
+    ///
+    /// assert_eq!(record.mate_reference_sequence_id(), Some(0));
+    /// assert_eq!(record.mate_alignment_start(), Some(Position::MIN));
+    /// assert_eq!(
+    ///     record.flags(),
+    ///     Flags::SEGMENTED | Flags::MATE_REVERSE_COMPLEMENTED
+    /// );
+    /// ```
+    pub fn set_mate(
+        mut self,
+        mate_reference_sequence_id: usize,
+        mate_alignment_start: Position,
+        mate_flags: Flags,
+    ) -> Self {
+        self.mate_reference_sequence_id = Some(mate_reference_sequence_id);
+        self.mate_alignment_start = Some(mate_alignment_start);
+        self.mate_flags = Some(mate_flags);
+        self
+    }
+
     /// Sets the template length.
     ///
     /// # Examples
@@ -264,9 +301,20 @@ impl Builder {
     /// assert_eq!(record, sam::alignment::Record::default());
     /// ```
     pub fn build(self) -> Record {
+        let mut flags = self.flags;
+
+        if let Some(mate_flags) = self.mate_flags {
+            flags.insert(Flags::SEGMENTED);
+            flags.set(Flags::MATE_UNMAPPED, mate_flags.is_unmapped());
+            flags.set(
+                Flags::MATE_REVERSE_COMPLEMENTED,
+                mate_flags.is_reverse_complemented(),
+            );
+        }
+
         Record {
             read_name: self.read_name,
-            flags: self.flags,
+            flags,
             reference_sequence_id: self.reference_sequence_id,
             alignment_start: self.alignment_start,
             mapping_quality: self.mapping_quality,
@@ -292,6 +340,7 @@ impl Default for Builder {
             cigar: Cigar::default(),
             mate_reference_sequence_id: None,
             mate_alignment_start: None,
+            mate_flags: None,
             template_length: 0,
             sequence: Sequence::default(),
             quality_scores: QualityScores::default(),
@@ -316,9 +365,43 @@ mod tests {
         assert!(builder.cigar.is_empty());
         assert!(builder.mate_reference_sequence_id.is_none());
         assert!(builder.mate_alignment_start.is_none());
+        assert!(builder.mate_flags.is_none());
         assert_eq!(builder.template_length, 0);
         assert!(builder.sequence.is_empty());
         assert!(builder.quality_scores.is_empty());
         assert!(builder.data.is_empty());
     }
+
+    #[test]
+    fn test_set_mate() -> Result<(), noodles_core::position::TryFromIntError> {
+        let mate_alignment_start = Position::try_from(13)?;
+
+        let record = Builder::default()
+            .set_mate(
+                1,
+                mate_alignment_start,
+                Flags::UNMAPPED | Flags::REVERSE_COMPLEMENTED,
+            )
+            .build();
+
+        assert_eq!(record.mate_reference_sequence_id(), Some(1));
+        assert_eq!(record.mate_alignment_start(), Some(mate_alignment_start));
+        assert_eq!(
+            record.flags(),
+            Flags::UNMAPPED
+                | Flags::SEGMENTED
+                | Flags::MATE_UNMAPPED
+                | Flags::MATE_REVERSE_COMPLEMENTED
+        );
+
+        // Stale mate flags are cleared, even when the flags are set after the mate.
+        let record = Builder::default()
+            .set_mate(1, mate_alignment_start, Flags::empty())
+            .set_flags(Flags::MATE_UNMAPPED | Flags::MATE_REVERSE_COMPLEMENTED)
+            .build();
+
+        assert_eq!(record.flags(), Flags::SEGMENTED);
+
+        Ok(())
+    }
 }
@@ -141,10 +141,10 @@ where
         header: &Header,
         record: &Record,
     ) -> io::Result<()> {
-        use crate::writer::write_record;
+        use crate::writer::{write_record, DataFieldOrder};
 
         let mut buf = Vec::new();
-        write_record(&mut buf, header, record)?;
+        write_record(&mut buf, header, record, DataFieldOrder::default())?;
         self.inner.write_all(&buf).await
     }
 }
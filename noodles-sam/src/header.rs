@@ -73,13 +73,16 @@
 
 mod builder;
 mod parser;
+mod program_chain;
 pub mod record;
 
 use std::{fmt, str::FromStr};
 
 use indexmap::IndexMap;
 
-pub use self::{builder::Builder, parser::ParseError, record::Record};
+pub use self::{
+    builder::Builder, parser::ParseError, program_chain::ProgramChainError, record::Record,
+};
 
 use self::record::value::{
     map::{self, Program, ReadGroup, ReferenceSequence},
@@ -99,6 +102,9 @@ pub type Programs = IndexMap<String, Map<Program>>;
 ///
 /// Records are grouped by their types: header, reference sequence, read group, program, and
 /// comment.
+///
+/// The original position of comments relative to other records is not tracked. When formatted,
+/// comments are written after all other records in insertion order.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Header {
     header: Option<Map<map::Header>>,
@@ -162,6 +168,63 @@ impl Header {
         &mut self.header
     }
 
+    /// Returns the sort order (`@HD SO`) if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::header::SortOrder};
+    ///
+    /// let header = sam::Header::default();
+    /// assert!(header.sort_order().is_none());
+    ///
+    /// let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n".parse()?;
+    /// assert_eq!(header.sort_order(), Some(SortOrder::Coordinate));
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn sort_order(&self) -> Option<map::header::SortOrder> {
+        self.header().and_then(|header| header.sort_order())
+    }
+
+    /// Returns the group order (`@HD GO`) if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::header::GroupOrder};
+    ///
+    /// let header = sam::Header::default();
+    /// assert!(header.group_order().is_none());
+    ///
+    /// let header: sam::Header = "@HD\tVN:1.6\tGO:query\n".parse()?;
+    /// assert_eq!(header.group_order(), Some(GroupOrder::Query));
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn group_order(&self) -> Option<map::header::GroupOrder> {
+        self.header().and_then(|header| header.group_order())
+    }
+
+    /// Returns the subsort order (`@HD SS`) if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::header::SubsortOrder};
+    ///
+    /// let header = sam::Header::default();
+    /// assert!(header.subsort_order().is_none());
+    ///
+    /// let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\tSS:coordinate:MI\n".parse()?;
+    /// assert_eq!(
+    ///     header.subsort_order(),
+    ///     Some(&SubsortOrder::Coordinate(vec![String::from("MI")]))
+    /// );
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn subsort_order(&self) -> Option<&map::header::SubsortOrder> {
+        self.header().and_then(|header| header.subsort_order())
+    }
+
     /// Returns the SAM header reference sequences.
     ///
     /// This is also called the reference sequence dictionary.
@@ -306,8 +369,83 @@ impl Header {
         &mut self.programs
     }
 
+    /// Returns the SAM header program IDs in chain order.
+    ///
+    /// Programs are chained by their previous program IDs (`PP`). Each chain is traversed from
+    /// its root, i.e., a program without a previous program ID, so that every program comes after
+    /// its previous program. Roots and programs with the same previous program keep their header
+    /// order.
+    ///
+    /// This returns an error if a previous program ID does not match any program or if the chain
+    /// contains a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::{map::Program, Map}};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_program("pg1", Map::<Program>::builder().set_previous_id("pg0").build()?)
+    ///     .add_program("pg0", Map::<Program>::default())
+    ///     .build();
+    ///
+    /// assert_eq!(header.program_chain()?, ["pg0", "pg1"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn program_chain(&self) -> Result<Vec<&str>, ProgramChainError> {
+        program_chain::program_chain(&self.programs)
+    }
+
+    /// Adds a program that follows the last program in the chain.
+    ///
+    /// The previous program ID (`PP`) of the given program is set to the ID of the chain tail,
+    /// i.e., the last program in header order that is not the previous program of any other
+    /// program. If there are no programs, the previous program ID is cleared.
+    ///
+    /// This returns an error if a program with the same ID already exists. Replacing it could make
+    /// another program's previous program ID refer to the given program, creating a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::{map::Program, Map}};
+    ///
+    /// let mut header = sam::Header::default();
+    /// header.add_program_chained("pg0", Map::<Program>::default())?;
+    /// header.add_program_chained("pg1", Map::<Program>::default())?;
+    ///
+    /// assert_eq!(
+    ///     header.programs().get("pg1").and_then(|program| program.previous_id()),
+    ///     Some("pg0")
+    /// );
+    /// # Ok::<_, sam::header::ProgramChainError>(())
+    /// ```
+    pub fn add_program_chained<I>(
+        &mut self,
+        id: I,
+        mut program: Map<Program>,
+    ) -> Result<(), ProgramChainError>
+    where
+        I: Into<String>,
+    {
+        let id = id.into();
+
+        if self.programs.contains_key(&id) {
+            return Err(ProgramChainError::DuplicateId(id));
+        }
+
+        *program.previous_id_mut() = program_chain::chain_tail(&self.programs).map(String::from);
+
+        self.programs.insert(id, program);
+
+        Ok(())
+    }
+
     /// Returns the SAM header comments.
     ///
+    /// Comments are in insertion order, which, for a parsed header, is the order they appear in
+    /// the input.
+    ///
     /// # Examples
     ///
     /// ```
@@ -464,6 +602,33 @@ impl FromStr for Header {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_add_program_chained() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = Header::default();
+
+        header.add_program_chained("pg0", Map::<Program>::default())?;
+        header.add_program_chained(
+            "pg1",
+            Map::<Program>::builder().set_previous_id("pg2").build()?,
+        )?;
+
+        let pg0 = header.programs().get("pg0").ok_or("missing pg0")?;
+        assert!(pg0.previous_id().is_none());
+
+        let pg1 = header.programs().get("pg1").ok_or("missing pg1")?;
+        assert_eq!(pg1.previous_id(), Some("pg0"));
+
+        assert_eq!(header.program_chain()?, ["pg0", "pg1"]);
+
+        assert_eq!(
+            header.add_program_chained("pg0", Map::<Program>::default()),
+            Err(ProgramChainError::DuplicateId(String::from("pg0")))
+        );
+        assert_eq!(header.program_chain()?, ["pg0", "pg1"]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
         use std::num::NonZeroUsize;
@@ -505,4 +670,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_fmt_with_comments() -> Result<(), Box<dyn std::error::Error>> {
+        let src = "\
+@HD\tVN:1.6
+@CO\tnoodles
+@SQ\tSN:sq0\tLN:8
+@CO\tsam
+@RG\tID:rg0
+@CO\tcomment 2
+";
+
+        let header: Header = src.parse()?;
+        assert_eq!(header.comments(), ["noodles", "sam", "comment 2"]);
+
+        let actual = header.to_string();
+        let expected = "\
+@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:8
+@RG\tID:rg0
+@CO\tnoodles
+@CO\tsam
+@CO\tcomment 2
+";
+        assert_eq!(actual, expected);
+
+        let header_from_output: Header = actual.parse()?;
+        assert_eq!(header_from_output, header);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_order() -> Result<(), ParseError> {
+        use super::record::value::map::header::{GroupOrder, SortOrder, SubsortOrder};
+
+        let header: Header = "@HD\tVN:1.6\tSO:coordinate\n".parse()?;
+        assert_eq!(header.sort_order(), Some(SortOrder::Coordinate));
+        assert!(header.group_order().is_none());
+        assert!(header.subsort_order().is_none());
+
+        let header: Header =
+            "@HD\tVN:1.6\tSO:queryname\tGO:query\tSS:queryname:natural\n".parse()?;
+        assert_eq!(header.sort_order(), Some(SortOrder::QueryName));
+        assert_eq!(header.group_order(), Some(GroupOrder::Query));
+        assert_eq!(
+            header.subsort_order(),
+            Some(&SubsortOrder::QueryName(vec![String::from("natural")]))
+        );
+
+        let header = Header::default();
+        assert!(header.sort_order().is_none());
+        assert!(header.group_order().is_none());
+        assert!(header.subsort_order().is_none());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,186 @@
+use std::{error, fmt};
+
+use indexmap::{IndexMap, IndexSet};
+
+use super::Programs;
+
+/// An error returned when a SAM header program chain is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProgramChainError {
+    /// A program's previous program ID (`PP`) does not match any program.
+    MissingPreviousProgram {
+        /// The program ID.
+        id: String,
+        /// The previous program ID.
+        previous_id: String,
+    },
+    /// The chain contains a cycle.
+    ///
+    /// This includes the ID of a program in the cycle.
+    Cycle(String),
+    /// A program with the same ID already exists.
+    DuplicateId(String),
+}
+
+impl error::Error for ProgramChainError {}
+
+impl fmt::Display for ProgramChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPreviousProgram { id, previous_id } => {
+                write!(f, "missing previous program: {id} -> {previous_id}")
+            }
+            Self::Cycle(id) => write!(f, "cycle in program chain at {id}"),
+            Self::DuplicateId(id) => write!(f, "duplicate program ID: {id}"),
+        }
+    }
+}
+
+pub(super) fn program_chain(programs: &Programs) -> Result<Vec<&str>, ProgramChainError> {
+    let mut roots = Vec::new();
+    let mut next_ids: IndexMap<&str, Vec<&str>> = IndexMap::new();
+
+    for (id, program) in programs {
+        match program.previous_id() {
+            Some(previous_id) => {
+                if !programs.contains_key(previous_id) {
+                    return Err(ProgramChainError::MissingPreviousProgram {
+                        id: id.clone(),
+                        previous_id: previous_id.into(),
+                    });
+                }
+
+                next_ids.entry(previous_id).or_default().push(id);
+            }
+            None => roots.push(id.as_str()),
+        }
+    }
+
+    let mut ids = IndexSet::with_capacity(programs.len());
+    let mut stack: Vec<_> = roots.into_iter().rev().collect();
+
+    while let Some(id) = stack.pop() {
+        ids.insert(id);
+
+        if let Some(next) = next_ids.get(id) {
+            stack.extend(next.iter().rev());
+        }
+    }
+
+    // Every program has at most one previous program, so any program not reachable from a root is
+    // in or follows a cycle.
+    if let Some(id) = programs.keys().find(|id| !ids.contains(id.as_str())) {
+        return Err(ProgramChainError::Cycle(id.clone()));
+    }
+
+    Ok(ids.into_iter().collect())
+}
+
+// Returns the ID of the last program, in header order, that is not the previous program of any
+// other program.
+pub(super) fn chain_tail(programs: &Programs) -> Option<&str> {
+    let previous_ids: IndexSet<&str> = programs
+        .values()
+        .filter_map(|program| program.previous_id())
+        .collect();
+
+    programs
+        .keys()
+        .rev()
+        .map(|id| id.as_str())
+        .find(|id| !previous_ids.contains(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{map::Program, Map};
+
+    fn build_programs(
+        programs: &[(&str, Option<&str>)],
+    ) -> Result<Programs, Box<dyn std::error::Error>> {
+        programs
+            .iter()
+            .map(|&(id, previous_id)| {
+                let mut builder = Map::<Program>::builder();
+
+                if let Some(previous_id) = previous_id {
+                    builder = builder.set_previous_id(previous_id);
+                }
+
+                Ok((id.into(), builder.build()?))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_program_chain() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(program_chain(&Programs::default())?.is_empty());
+
+        let programs =
+            build_programs(&[("pg2", Some("pg1")), ("pg0", None), ("pg1", Some("pg0"))])?;
+        assert_eq!(program_chain(&programs)?, ["pg0", "pg1", "pg2"]);
+
+        let programs = build_programs(&[
+            ("pg0", None),
+            ("pg1", Some("pg0")),
+            ("pg2", Some("pg0")),
+            ("pg3", None),
+        ])?;
+        assert_eq!(program_chain(&programs)?, ["pg0", "pg1", "pg2", "pg3"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_chain_with_cycle() -> Result<(), Box<dyn std::error::Error>> {
+        let programs =
+            build_programs(&[("pg0", None), ("pg1", Some("pg2")), ("pg2", Some("pg1"))])?;
+
+        assert_eq!(
+            program_chain(&programs),
+            Err(ProgramChainError::Cycle(String::from("pg1")))
+        );
+
+        let programs = build_programs(&[("pg0", Some("pg0"))])?;
+
+        assert_eq!(
+            program_chain(&programs),
+            Err(ProgramChainError::Cycle(String::from("pg0")))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chain_tail() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(chain_tail(&Programs::default()).is_none());
+
+        let programs = build_programs(&[("pg0", None), ("pg1", Some("pg0"))])?;
+        assert_eq!(chain_tail(&programs), Some("pg1"));
+
+        let programs = build_programs(&[("pg1", Some("pg0")), ("pg0", None)])?;
+        assert_eq!(chain_tail(&programs), Some("pg1"));
+
+        let programs = build_programs(&[("pg0", None), ("pg1", None)])?;
+        assert_eq!(chain_tail(&programs), Some("pg1"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_chain_with_missing_previous_program() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let programs = build_programs(&[("pg1", Some("pg0"))])?;
+
+        assert_eq!(
+            program_chain(&programs),
+            Err(ProgramChainError::MissingPreviousProgram {
+                id: String::from("pg1"),
+                previous_id: String::from("pg0"),
+            })
+        );
+
+        Ok(())
+    }
+}
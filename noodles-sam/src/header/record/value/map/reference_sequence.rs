@@ -230,6 +230,9 @@ impl Map<ReferenceSequence> {
 
     /// Returns the molecule topology.
     ///
+    /// If unset, the molecule topology is typically treated as linear
+    /// ([`MoleculeTopology::default`]).
+    ///
     /// # Examples
     ///
     /// ```
@@ -526,4 +529,29 @@ mod tests {
             Err(ParseError::InvalidLength(_))
         ));
     }
+
+    #[test]
+    fn test_try_from_fields_for_map_reference_sequence_with_alternative_locus(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let fields = vec![
+            (String::from("LN"), String::from("13")),
+            (String::from("AH"), String::from("*")),
+        ];
+        let map = Map::<ReferenceSequence>::try_from(fields)?;
+        assert_eq!(map.alternative_locus(), Some(&AlternativeLocus::Unknown));
+        assert_eq!(map.to_string(), "\tLN:13\tAH:*");
+
+        let fields = vec![
+            (String::from("LN"), String::from("13")),
+            (String::from("AH"), String::from("sq0:13-8")),
+        ];
+        assert_eq!(
+            Map::<ReferenceSequence>::try_from(fields),
+            Err(ParseError::InvalidAlternativeLocus(
+                alternative_locus::ParseError::InvalidInterval
+            ))
+        );
+
+        Ok(())
+    }
 }
@@ -15,6 +15,58 @@ pub enum AlternativeLocus {
     Unknown,
 }
 
+impl AlternativeLocus {
+    /// Returns the reference sequence name of the region in the primary assembly.
+    ///
+    /// This returns `None` if the region is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::reference_sequence::AlternativeLocus;
+    ///
+    /// let alternative_locus: AlternativeLocus = "sq0:8-13".parse()?;
+    /// assert_eq!(alternative_locus.reference_sequence_name(), Some("sq0"));
+    ///
+    /// assert!(AlternativeLocus::Unknown.reference_sequence_name().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reference_sequence_name(&self) -> Option<&str> {
+        match self {
+            Self::Region(reference_sequence_name, _) => Some(reference_sequence_name),
+            Self::Unknown => None,
+        }
+    }
+
+    /// Returns the start and end positions of the region in the primary assembly.
+    ///
+    /// This returns `None` if the region is unknown or if the region is the entire reference
+    /// sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::header::record::value::map::reference_sequence::AlternativeLocus;
+    ///
+    /// let alternative_locus: AlternativeLocus = "sq0:8-13".parse()?;
+    /// assert_eq!(
+    ///     alternative_locus.interval(),
+    ///     Some((Position::try_from(8)?, Position::try_from(13)?))
+    /// );
+    ///
+    /// let alternative_locus: AlternativeLocus = "sq0".parse()?;
+    /// assert!(alternative_locus.interval().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn interval(&self) -> Option<(Position, Position)> {
+        match self {
+            Self::Region(_, interval) => *interval,
+            Self::Unknown => None,
+        }
+    }
+}
+
 impl fmt::Display for AlternativeLocus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -37,6 +89,8 @@ pub enum ParseError {
     /// The reference sequence name is invalid.
     InvalidReferenceSequenceName,
     /// The interval is invalid.
+    ///
+    /// The interval must be `<start>-<end>`, where `start` <= `end`.
     InvalidInterval,
 }
 
@@ -88,7 +142,12 @@ fn parse_interval(s: &str) -> Result<(Position, Position), ParseError> {
     let (raw_start, raw_end) = s.split_once('-').ok_or(ParseError::InvalidInterval)?;
     let start = raw_start.parse().map_err(|_| ParseError::InvalidInterval)?;
     let end = raw_end.parse().map_err(|_| ParseError::InvalidInterval)?;
-    Ok((start, end))
+
+    if start <= end {
+        Ok((start, end))
+    } else {
+        Err(ParseError::InvalidInterval)
+    }
 }
 
 #[cfg(test)]
@@ -113,6 +172,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reference_sequence_name_and_interval(
+    ) -> Result<(), noodles_core::position::TryFromIntError> {
+        let alternative_locus = AlternativeLocus::Unknown;
+        assert!(alternative_locus.reference_sequence_name().is_none());
+        assert!(alternative_locus.interval().is_none());
+
+        let alternative_locus = AlternativeLocus::Region(String::from("sq0"), None);
+        assert_eq!(alternative_locus.reference_sequence_name(), Some("sq0"));
+        assert!(alternative_locus.interval().is_none());
+
+        let interval = (Position::try_from(8)?, Position::try_from(13)?);
+        let alternative_locus = AlternativeLocus::Region(String::from("sq0"), Some(interval));
+        assert_eq!(alternative_locus.reference_sequence_name(), Some("sq0"));
+        assert_eq!(alternative_locus.interval(), Some(interval));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_round_trip() -> Result<(), ParseError> {
+        for s in ["*", "sq0", "sq0:8-13"] {
+            let alternative_locus: AlternativeLocus = s.parse()?;
+            assert_eq!(alternative_locus.to_string(), s);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() -> Result<(), noodles_core::position::TryFromIntError> {
         assert_eq!("*".parse(), Ok(AlternativeLocus::Unknown));
@@ -138,6 +226,21 @@ mod tests {
             Err(ParseError::InvalidReferenceSequenceName)
         );
 
+        for s in [
+            "sq0:",
+            "sq0:8",
+            "sq0:8-",
+            "sq0:-13",
+            "sq0:0-13",
+            "sq0:13-8",
+            "sq0:8-13:21",
+        ] {
+            assert_eq!(
+                s.parse::<AlternativeLocus>(),
+                Err(ParseError::InvalidInterval)
+            );
+        }
+
         Ok(())
     }
 }
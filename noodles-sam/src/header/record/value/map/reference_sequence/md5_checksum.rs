@@ -6,6 +6,49 @@ use std::{error, fmt, ops::Deref, str::FromStr};
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Md5Checksum([u8; 16]);
 
+impl Md5Checksum {
+    /// Calculates the MD5 checksum of a reference sequence.
+    ///
+    /// The sequence is normalized before computing the digest: all characters outside of the
+    /// inclusive range 33 (`!`) to 126 (`~`) are stripped out, and all lowercase characters are
+    /// converted to uppercase. This is the checksum expected in the `@SQ` `M5` field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::reference_sequence::Md5Checksum;
+    /// let md5_checksum = Md5Checksum::from_sequence(b"ACgt");
+    /// assert_eq!(md5_checksum.to_string(), "f1f8f4bf413b16ad135722aa4591043e");
+    /// ```
+    pub fn from_sequence(sequence: &[u8]) -> Self {
+        use md5::{Digest, Md5};
+
+        const CHUNK_SIZE: usize = 1 << 12;
+
+        let mut hasher = Md5::new();
+        let mut buf = [0; CHUNK_SIZE];
+
+        // § 1.3.2 "Reference MD5 calculation" (2021-06-03)
+        for chunk in sequence.chunks(CHUNK_SIZE) {
+            let mut len = 0;
+
+            for &b in chunk {
+                // "All characters outside of the inclusive range 33 ('!') to 126 ('~') are
+                // stripped out."
+                if b.is_ascii_graphic() {
+                    // "All lowercase characters are converted to uppercase."
+                    buf[len] = b.to_ascii_uppercase();
+                    len += 1;
+                }
+            }
+
+            hasher.update(&buf[..len]);
+        }
+
+        Self(hasher.finalize().into())
+    }
+}
+
 impl Deref for Md5Checksum {
     type Target = [u8];
 
@@ -90,6 +133,40 @@ fn parse_digit(b: u8) -> Result<u8, ParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_sequence() {
+        let expected = Md5Checksum::from([
+            0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad, 0x13, 0x57, 0x22, 0xaa, 0x45, 0x91,
+            0x04, 0x3e,
+        ]);
+
+        assert_eq!(Md5Checksum::from_sequence(b"ACGT"), expected);
+        assert_eq!(Md5Checksum::from_sequence(b"acgt"), expected);
+        assert_eq!(Md5Checksum::from_sequence(b"AC GT\n"), expected);
+
+        assert_eq!(
+            Md5Checksum::from_sequence(b"ACGTACGTACGTACGTACGTACGT...12345!!!").to_string(),
+            "dfabdbb36e239a6da88957841f32b8e4"
+        );
+    }
+
+    #[test]
+    fn test_from_sequence_with_multiple_chunks() {
+        use md5::{Digest, Md5};
+
+        let sequence: Vec<_> = b"acgt\nN".iter().copied().cycle().take(10000).collect();
+
+        let normalized: Vec<_> = sequence
+            .iter()
+            .filter(|b| b.is_ascii_graphic())
+            .map(|b| b.to_ascii_uppercase())
+            .collect();
+
+        let expected = Md5Checksum::from(<[u8; 16]>::from(Md5::digest(&normalized)));
+
+        assert_eq!(Md5Checksum::from_sequence(&sequence), expected);
+    }
+
     #[test]
     fn test_fmt() {
         let md5_checksum = Md5Checksum::from([
@@ -6,8 +6,13 @@ use std::{
 
 use noodles_core::Position;
 
-use crate::record::{
-    Cigar, Data, Flags, MappingQuality, QualityScores, ReadName, ReferenceSequenceName, Sequence,
+use crate::{
+    alignment,
+    record::{
+        Cigar, Data, Flags, MappingQuality, QualityScores, ReadName, ReferenceSequenceName,
+        Sequence,
+    },
+    Header,
 };
 
 const MISSING: &[u8] = b"*";
@@ -25,6 +30,7 @@ pub(crate) struct Bounds {
     pub(crate) template_length_end: usize,
     pub(crate) sequence_end: usize,
     pub(crate) quality_scores_end: usize,
+    pub(crate) data_start: usize,
 }
 
 impl Bounds {
@@ -73,7 +79,7 @@ impl Bounds {
     }
 
     fn data_range(&self) -> RangeFrom<usize> {
-        self.quality_scores_end..
+        self.data_start..
     }
 }
 
@@ -341,6 +347,175 @@ impl Record {
 
         Ok(data)
     }
+
+    /// Converts an alignment record to a lazy SAM record.
+    ///
+    /// The reference sequence IDs of the alignment record are resolved to names using the given
+    /// header. Missing fields are written as `*` (or `0` for positions and `255` for the mapping
+    /// quality), as in a SAM file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let record = sam::alignment::Record::default();
+    ///
+    /// let lazy_record = sam::lazy::Record::try_from_alignment_record(&header, &record)?;
+    /// assert!(lazy_record.read_name()?.is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn try_from_alignment_record(
+        header: &Header,
+        record: &alignment::Record,
+    ) -> io::Result<Self> {
+        use crate::writer::{
+            num,
+            record::{
+                write_cigar, write_data, write_position, write_quality_scores, write_sequence,
+                DataFieldOrder,
+            },
+        };
+
+        const EQ: &[u8] = b"=";
+
+        let mut buf = Vec::new();
+
+        let read_name = record
+            .read_name()
+            .map(|name| name.as_ref())
+            .unwrap_or(MISSING);
+        buf.extend_from_slice(read_name);
+        let read_name_end = buf.len();
+
+        num::write_u16(&mut buf, u16::from(record.flags()))?;
+        let flags_end = buf.len();
+
+        let reference_sequence = record.reference_sequence(header).transpose()?;
+        let reference_sequence_name = reference_sequence
+            .map(|(name, _)| name.as_bytes())
+            .unwrap_or(MISSING);
+        buf.extend_from_slice(reference_sequence_name);
+        let reference_sequence_name_end = buf.len();
+
+        write_position(&mut buf, record.alignment_start())?;
+        let alignment_start_end = buf.len();
+
+        let mapping_quality = record
+            .mapping_quality()
+            .map(u8::from)
+            .unwrap_or(crate::record::mapping_quality::MISSING);
+        num::write_u8(&mut buf, mapping_quality)?;
+        let mapping_quality_end = buf.len();
+
+        write_cigar(&mut buf, record.cigar())?;
+        let cigar_end = buf.len();
+
+        let mate_reference_sequence_name = record
+            .mate_reference_sequence(header)
+            .transpose()?
+            .map(|(mate_reference_sequence_name, _)| {
+                if let Some((reference_sequence_name, _)) = reference_sequence {
+                    if mate_reference_sequence_name == reference_sequence_name {
+                        return EQ;
+                    }
+                }
+
+                mate_reference_sequence_name.as_bytes()
+            })
+            .unwrap_or(MISSING);
+        buf.extend_from_slice(mate_reference_sequence_name);
+        let mate_reference_sequence_name_end = buf.len();
+
+        write_position(&mut buf, record.mate_alignment_start())?;
+        let mate_alignment_start_end = buf.len();
+
+        num::write_i32(&mut buf, record.template_length())?;
+        let template_length_end = buf.len();
+
+        write_sequence(&mut buf, record.cigar().read_length(), record.sequence())?;
+        let sequence_end = buf.len();
+
+        write_quality_scores(&mut buf, record.sequence().len(), record.quality_scores())?;
+        let quality_scores_end = buf.len();
+
+        // Each data field is written with a leading delimiter.
+        write_data(&mut buf, record.data(), DataFieldOrder::default())?;
+        let data_start = if record.data().is_empty() {
+            quality_scores_end
+        } else {
+            quality_scores_end + 1
+        };
+
+        let bounds = Bounds {
+            read_name_end,
+            flags_end,
+            reference_sequence_name_end,
+            alignment_start_end,
+            mapping_quality_end,
+            cigar_end,
+            mate_reference_sequence_name_end,
+            mate_alignment_start_end,
+            template_length_end,
+            sequence_end,
+            quality_scores_end,
+            data_start,
+        };
+
+        Ok(Self { buf, bounds })
+    }
+
+    /// Converts this lazy SAM record to an alignment record.
+    ///
+    /// The reference sequence names are resolved to IDs using the reference sequences in the given
+    /// header. This fails if a name is not in the header. Missing fields (`*`) are set to their
+    /// default values, i.e., `None` or empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let lazy_record = sam::lazy::Record::default();
+    ///
+    /// let record = lazy_record.try_into_alignment_record(&header)?;
+    /// assert_eq!(record, sam::alignment::Record::default());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn try_into_alignment_record(&self, header: &Header) -> io::Result<alignment::Record> {
+        const EQ: &[u8] = b"=";
+
+        let reference_sequence_id = match &self.buf[self.bounds.reference_sequence_name_range()] {
+            MISSING => None,
+            src => parse_reference_sequence_id(header, src).map(Some)?,
+        };
+
+        let mate_reference_sequence_id =
+            match &self.buf[self.bounds.mate_reference_sequence_name_range()] {
+                MISSING => None,
+                EQ => reference_sequence_id,
+                src => parse_reference_sequence_id(header, src).map(Some)?,
+            };
+
+        let mut record = alignment::Record::default();
+
+        *record.read_name_mut() = self.read_name()?;
+        *record.flags_mut() = self.flags()?;
+        *record.reference_sequence_id_mut() = reference_sequence_id;
+        *record.alignment_start_mut() = self.alignment_start()?;
+        *record.mapping_quality_mut() = self.mapping_quality()?;
+        *record.cigar_mut() = self.cigar()?;
+        *record.mate_reference_sequence_id_mut() = mate_reference_sequence_id;
+        *record.mate_alignment_start_mut() = self.mate_alignment_start()?;
+        *record.template_length_mut() = self.template_length()?;
+        *record.sequence_mut() = self.sequence()?;
+        *record.quality_scores_mut() = self.quality_scores()?;
+        *record.data_mut() = self.data()?;
+
+        Ok(record)
+    }
 }
 
 impl fmt::Debug for Record {
@@ -381,12 +556,18 @@ impl Default for Record {
             template_length_end: 11,
             sequence_end: 12,
             quality_scores_end: 13,
+            data_start: 13,
         };
 
         Self { buf, bounds }
     }
 }
 
+fn parse_reference_sequence_id(header: &Header, buf: &[u8]) -> io::Result<usize> {
+    crate::reader::record::parse_reference_sequence_id(header, buf)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 fn parse_reference_sequence_name(buf: &[u8]) -> io::Result<ReferenceSequenceName> {
     str::from_utf8(buf)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -395,3 +576,125 @@ fn parse_reference_sequence_name(buf: &[u8]) -> io::Result<ReferenceSequenceName
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_header() -> Result<Header, Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use crate::header::record::value::{map::ReferenceSequence, Map};
+
+        Ok(Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build())
+    }
+
+    #[test]
+    fn test_try_from_alignment_record_with_unmapped_record(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let record = alignment::Record::default();
+
+        let lazy_record = Record::try_from_alignment_record(&header, &record)?;
+        assert_eq!(lazy_record, Record::default());
+
+        assert_eq!(lazy_record.try_into_alignment_record(&header)?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_alignment_record_with_mapped_record() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use crate::record::data::field::{tag, Value};
+
+        let header = build_header()?;
+
+        let record = alignment::Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::try_from(5)?)
+            .set_mapping_quality(MappingQuality::try_from(13)?)
+            .set_cigar("4M".parse()?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(3)?)
+            .set_template_length(-8)
+            .set_sequence("ACGT".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .set_data(
+                [
+                    (tag::READ_GROUP, Value::String(String::from("rg0"))),
+                    (tag::ALIGNMENT_HIT_COUNT, Value::from(1)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let lazy_record = Record::try_from_alignment_record(&header, &record)?;
+
+        assert_eq!(lazy_record.read_name()?, record.read_name().cloned());
+        assert_eq!(lazy_record.reference_sequence_name()?, Some("sq1".parse()?));
+        assert_eq!(
+            lazy_record.mate_reference_sequence_name()?,
+            Some("sq0".parse()?)
+        );
+        assert_eq!(lazy_record.data()?, *record.data());
+
+        assert_eq!(lazy_record.try_into_alignment_record(&header)?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_alignment_record_matches_read_lazy_record(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::reader::read_lazy_record;
+
+        let header = build_header()?;
+
+        for src in [
+            &b"r0\t99\tsq1\t5\t13\t4M\t=\t3\t-8\tACGT\tNDLS\tRG:Z:rg0\tNH:i:1\n"[..],
+            &b"r1\t67\tsq1\t5\t13\t4M\tsq0\t3\t0\tACGT\t*\n"[..],
+        ] {
+            let mut expected = Record::default();
+            read_lazy_record(&mut &src[..], &mut expected)?;
+
+            let record = expected.try_into_alignment_record(&header)?;
+            let actual = Record::try_from_alignment_record(&header, &record)?;
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_into_alignment_record_with_missing_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut lazy_record = Record::default();
+        crate::reader::read_lazy_record(
+            &mut &b"r0\t0\tsq2\t1\t255\t4M\t*\t0\t0\tACGT\t*\n"[..],
+            &mut lazy_record,
+        )?;
+
+        assert!(matches!(
+            lazy_record.try_into_alignment_record(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -45,7 +45,7 @@ pub mod indexed_reader;
 pub mod lazy;
 pub mod reader;
 pub mod record;
-mod writer;
+pub mod writer;
 
 pub use self::{
     alignment_reader::AlignmentReader, alignment_writer::AlignmentWriter, header::Header,
@@ -237,6 +237,36 @@ where
     }
 }
 
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read,
+{
+    /// Returns the current virtual position of the underlying BGZF reader.
+    ///
+    /// After reading the header, this is the position of the start of the first record, which is
+    /// the start of the first record's chunk in an associated index. When the header ends at a
+    /// block boundary, this is the start of the next block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_sam as sam;
+    ///
+    /// let data = Vec::new();
+    /// let reader = sam::Reader::new(bgzf::Reader::new(&data[..]));
+    /// let virtual_position = reader.virtual_position();
+    ///
+    /// assert_eq!(virtual_position.compressed(), 0);
+    /// assert_eq!(virtual_position.uncompressed(), 0);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
 impl<R> Reader<bgzf::Reader<R>>
 where
     R: Read + Seek,
@@ -339,7 +369,7 @@ where
     }
 }
 
-fn read_lazy_record<R>(reader: &mut R, record: &mut lazy::Record) -> io::Result<usize>
+pub(crate) fn read_lazy_record<R>(reader: &mut R, record: &mut lazy::Record) -> io::Result<usize>
 where
     R: BufRead,
 {
@@ -377,11 +407,25 @@ where
     len += read_field(reader, &mut record.buf)?;
     record.bounds.sequence_end = record.buf.len();
 
-    len += read_field(reader, &mut record.buf)?;
-    record.bounds.quality_scores_end = record.buf.len();
-
+    // The quality scores field is the last required field and may end the line, so it and the
+    // optional data fields are read together and split at the first delimiter. The delimiter is
+    // kept in the buffer, and the data starts after it.
+    let start = record.buf.len();
     len += read_line(reader, &mut record.buf)?;
 
+    let rest = &record.buf[start..];
+
+    match memchr::memchr(b'\t', rest) {
+        Some(i) => {
+            record.bounds.quality_scores_end = start + i;
+            record.bounds.data_start = start + i + 1;
+        }
+        None => {
+            record.bounds.quality_scores_end = record.buf.len();
+            record.bounds.data_start = record.buf.len();
+        }
+    }
+
     Ok(len)
 }
 
@@ -478,4 +522,79 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_lazy_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut record = lazy::Record::default();
+
+        let mut src = &b"*\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS\r\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tNH:i:1\n"[..];
+
+        read_lazy_record(&mut src, &mut record)?;
+        assert_eq!(record.quality_scores()?, "NDLS".parse()?);
+        assert!(record.data()?.is_empty());
+
+        read_lazy_record(&mut src, &mut record)?;
+        assert!(record.quality_scores()?.is_empty());
+        assert_eq!(record.data()?.len(), 1);
+
+        assert_eq!(read_lazy_record(&mut src, &mut record)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_virtual_position_after_read_header() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        use noodles_core::Position;
+        use noodles_csi::index::{reference_sequence::bin::Chunk, Indexer};
+
+        const HEADER: &[u8] = b"@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:8\n";
+        const RECORDS: &[u8] = b"r0\t0\tsq0\t1\t255\t4M\t*\t0\t0\tACGT\tNDLS\n";
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(HEADER)?;
+        // Ends the header at a block boundary.
+        writer.flush()?;
+        let expected = writer.virtual_position();
+        writer.write_all(RECORDS)?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(&data[..]));
+        let header = reader.read_header()?;
+
+        let actual = reader.virtual_position();
+        assert_eq!(actual, expected);
+        assert_eq!(actual.uncompressed(), 0);
+
+        let mut indexer = Indexer::default();
+        let mut record = Record::default();
+        let mut start_position = reader.virtual_position();
+
+        while reader.read_record(&header, &mut record)? != 0 {
+            let end_position = reader.virtual_position();
+            let chunk = Chunk::new(start_position, end_position);
+
+            let alignment_context = match (
+                record.reference_sequence_id(),
+                record.alignment_start(),
+                record.alignment_end(),
+            ) {
+                (Some(id), Some(start), Some(end)) => {
+                    Some((id, start, end, !record.flags().is_unmapped()))
+                }
+                _ => None,
+            };
+
+            indexer.add_record(alignment_context, chunk)?;
+
+            start_position = end_position;
+        }
+
+        let index = indexer.build(header.reference_sequences().len());
+        let chunks = index.query(0, Position::MIN..=Position::MIN)?;
+        assert_eq!(chunks.first().map(|chunk| chunk.start()), Some(actual));
+
+        Ok(())
+    }
 }
@@ -89,17 +89,10 @@ where
                 Err(e) => return Some(Err(e)),
             };
 
-            if let (Some(id), Some(start), Some(end)) = (
-                record.reference_sequence_id(),
-                record.alignment_start(),
-                record.alignment_end(),
-            ) {
-                let alignment_interval = Interval::from(start..=end);
-
-                if id == self.reference_sequence_id && self.interval.intersects(alignment_interval)
-                {
-                    return Some(Ok(record));
-                }
+            if record.reference_sequence_id() == Some(self.reference_sequence_id)
+                && record.overlaps(self.interval)
+            {
+                return Some(Ok(record));
             }
         }
     }
@@ -12,7 +12,8 @@ mod template_length;
 pub(crate) use self::{
     cigar::parse_cigar, data::parse_data, flags::parse_flags,
     mapping_quality::parse_mapping_quality, position::parse_alignment_start,
-    quality_scores::parse_quality_scores, read_name::parse_read_name, sequence::parse_sequence,
+    quality_scores::parse_quality_scores, read_name::parse_read_name,
+    reference_sequence_id::parse_reference_sequence_id, sequence::parse_sequence,
     template_length::parse_template_length,
 };
 
@@ -21,7 +22,6 @@ use std::{
     io::{self, BufRead},
 };
 
-use self::reference_sequence_id::parse_reference_sequence_id;
 use super::read_line;
 use crate::{alignment::Record, Header};
 
@@ -13,13 +13,20 @@ pub enum ParseError {
     /// The input is invalid.
     Invalid,
     /// An op is invalid.
-    InvalidOp(op::ParseError),
+    InvalidOp {
+        /// The 0-based byte offset of the op in the input.
+        position: usize,
+        /// The raw op.
+        token: String,
+        /// The op parse error.
+        error: op::ParseError,
+    },
 }
 
 impl error::Error for ParseError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
-            Self::InvalidOp(e) => Some(e),
+            Self::InvalidOp { error, .. } => Some(error),
             _ => None,
         }
     }
@@ -30,20 +37,30 @@ impl fmt::Display for ParseError {
         match self {
             Self::Empty => write!(f, "empty input"),
             Self::Invalid => write!(f, "invalid input"),
-            Self::InvalidOp(_) => write!(f, "invalid op"),
+            Self::InvalidOp {
+                position, token, ..
+            } => write!(f, "invalid op at position {position}: {token:?}"),
         }
     }
 }
 
-pub(crate) fn parse_cigar(mut src: &[u8], cigar: &mut Cigar) -> Result<(), ParseError> {
-    if src.is_empty() {
+pub(crate) fn parse_cigar(s: &[u8], cigar: &mut Cigar) -> Result<(), ParseError> {
+    if s.is_empty() {
         return Err(ParseError::Empty);
     }
 
     let mut ops = Vec::from(mem::take(cigar));
+    let mut src = s;
 
     while !src.is_empty() {
-        let op = parse_op(&mut src).map_err(ParseError::InvalidOp)?;
+        let position = s.len() - src.len();
+
+        let op = parse_op(&mut src).map_err(|e| ParseError::InvalidOp {
+            position,
+            token: raw_op(&s[position..]),
+            error: e,
+        })?;
+
         ops.push(op);
     }
 
@@ -52,6 +69,17 @@ pub(crate) fn parse_cigar(mut src: &[u8], cigar: &mut Cigar) -> Result<(), Parse
     Ok(())
 }
 
+// An op is a length followed by a single kind character.
+fn raw_op(src: &[u8]) -> String {
+    let end = src
+        .iter()
+        .position(|b| !b.is_ascii_digit())
+        .map(|i| i + 1)
+        .unwrap_or(src.len());
+
+    String::from_utf8_lossy(&src[..end]).into()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,10 +113,28 @@ mod tests {
 
         cigar.clear();
         assert!(matches!(
-            parse_cigar(b"8Z", &mut cigar),
-            Err(ParseError::InvalidOp(_))
+            parse_cigar(b"10Z", &mut cigar),
+            Err(ParseError::InvalidOp { position: 0, token, .. }) if token == "10Z"
+        ));
+
+        cigar.clear();
+        assert!(matches!(
+            parse_cigar(b"4M10Z", &mut cigar),
+            Err(ParseError::InvalidOp { position: 2, token, .. }) if token == "10Z"
         ));
 
+        cigar.clear();
+        assert!(matches!(
+            parse_cigar(b"M10", &mut cigar),
+            Err(ParseError::InvalidOp { position: 0, token, .. }) if token == "M"
+        ));
+
+        cigar.clear();
+        assert_eq!(
+            parse_cigar(b"4M10Z", &mut cigar).map_err(|e| e.to_string()),
+            Err(String::from(r#"invalid op at position 2: "10Z""#))
+        );
+
         Ok(())
     }
 }
@@ -39,9 +39,13 @@ pub(super) fn parse_op(src: &mut &[u8]) -> Result<Op, ParseError> {
 }
 
 fn parse_len(src: &mut &[u8]) -> Result<usize, ParseError> {
-    let (len, i) = lexical_core::parse_partial(src).map_err(|_| ParseError::InvalidLength)?;
-    *src = &src[i..];
-    Ok(len)
+    match lexical_core::parse_partial(src) {
+        Ok((len, i)) if i > 0 => {
+            *src = &src[i..];
+            Ok(len)
+        }
+        _ => Err(ParseError::InvalidLength),
+    }
 }
 
 #[cfg(test)]
@@ -64,6 +68,10 @@ mod tests {
         let mut src = &data[..];
         assert_eq!(parse_op(&mut src), Err(ParseError::InvalidLength));
 
+        let data = b"M";
+        let mut src = &data[..];
+        assert_eq!(parse_op(&mut src), Err(ParseError::InvalidLength));
+
         let data = b"8Z";
         let mut src = &data[..];
         assert!(matches!(
@@ -54,6 +54,24 @@ pub(super) fn parse_array(src: &mut &[u8]) -> Result<Array, ParseError> {
         }
     }
 
+    // Values are parsed up to the next delimiter so that a value that is out of range of the
+    // subtype is invalid rather than partially parsed.
+    fn parse_value<N>(src: &mut &[u8]) -> Result<N, ParseError>
+    where
+        N: lexical_core::FromLexical,
+    {
+        let i = src
+            .iter()
+            .position(|&b| b == DELIMITER)
+            .unwrap_or(src.len());
+
+        let (buf, rest) = src.split_at(i);
+        let value = lexical_core::parse(buf).map_err(ParseError::InvalidValue)?;
+        *src = rest;
+
+        Ok(value)
+    }
+
     let subtype = parse_subtype(src).map_err(ParseError::InvalidSubtype)?;
 
     match subtype {
@@ -62,9 +80,7 @@ pub(super) fn parse_array(src: &mut &[u8]) -> Result<Array, ParseError> {
 
             while !src.is_empty() {
                 consume_delimiter(src)?;
-                let (value, i) =
-                    lexical_core::parse_partial(src).map_err(ParseError::InvalidValue)?;
-                *src = &src[i..];
+                let value = parse_value(src)?;
                 values.push(value);
             }
 
@@ -75,9 +91,7 @@ pub(super) fn parse_array(src: &mut &[u8]) -> Result<Array, ParseError> {
 
             while !src.is_empty() {
                 consume_delimiter(src)?;
-                let (value, i) =
-                    lexical_core::parse_partial(src).map_err(ParseError::InvalidValue)?;
-                *src = &src[i..];
+                let value = parse_value(src)?;
                 values.push(value);
             }
 
@@ -88,9 +102,7 @@ pub(super) fn parse_array(src: &mut &[u8]) -> Result<Array, ParseError> {
 
             while !src.is_empty() {
                 consume_delimiter(src)?;
-                let (value, i) =
-                    lexical_core::parse_partial(src).map_err(ParseError::InvalidValue)?;
-                *src = &src[i..];
+                let value = parse_value(src)?;
                 values.push(value);
             }
 
@@ -101,9 +113,7 @@ pub(super) fn parse_array(src: &mut &[u8]) -> Result<Array, ParseError> {
 
             while !src.is_empty() {
                 consume_delimiter(src)?;
-                let (value, i) =
-                    lexical_core::parse_partial(src).map_err(ParseError::InvalidValue)?;
-                *src = &src[i..];
+                let value = parse_value(src)?;
                 values.push(value);
             }
 
@@ -114,9 +124,7 @@ pub(super) fn parse_array(src: &mut &[u8]) -> Result<Array, ParseError> {
 
             while !src.is_empty() {
                 consume_delimiter(src)?;
-                let (value, i) =
-                    lexical_core::parse_partial(src).map_err(ParseError::InvalidValue)?;
-                *src = &src[i..];
+                let value = parse_value(src)?;
                 values.push(value);
             }
 
@@ -127,9 +135,7 @@ pub(super) fn parse_array(src: &mut &[u8]) -> Result<Array, ParseError> {
 
             while !src.is_empty() {
                 consume_delimiter(src)?;
-                let (value, i) =
-                    lexical_core::parse_partial(src).map_err(ParseError::InvalidValue)?;
-                *src = &src[i..];
+                let value = parse_value(src)?;
                 values.push(value);
             }
 
@@ -140,9 +146,7 @@ pub(super) fn parse_array(src: &mut &[u8]) -> Result<Array, ParseError> {
 
             while !src.is_empty() {
                 consume_delimiter(src)?;
-                let (value, i) =
-                    lexical_core::parse_partial(src).map_err(ParseError::InvalidValue)?;
-                *src = &src[i..];
+                let value = parse_value(src)?;
                 values.push(value);
             }
 
@@ -189,4 +193,21 @@ mod tests {
         t(b"f,0", Array::Float(vec![0.0]));
         t(b"f,0,0", Array::Float(vec![0.0, 0.0]));
     }
+
+    #[test]
+    fn test_parse_array_with_values_out_of_subtype_range() {
+        fn t(mut src: &[u8]) {
+            assert!(matches!(
+                parse_array(&mut src),
+                Err(ParseError::InvalidValue(_))
+            ));
+        }
+
+        t(b"c,128");
+        t(b"C,-1");
+        t(b"s,32768");
+        t(b"S,-1");
+        t(b"i,2147483648");
+        t(b"I,-1");
+    }
 }
@@ -27,7 +27,7 @@ impl fmt::Display for ParseError {
     }
 }
 
-pub(super) fn parse_reference_sequence_id(
+pub(crate) fn parse_reference_sequence_id(
     header: &Header,
     src: &[u8],
 ) -> Result<usize, ParseError> {
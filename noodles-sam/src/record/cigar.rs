@@ -82,6 +82,34 @@ impl Cigar {
             .filter_map(|op| op.kind().consumes_read().then_some(op.len()))
             .sum()
     }
+
+    /// Simplifies the CIGAR.
+    ///
+    /// This drops operations with a length of 0 and merges adjacent operations of the same kind.
+    /// The order of operations is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Cigar;
+    ///
+    /// let mut cigar: Cigar = "2M3M0I4D1M".parse()?;
+    /// cigar.simplify();
+    /// assert_eq!(cigar.to_string(), "5M4D1M");
+    /// # Ok::<_, noodles_sam::record::cigar::ParseError>(())
+    /// ```
+    pub fn simplify(&mut self) {
+        self.0.retain(|op| !op.is_empty());
+
+        self.0.dedup_by(|op, prev_op| {
+            if op.kind() == prev_op.kind() {
+                *prev_op = Op::new(prev_op.kind(), prev_op.len() + op.len());
+                true
+            } else {
+                false
+            }
+        });
+    }
 }
 
 impl Deref for Cigar {
@@ -124,13 +152,20 @@ pub enum ParseError {
     /// The input is invalid.
     Invalid,
     /// The CIGAR string has an invalid operation.
-    InvalidOp(op::ParseError),
+    InvalidOp {
+        /// The 0-based byte offset of the operation in the input.
+        position: usize,
+        /// The raw operation.
+        token: String,
+        /// The operation parse error.
+        error: op::ParseError,
+    },
 }
 
 impl error::Error for ParseError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
-            Self::InvalidOp(e) => Some(e),
+            Self::InvalidOp { error, .. } => Some(error),
             _ => None,
         }
     }
@@ -141,7 +176,9 @@ impl fmt::Display for ParseError {
         match self {
             Self::Empty => f.write_str("empty input"),
             Self::Invalid => f.write_str("invalid input"),
-            Self::InvalidOp(_) => f.write_str("invalid op"),
+            Self::InvalidOp {
+                position, token, ..
+            } => write!(f, "invalid op at position {position}: {token:?}"),
         }
     }
 }
@@ -160,7 +197,14 @@ impl FromStr for Cigar {
         let mut start = 0;
 
         for (end, raw_kind) in matches {
-            let op = s[start..=end].parse().map_err(ParseError::InvalidOp)?;
+            let token = &s[start..end + raw_kind.len()];
+
+            let op = token.parse().map_err(|e| ParseError::InvalidOp {
+                position: start,
+                token: token.into(),
+                error: e,
+            })?;
+
             ops.push(op);
             start = end + raw_kind.len();
         }
@@ -183,6 +227,30 @@ impl From<Cigar> for Vec<Op> {
 mod tests {
     use super::{op::Kind, *};
 
+    #[test]
+    fn test_simplify() -> Result<(), ParseError> {
+        fn t(s: &str, expected: &str) -> Result<(), ParseError> {
+            let mut cigar: Cigar = s.parse()?;
+            cigar.simplify();
+            assert_eq!(cigar.to_string(), expected);
+            Ok(())
+        }
+
+        let mut cigar = Cigar::default();
+        cigar.simplify();
+        assert!(cigar.is_empty());
+
+        t("5M", "5M")?;
+        t("2M3M", "5M")?;
+        t("1M2M3M1I", "6M1I")?;
+        t("2M0I3M", "5M")?;
+        t("0M", "")?;
+        t("4S2M1I1I3M4S", "4S2M2I3M4S")?;
+        t("2M1D2M", "2M1D2M")?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_is_empty() -> Result<(), ParseError> {
         let cigar = Cigar::default();
@@ -225,9 +293,29 @@ mod tests {
 
         assert!(matches!(
             "*".parse::<Cigar>(),
-            Err(ParseError::InvalidOp(_))
+            Err(ParseError::InvalidOp { position: 0, .. })
+        ));
+
+        assert!(matches!(
+            "10Z".parse::<Cigar>(),
+            Err(ParseError::InvalidOp { position: 0, token, .. }) if token == "10Z"
+        ));
+
+        assert!(matches!(
+            "4M10Z".parse::<Cigar>(),
+            Err(ParseError::InvalidOp { position: 2, token, .. }) if token == "10Z"
+        ));
+
+        assert!(matches!(
+            "M10".parse::<Cigar>(),
+            Err(ParseError::InvalidOp { position: 0, token, .. }) if token == "M"
         ));
 
+        assert_eq!(
+            "4M10Z".parse::<Cigar>().map_err(|e| e.to_string()),
+            Err(String::from(r#"invalid op at position 2: "10Z""#))
+        );
+
         Ok(())
     }
 }
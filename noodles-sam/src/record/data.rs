@@ -222,6 +222,44 @@ impl Data {
         self.swap_remove(tag)
     }
 
+    /// Returns whether the data has the same fields as another, ignoring the order of fields.
+    ///
+    /// Unlike `PartialEq`, which compares fields in order, this compares the set of fields. Integer
+    /// values are compared by value, regardless of their width, e.g., `Value::UInt8(1)` is
+    /// semantically equal to `Value::Int32(1)`. This is useful when comparing data decoded from
+    /// different formats, which may order or encode fields differently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{data::field::{tag, Value}, Data};
+    ///
+    /// let nh = (tag::ALIGNMENT_HIT_COUNT, Value::from(1));
+    /// let rg = (tag::READ_GROUP, Value::String(String::from("rg0")));
+    ///
+    /// let a: Data = [nh.clone(), rg.clone()].into_iter().collect();
+    /// let b: Data = [rg, nh].into_iter().collect();
+    ///
+    /// assert!(a.semantic_eq(&b));
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        fn value_eq(a: &field::Value, b: &field::Value) -> bool {
+            match (a.as_int(), b.as_int()) {
+                (Some(m), Some(n)) => m == n,
+                _ => a == b,
+            }
+        }
+
+        self.len() == other.len()
+            && self.iter().all(|(tag, value)| {
+                other
+                    .get(&tag)
+                    .map(|other_value| value_eq(value, other_value))
+                    .unwrap_or_default()
+            })
+    }
+
     fn swap_remove<K>(&mut self, tag: &K) -> Option<(field::Tag, field::Value)>
     where
         K: indexmap::Equivalent<field::Tag>,
@@ -359,6 +397,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_semantic_eq() {
+        let nh = (tag::ALIGNMENT_HIT_COUNT, Value::from(1));
+        let rg = (tag::READ_GROUP, Value::String(String::from("rg0")));
+        let nm = (tag::EDIT_DISTANCE, Value::from(0));
+
+        let a: Data = [nh.clone(), rg.clone()].into_iter().collect();
+        let b: Data = [rg.clone(), nh.clone()].into_iter().collect();
+        assert!(a.semantic_eq(&b));
+        assert!(b.semantic_eq(&a));
+        assert_ne!(a, b);
+
+        let b: Data = [
+            (tag::READ_GROUP, Value::String(String::from("rg0"))),
+            (tag::ALIGNMENT_HIT_COUNT, Value::UInt8(1)),
+        ]
+        .into_iter()
+        .collect();
+        assert!(a.semantic_eq(&b));
+
+        let b: Data = [rg.clone()].into_iter().collect();
+        assert!(!a.semantic_eq(&b));
+
+        let b: Data = [rg.clone(), nh.clone(), nm].into_iter().collect();
+        assert!(!a.semantic_eq(&b));
+
+        let b: Data = [rg, (tag::ALIGNMENT_HIT_COUNT, Value::from(2))]
+            .into_iter()
+            .collect();
+        assert!(!a.semantic_eq(&b));
+
+        let b: Data = [nh, (tag::READ_GROUP, Value::String(String::from("rg1")))]
+            .into_iter()
+            .collect();
+        assert!(!a.semantic_eq(&b));
+    }
+
     #[test]
     fn test_fmt() {
         let data: Data = [
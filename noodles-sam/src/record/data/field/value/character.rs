@@ -87,12 +87,16 @@ mod tests {
     fn test_try_from_u8_for_character() {
         assert_eq!(Character::try_from(b'n'), Ok(Character(b'n')));
         assert_eq!(Character::try_from(b'\t'), Err(ParseError::Invalid));
+        assert_eq!(Character::try_from(b' '), Err(ParseError::Invalid));
+        assert_eq!(Character::try_from(0x80), Err(ParseError::Invalid));
     }
 
     #[test]
     fn test_try_from_char_for_character() {
         assert_eq!(Character::try_from('n'), Ok(Character(b'n')));
         assert_eq!(Character::try_from('\t'), Err(ParseError::Invalid));
+        assert_eq!(Character::try_from(' '), Err(ParseError::Invalid));
+        assert_eq!(Character::try_from('é'), Err(ParseError::Invalid));
     }
 
     #[test]
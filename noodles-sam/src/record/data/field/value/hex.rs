@@ -81,6 +81,18 @@ fn is_upper_ascii_hexdigit(n: u8) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_str() {
+        assert_eq!("CAFE".parse(), Ok(Hex(String::from("CAFE"))));
+        assert_eq!("".parse(), Ok(Hex::default()));
+
+        assert_eq!("cafe".parse::<Hex>(), Err(ParseError::Invalid));
+        assert_eq!(
+            "CAF".parse::<Hex>(),
+            Err(ParseError::InvalidLength { actual: 3 })
+        );
+    }
+
     #[test]
     fn test_try_from_u8_slice_for_hex() {
         assert_eq!(Hex::try_from(&b"CAFE"[..]), Ok(Hex(String::from("CAFE"))));
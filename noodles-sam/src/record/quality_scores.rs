@@ -111,6 +111,22 @@ impl QualityScores {
         index.get_mut(self.0.as_mut())
     }
 
+    /// Returns an iterator over the raw scores.
+    ///
+    /// Each value is a Phred quality score, i.e., the score without the printable ASCII offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::QualityScores;
+    /// let quality_scores: QualityScores = "NDLS".parse()?;
+    /// assert_eq!(quality_scores.iter().collect::<Vec<_>>(), [45, 35, 43, 50]);
+    /// # Ok::<_, noodles_sam::record::quality_scores::ParseError>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.iter().copied().map(u8::from)
+    }
+
     /// Appends a score.
     ///
     /// # Examples
@@ -267,6 +283,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_iter() -> Result<(), ParseError> {
+        let quality_scores = QualityScores::default();
+        assert!(quality_scores.iter().next().is_none());
+
+        let quality_scores: QualityScores = "NDLS!".parse()?;
+        let sum: u32 = quality_scores.iter().map(u32::from).sum();
+        assert_eq!(sum, 45 + 35 + 43 + 50);
+
+        let mut histogram = [0; 64];
+
+        for score in quality_scores.iter() {
+            histogram[usize::from(score)] += 1;
+        }
+
+        assert_eq!(histogram[0], 1);
+        assert_eq!(histogram[45], 1);
+        assert_eq!(histogram.iter().sum::<u32>(), 5);
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), ParseError> {
         let quality_scores = QualityScores::default();
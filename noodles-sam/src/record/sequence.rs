@@ -128,6 +128,30 @@ impl Sequence {
     pub fn push(&mut self, base: Base) {
         self.0.push(base);
     }
+
+    /// Reverse complements the sequence in place.
+    ///
+    /// Each base is complemented using [`Base::complement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Sequence;
+    ///
+    /// let mut sequence: Sequence = "AACGR".parse()?;
+    /// sequence.reverse_complement();
+    ///
+    /// let expected: Sequence = "YCGTT".parse()?;
+    /// assert_eq!(sequence, expected);
+    /// # Ok::<_, noodles_sam::record::sequence::ParseError>(())
+    /// ```
+    pub fn reverse_complement(&mut self) {
+        self.0.reverse();
+
+        for base in &mut self.0 {
+            *base = base.complement();
+        }
+    }
 }
 
 impl AsRef<[Base]> for Sequence {
@@ -263,4 +287,21 @@ mod tests {
             Err(ParseError::InvalidBase(_))
         ));
     }
+
+    #[test]
+    fn test_reverse_complement() -> Result<(), ParseError> {
+        let mut sequence: Sequence = "ACGT".parse()?;
+        sequence.reverse_complement();
+        assert_eq!(sequence, "ACGT".parse()?);
+
+        let mut sequence: Sequence = "ANCG".parse()?;
+        sequence.reverse_complement();
+        assert_eq!(sequence, "CGNT".parse()?);
+
+        let mut sequence: Sequence = "RSKB".parse()?;
+        sequence.reverse_complement();
+        assert_eq!(sequence, "VMSY".parse()?);
+
+        Ok(())
+    }
 }
@@ -64,6 +64,40 @@ pub enum Base {
     Eq,
 }
 
+impl Base {
+    /// Returns the complement of the base.
+    ///
+    /// This uses the IUPAC complement table for nucleotide ambiguity codes, e.g., `R` (A or G) is
+    /// complemented to `Y` (C or T). Bases that are their own complement (e.g., `S`, `W`, `N`) and
+    /// symbols with no nucleotide meaning (e.g., `=`) are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::sequence::Base;
+    /// assert_eq!(Base::A.complement(), Base::T);
+    /// assert_eq!(Base::R.complement(), Base::Y);
+    /// assert_eq!(Base::N.complement(), Base::N);
+    /// ```
+    pub fn complement(self) -> Self {
+        match self {
+            Self::A => Self::T,
+            Self::B => Self::V,
+            Self::C => Self::G,
+            Self::D => Self::H,
+            Self::G => Self::C,
+            Self::H => Self::D,
+            Self::K => Self::M,
+            Self::M => Self::K,
+            Self::R => Self::Y,
+            Self::T | Self::U => Self::A,
+            Self::V => Self::B,
+            Self::Y => Self::R,
+            _ => self,
+        }
+    }
+}
+
 impl fmt::Display for Base {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_char(char::from(*self))
@@ -96,36 +130,55 @@ impl TryFrom<u8> for Base {
     type Error = TryFromCharError;
 
     fn try_from(n: u8) -> Result<Self, Self::Error> {
-        match n.to_ascii_uppercase() {
-            b'A' => Ok(Self::A),
-            b'B' => Ok(Self::B),
-            b'C' => Ok(Self::C),
-            b'D' => Ok(Self::D),
-            b'E' => Ok(Self::E),
-            b'F' => Ok(Self::F),
-            b'G' => Ok(Self::G),
-            b'H' => Ok(Self::H),
-            b'I' => Ok(Self::I),
-            b'J' => Ok(Self::J),
-            b'K' => Ok(Self::K),
-            b'L' => Ok(Self::L),
-            b'M' => Ok(Self::M),
-            b'N' => Ok(Self::N),
-            b'O' => Ok(Self::O),
-            b'P' => Ok(Self::P),
-            b'Q' => Ok(Self::Q),
-            b'R' => Ok(Self::R),
-            b'S' => Ok(Self::S),
-            b'T' => Ok(Self::T),
-            b'U' => Ok(Self::U),
-            b'V' => Ok(Self::V),
-            b'W' => Ok(Self::W),
-            b'X' => Ok(Self::X),
-            b'Y' => Ok(Self::Y),
-            b'Z' => Ok(Self::Z),
-            b'=' => Ok(Self::Eq),
-            _ => Err(TryFromCharError(char::from(n))),
-        }
+        BASES[usize::from(n)].ok_or(TryFromCharError(char::from(n)))
+    }
+}
+
+// A lookup table of bytes to bases.
+const BASES: [Option<Base>; 256] = build_bases();
+
+const fn build_bases() -> [Option<Base>; 256] {
+    let mut bases = [None; 256];
+    let mut i = 0;
+
+    while i < bases.len() {
+        bases[i] = decode_base(i as u8);
+        i += 1;
+    }
+
+    bases
+}
+
+const fn decode_base(n: u8) -> Option<Base> {
+    match n.to_ascii_uppercase() {
+        b'A' => Some(Base::A),
+        b'B' => Some(Base::B),
+        b'C' => Some(Base::C),
+        b'D' => Some(Base::D),
+        b'E' => Some(Base::E),
+        b'F' => Some(Base::F),
+        b'G' => Some(Base::G),
+        b'H' => Some(Base::H),
+        b'I' => Some(Base::I),
+        b'J' => Some(Base::J),
+        b'K' => Some(Base::K),
+        b'L' => Some(Base::L),
+        b'M' => Some(Base::M),
+        b'N' => Some(Base::N),
+        b'O' => Some(Base::O),
+        b'P' => Some(Base::P),
+        b'Q' => Some(Base::Q),
+        b'R' => Some(Base::R),
+        b'S' => Some(Base::S),
+        b'T' => Some(Base::T),
+        b'U' => Some(Base::U),
+        b'V' => Some(Base::V),
+        b'W' => Some(Base::W),
+        b'X' => Some(Base::X),
+        b'Y' => Some(Base::Y),
+        b'Z' => Some(Base::Z),
+        b'=' => Some(Base::Eq),
+        _ => None,
     }
 }
 
@@ -208,6 +261,29 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_complement() {
+        assert_eq!(Base::A.complement(), Base::T);
+        assert_eq!(Base::C.complement(), Base::G);
+        assert_eq!(Base::G.complement(), Base::C);
+        assert_eq!(Base::T.complement(), Base::A);
+        assert_eq!(Base::U.complement(), Base::A);
+
+        assert_eq!(Base::R.complement(), Base::Y);
+        assert_eq!(Base::Y.complement(), Base::R);
+        assert_eq!(Base::K.complement(), Base::M);
+        assert_eq!(Base::M.complement(), Base::K);
+        assert_eq!(Base::B.complement(), Base::V);
+        assert_eq!(Base::V.complement(), Base::B);
+        assert_eq!(Base::D.complement(), Base::H);
+        assert_eq!(Base::H.complement(), Base::D);
+
+        assert_eq!(Base::S.complement(), Base::S);
+        assert_eq!(Base::W.complement(), Base::W);
+        assert_eq!(Base::N.complement(), Base::N);
+        assert_eq!(Base::Eq.complement(), Base::Eq);
+    }
+
     #[test]
     fn test_try_from_char_for_base() {
         for (c, &expected) in ('A'..='Z').zip(ALPHA_BASES) {
@@ -230,6 +306,11 @@ mod tests {
         assert_eq!(Base::try_from(b'='), Ok(Base::Eq));
 
         assert_eq!(Base::try_from(b'*'), Err(TryFromCharError('*')));
+
+        for n in 0..=u8::MAX {
+            let is_valid = n.is_ascii_alphabetic() || n == b'=';
+            assert_eq!(Base::try_from(n).is_ok(), is_valid, "{n}");
+        }
     }
 
     #[test]
@@ -1,5 +1,10 @@
-mod num;
-mod record;
+//! SAM writer.
+
+mod builder;
+pub(crate) mod num;
+pub(crate) mod record;
+
+pub use self::{builder::Builder, record::DataFieldOrder};
 
 use std::io::{self, Write};
 
@@ -41,6 +46,7 @@ where
     W: Write,
 {
     inner: W,
+    data_field_order: DataFieldOrder,
 }
 
 impl<W> Writer<W>
@@ -56,7 +62,7 @@ where
     /// let writer = sam::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Builder::default().build_with_writer(inner)
     }
 
     /// Returns a reference to the underlying writer.
@@ -136,7 +142,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        write_record(&mut self.inner, header, record, self.data_field_order)
     }
 }
 
@@ -149,10 +155,100 @@ where
     }
 
     fn write_alignment_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        write_record(&mut self.inner, header, record, self.data_field_order)
     }
 
     fn finish(&mut self, _: &Header) -> io::Result<()> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_record_with_data_field_order() -> io::Result<()> {
+        use crate::record::data::field::{tag, Value};
+
+        let header = Header::default();
+
+        let data = [
+            (tag::READ_GROUP, Value::String(String::from("rg0"))),
+            (tag::ALIGNMENT_HIT_COUNT, Value::from(1)),
+            (tag::COMMENT, Value::String(String::from("noodles"))),
+        ]
+        .into_iter()
+        .collect();
+
+        let record = Record::builder().set_data(data).build();
+
+        let mut writer = Builder::default()
+            .set_data_field_order(DataFieldOrder::Insertion)
+            .build_with_writer(Vec::new());
+        writer.write_record(&header, &record)?;
+
+        let expected = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0\tNH:i:1\tCO:Z:noodles\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        let mut writer = Builder::default()
+            .set_data_field_order(DataFieldOrder::Sorted)
+            .build_with_writer(Vec::new());
+        writer.write_record(&header, &record)?;
+
+        let expected = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tCO:Z:noodles\tNH:i:1\tRG:Z:rg0\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_quality_scores_round_trip() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use crate::{
+            reader::record::parse_record,
+            record::{quality_scores::Score, QualityScores},
+        };
+
+        fn t(
+            header: &Header,
+            quality_scores: QualityScores,
+            expected_field: &[u8],
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let record = Record::builder()
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores(quality_scores)
+                .build();
+
+            let mut writer = Writer::new(Vec::new());
+            writer.write_record(header, &record)?;
+
+            let line = writer
+                .get_ref()
+                .strip_suffix(b"\n")
+                .ok_or("missing newline")?;
+            let actual_field = line.split(|&b| b == b'\t').nth(10);
+            assert_eq!(actual_field, Some(expected_field));
+
+            let mut actual = Record::default();
+            parse_record(line, header, &mut actual)?;
+            assert_eq!(actual.quality_scores(), record.quality_scores());
+
+            Ok(())
+        }
+
+        let header = Header::default();
+
+        t(&header, "NDLS".parse()?, b"NDLS")?;
+        t(
+            &header,
+            QualityScores::try_from(vec![45, 35, 43, 50])?,
+            b"NDLS",
+        )?;
+        t(&header, QualityScores::from(vec![Score::MIN; 4]), b"!!!!")?;
+        t(&header, QualityScores::from(vec![Score::MAX; 4]), b"~~~~")?;
+        t(&header, QualityScores::default(), b"*")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use std::io::Write;
+
+use super::{DataFieldOrder, Writer};
+
+/// A SAM writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    data_field_order: DataFieldOrder,
+}
+
+impl Builder {
+    /// Sets the order in which record data fields are written.
+    ///
+    /// By default, data fields are written in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, writer::DataFieldOrder};
+    ///
+    /// let builder = sam::writer::Builder::default()
+    ///     .set_data_field_order(DataFieldOrder::Sorted);
+    /// ```
+    pub fn set_data_field_order(mut self, data_field_order: DataFieldOrder) -> Self {
+        self.data_field_order = data_field_order;
+        self
+    }
+
+    /// Builds a SAM writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam as sam;
+    /// let writer = sam::writer::Builder::default().build_with_writer(io::sink());
+    /// ```
+    pub fn build_with_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: Write,
+    {
+        Writer {
+            inner: writer,
+            data_field_order: self.data_field_order,
+        }
+    }
+}
@@ -5,8 +5,11 @@ mod quality_scores;
 mod sequence;
 
 pub use self::{
-    cigar::write_cigar, data::write_data, position::write_position,
-    quality_scores::write_quality_scores, sequence::write_sequence,
+    cigar::write_cigar,
+    data::{write_data, DataFieldOrder},
+    position::write_position,
+    quality_scores::write_quality_scores,
+    sequence::write_sequence,
 };
 
 use std::io::{self, Write};
@@ -15,7 +18,12 @@ use crate::{alignment::Record, Header};
 
 const MISSING: u8 = b'*';
 
-pub fn write_record<W>(writer: &mut W, header: &Header, record: &Record) -> io::Result<()>
+pub fn write_record<W>(
+    writer: &mut W,
+    header: &Header,
+    record: &Record,
+    data_field_order: DataFieldOrder,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -87,7 +95,7 @@ where
     writer.write_all(DELIMITER)?;
     write_quality_scores(writer, record.sequence().len(), record.quality_scores())?;
 
-    write_data(writer, record.data())?;
+    write_data(writer, record.data(), data_field_order)?;
 
     writeln!(writer)?;
 
@@ -111,7 +119,7 @@ mod tests {
             .collect();
         let record = Record::builder().set_data(data).build();
 
-        write_record(&mut buf, &header, &record)?;
+        write_record(&mut buf, &header, &record, DataFieldOrder::default())?;
 
         let expected = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0\n";
         assert_eq!(buf, expected);
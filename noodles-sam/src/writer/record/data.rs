@@ -3,15 +3,43 @@ mod field;
 use std::io::{self, Write};
 
 use self::field::write_field;
-use crate::record::Data;
+use crate::record::{
+    data::field::{Tag, Value},
+    Data,
+};
 
-pub fn write_data<W>(writer: &mut W, data: &Data) -> io::Result<()>
+/// The order in which data fields are written.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DataFieldOrder {
+    /// Data fields are written in the order they were inserted into the record data.
+    #[default]
+    Insertion,
+    /// Data fields are written sorted by tag.
+    Sorted,
+}
+
+pub fn write_data<W>(writer: &mut W, data: &Data, order: DataFieldOrder) -> io::Result<()>
+where
+    W: Write,
+{
+    match order {
+        DataFieldOrder::Insertion => write_fields(writer, data.iter()),
+        DataFieldOrder::Sorted => {
+            let mut fields: Vec<_> = data.iter().collect();
+            fields.sort_by_key(|(tag, _)| *tag.as_ref());
+            write_fields(writer, fields)
+        }
+    }
+}
+
+fn write_fields<'a, W, I>(writer: &mut W, fields: I) -> io::Result<()>
 where
     W: Write,
+    I: IntoIterator<Item = (Tag, &'a Value)>,
 {
     const DELIMITER: u8 = b'\t';
 
-    for (tag, value) in data.iter() {
+    for (tag, value) in fields {
         writer.write_all(&[DELIMITER])?;
         write_field(writer, tag, value)?;
     }
@@ -36,10 +64,13 @@ mod tests {
         .into_iter()
         .collect();
 
-        write_data(&mut buf, &data)?;
-
+        write_data(&mut buf, &data, DataFieldOrder::Insertion)?;
         assert_eq!(buf, b"\tNH:i:1\tCO:Z:noodles");
 
+        buf.clear();
+        write_data(&mut buf, &data, DataFieldOrder::Sorted)?;
+        assert_eq!(buf, b"\tCO:Z:noodles\tNH:i:1");
+
         Ok(())
     }
 }
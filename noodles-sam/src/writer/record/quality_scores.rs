@@ -37,6 +37,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::record::quality_scores::Score;
 
     #[test]
     fn test_write_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
@@ -58,6 +59,9 @@ mod tests {
         t(&mut buf, 4, &QualityScores::default(), &[b'*'])?;
         t(&mut buf, 4, &"NDLS".parse()?, &[b'N', b'D', b'L', b'S'])?;
 
+        let quality_scores = QualityScores::from(vec![Score::MIN, Score::MAX]);
+        t(&mut buf, 2, &quality_scores, b"!~")?;
+
         let quality_scores = "NDLS".parse()?;
         buf.clear();
         assert!(matches!(
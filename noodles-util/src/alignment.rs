@@ -2,6 +2,10 @@
 
 mod format;
 pub mod reader;
+mod reference_sequences;
 pub mod writer;
 
-pub use self::{format::Format, reader::Reader, writer::Writer};
+pub use self::{
+    format::Format, reader::Reader, reference_sequences::reference_sequences_from_fai,
+    writer::Writer,
+};
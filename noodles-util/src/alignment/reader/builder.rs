@@ -12,6 +12,7 @@ use noodles_sam as sam;
 
 use super::Reader;
 use crate::alignment::Format;
+use crate::format::PeekReader;
 
 /// An alignment reader builder.
 #[derive(Default)]
@@ -95,7 +96,7 @@ impl Builder {
     where
         R: Read + 'static,
     {
-        let mut reader = BufReader::new(reader);
+        let mut reader = PeekReader::new(BufReader::new(reader));
 
         let format = self
             .format
@@ -130,35 +131,28 @@ impl Builder {
     }
 }
 
-fn detect_format<R>(reader: &mut R) -> io::Result<Format>
+fn detect_format<R>(reader: &mut PeekReader<R>) -> io::Result<Format>
 where
     R: BufRead,
 {
-    const CRAM_MAGIC_NUMBER: [u8; 4] = [b'C', b'R', b'A', b'M'];
-    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
-    const BAM_MAGIC_NUMBER: [u8; 4] = [b'B', b'A', b'M', 0x01];
-
-    let src = reader.fill_buf()?;
-
-    if let Some(buf) = src.get(..4) {
-        if buf == CRAM_MAGIC_NUMBER {
-            return Ok(Format::Cram);
-        }
-
-        if buf[..2] == GZIP_MAGIC_NUMBER {
-            let mut reader = bgzf::Reader::new(src);
-            let mut buf = [0; 4];
-            reader.read_exact(&mut buf).ok();
-
-            if buf == BAM_MAGIC_NUMBER {
-                return Ok(Format::Bam);
-            } else {
-                return Ok(Format::SamGz);
-            }
-        }
+    use crate::format::{self, Compression};
+
+    let compression = format::detect_compression(reader)?;
+
+    match (format::detect_format(reader)?, compression) {
+        (_, Some(Compression::Gzip)) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unsupported compression: gzip",
+        )),
+        (format::Format::Bam, _) => Ok(Format::Bam),
+        (format::Format::Cram, _) => Ok(Format::Cram),
+        (format::Format::Text, None) => Ok(Format::Sam),
+        (format::Format::Text, Some(Compression::Bgzf)) => Ok(Format::SamGz),
+        (format::Format::Bcf, _) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid alignment format: BCF",
+        )),
     }
-
-    Ok(Format::Sam)
 }
 
 #[cfg(test)]
@@ -169,8 +163,9 @@ mod tests {
     fn test_detect_format() -> io::Result<()> {
         use std::io::Write;
 
-        fn t(mut src: &[u8], expected: Format) {
-            assert!(matches!(detect_format(&mut src), Ok(value) if value == expected));
+        fn t(src: &[u8], expected: Format) {
+            let mut reader = PeekReader::new(src);
+            assert!(matches!(detect_format(&mut reader), Ok(value) if value == expected));
         }
 
         t(b"@HD\tVN:1.6\n", Format::Sam);
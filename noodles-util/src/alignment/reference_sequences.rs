@@ -0,0 +1,81 @@
+use std::{io, num::NonZeroUsize};
+
+use noodles_fasta::fai;
+use noodles_sam::header::{
+    record::value::{map::ReferenceSequence, Map},
+    ReferenceSequences,
+};
+
+/// Builds a reference sequence dictionary from a FASTA index.
+///
+/// Each FASTA index record is added as a reference sequence with its name and length (`LN`), in
+/// index order.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::fai;
+/// use noodles_util::alignment;
+///
+/// let index = vec![
+///     fai::Record::new("sq0", 8, 5, 8, 9),
+///     fai::Record::new("sq1", 13, 19, 13, 14),
+/// ];
+///
+/// let reference_sequences = alignment::reference_sequences_from_fai(&index)?;
+/// assert_eq!(reference_sequences.len(), 2);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn reference_sequences_from_fai(index: &fai::Index) -> io::Result<ReferenceSequences> {
+    index
+        .iter()
+        .map(|record| {
+            let name = record
+                .name()
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            let length = usize::try_from(record.length())
+                .and_then(NonZeroUsize::try_from)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            Ok((name, Map::<ReferenceSequence>::new(length)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam as sam;
+
+    use super::*;
+
+    #[test]
+    fn test_reference_sequences_from_fai() -> Result<(), Box<dyn std::error::Error>> {
+        let index = vec![
+            fai::Record::new("sq0", 8, 5, 8, 9),
+            fai::Record::new("sq1", 13, 19, 13, 14),
+        ];
+
+        let reference_sequences = reference_sequences_from_fai(&index)?;
+
+        let mut header = sam::Header::default();
+        *header.reference_sequences_mut() = reference_sequences;
+
+        let mut writer = sam::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let actual = String::from_utf8(writer.get_ref().clone())?;
+        let expected = "@SQ\tSN:sq0\tLN:8\n@SQ\tSN:sq1\tLN:13\n";
+        assert_eq!(actual, expected);
+
+        let index = vec![fai::Record::new("sq0", 0, 5, 0, 1)];
+        assert!(matches!(
+            reference_sequences_from_fai(&index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
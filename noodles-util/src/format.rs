@@ -0,0 +1,394 @@
+//! File format detection.
+
+use std::io::{self, BufRead, Read};
+
+use flate2::bufread::MultiGzDecoder;
+use noodles_bgzf as bgzf;
+
+/// A detected file format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Binary Alignment/Map (BAM).
+    Bam,
+    /// BCF.
+    Bcf,
+    /// CRAM.
+    Cram,
+    /// A text format, e.g., SAM or VCF.
+    Text,
+}
+
+/// A detected file compression.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Compression {
+    /// BGZF compression.
+    Bgzf,
+    /// gzip compression without BGZF block headers.
+    Gzip,
+}
+
+/// A reader that buffers peeked input.
+///
+/// The input returned by [`Self::peek`] is not consumed and is read again by subsequent reads.
+pub struct PeekReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    position: usize,
+}
+
+impl<R> PeekReader<R>
+where
+    R: BufRead,
+{
+    /// Creates a peek reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::format::PeekReader;
+    /// let reader = PeekReader::new(&b"noodles"[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buf: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Returns at least `len` bytes of input without consuming it.
+    ///
+    /// This reads from the underlying reader until `len` bytes are buffered. Fewer bytes are
+    /// returned only if the underlying reader reaches EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Read};
+    /// use noodles_util::format::PeekReader;
+    ///
+    /// let mut reader = PeekReader::new(&b"noodles"[..]);
+    /// assert_eq!(reader.peek(4)?, b"nood");
+    ///
+    /// let mut buf = String::new();
+    /// reader.read_to_string(&mut buf)?;
+    /// assert_eq!(buf, "noodles");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn peek(&mut self, len: usize) -> io::Result<&[u8]> {
+        while self.buf.len() - self.position < len {
+            let src = self.inner.fill_buf()?;
+
+            if src.is_empty() {
+                break;
+            }
+
+            let n = src.len().min(len - (self.buf.len() - self.position));
+            self.buf.extend_from_slice(&src[..n]);
+            self.inner.consume(n);
+        }
+
+        let end = self.buf.len().min(self.position + len);
+        Ok(&self.buf[self.position..end])
+    }
+}
+
+impl<R> Read for PeekReader<R>
+where
+    R: BufRead,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut src = self.fill_buf()?;
+        let amt = src.read(buf)?;
+        self.consume(amt);
+        Ok(amt)
+    }
+}
+
+impl<R> BufRead for PeekReader<R>
+where
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.position < self.buf.len() {
+            Ok(&self.buf[self.position..])
+        } else {
+            self.inner.fill_buf()
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let remaining = self.buf.len() - self.position;
+
+        if amt < remaining {
+            self.position += amt;
+        } else {
+            self.buf.clear();
+            self.position = 0;
+            self.inner.consume(amt - remaining);
+        }
+    }
+}
+
+const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+// § 4.1 "The BGZF compression format" (2023-05-24): a gzip header with the FEXTRA flag set and a
+// `BC` extra subfield (SLEN = 2) holding the block size.
+const BGZF_HEADER_SIZE: usize = 18;
+const GZIP_CM_DEFLATE: u8 = 0x08;
+const GZIP_FLG_FEXTRA: u8 = 0x04;
+const BGZF_SUBFIELD: [u8; 4] = [b'B', b'C', 0x02, 0x00];
+
+// The amount of plain gzip input peeked to decompress a magic number.
+const GZIP_PEEK_SIZE: usize = 1 << 16;
+
+const MAGIC_NUMBER_SIZE: usize = 4;
+
+/// Detects the compression of the input.
+///
+/// This peeks at the input and does not consume it. Input that starts with the gzip magic number
+/// but not a complete BGZF block header, e.g., because the stream is shorter than the header, is
+/// detected as [`Compression::Gzip`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_util::format::{detect_compression, PeekReader};
+/// let mut reader = PeekReader::new(&b"##fileformat=VCFv4.3\n"[..]);
+/// assert!(detect_compression(&mut reader)?.is_none());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn detect_compression<R>(reader: &mut PeekReader<R>) -> io::Result<Option<Compression>>
+where
+    R: BufRead,
+{
+    let src = reader.peek(BGZF_HEADER_SIZE)?;
+
+    if !src.starts_with(&GZIP_MAGIC_NUMBER) {
+        Ok(None)
+    } else if is_bgzf_header(src) {
+        Ok(Some(Compression::Bgzf))
+    } else {
+        Ok(Some(Compression::Gzip))
+    }
+}
+
+fn is_bgzf_header(src: &[u8]) -> bool {
+    src.len() >= BGZF_HEADER_SIZE
+        && src[2] == GZIP_CM_DEFLATE
+        && src[3] & GZIP_FLG_FEXTRA != 0
+        && src[12..16] == BGZF_SUBFIELD
+}
+
+/// Detects the format of the input.
+///
+/// This peeks at the magic number of the input and does not consume it. If the input is
+/// compressed, the magic number is read from the start of the decompressed data. For BGZF, this
+/// peeks the entire first block, using the block size in its header. If the stream ends before
+/// the first block does, the available input is decompressed as plain gzip instead. An input that
+/// does not start with a known magic number is detected as [`Format::Text`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_util::format::{detect_format, Format, PeekReader};
+///
+/// let mut reader = PeekReader::new(&b"CRAM\x03\x00"[..]);
+/// assert_eq!(detect_format(&mut reader)?, Format::Cram);
+///
+/// let mut reader = PeekReader::new(&b"##fileformat=VCFv4.3\n"[..]);
+/// assert_eq!(detect_format(&mut reader)?, Format::Text);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn detect_format<R>(reader: &mut PeekReader<R>) -> io::Result<Format>
+where
+    R: BufRead,
+{
+    match detect_compression(reader)? {
+        Some(Compression::Bgzf) => {
+            let src = reader.peek(BGZF_HEADER_SIZE)?;
+
+            // BSIZE is the total block size minus 1.
+            let block_size = usize::from(u16::from_le_bytes([src[16], src[17]])) + 1;
+
+            let block = reader.peek(block_size)?;
+
+            if block.len() < block_size {
+                return Ok(match_magic_number(&decompress_gzip_prefix(block)));
+            }
+
+            let decoder = bgzf::Reader::new(block);
+            let mut buf = Vec::with_capacity(MAGIC_NUMBER_SIZE);
+            decoder
+                .take(MAGIC_NUMBER_SIZE as u64)
+                .read_to_end(&mut buf)?;
+
+            Ok(match_magic_number(&buf))
+        }
+        Some(Compression::Gzip) => {
+            let src = reader.peek(GZIP_PEEK_SIZE)?;
+            Ok(match_magic_number(&decompress_gzip_prefix(src)))
+        }
+        None => {
+            let src = reader.peek(MAGIC_NUMBER_SIZE)?;
+            Ok(match_magic_number(src))
+        }
+    }
+}
+
+// Decompresses up to the size of a magic number from possibly incomplete gzip data.
+//
+// Decompression stops at the first error, e.g., when the input ends early, and returns the data
+// decompressed until then.
+fn decompress_gzip_prefix(src: &[u8]) -> Vec<u8> {
+    let mut decoder = MultiGzDecoder::new(src);
+    let mut buf = [0; MAGIC_NUMBER_SIZE];
+    let mut len = 0;
+
+    while len < buf.len() {
+        match decoder.read(&mut buf[len..]) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => len += n,
+        }
+    }
+
+    buf[..len].to_vec()
+}
+
+fn match_magic_number(src: &[u8]) -> Format {
+    const BAM_MAGIC_NUMBER: [u8; 4] = *b"BAM\x01";
+    const BCF_MAGIC_NUMBER: [u8; 3] = *b"BCF";
+    const CRAM_MAGIC_NUMBER: [u8; 4] = *b"CRAM";
+
+    if src.starts_with(&BAM_MAGIC_NUMBER) {
+        Format::Bam
+    } else if src.starts_with(&BCF_MAGIC_NUMBER) {
+        Format::Bcf
+    } else if src.starts_with(&CRAM_MAGIC_NUMBER) {
+        Format::Cram
+    } else {
+        Format::Text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Write};
+
+    use super::*;
+
+    fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        writer.finish()
+    }
+
+    fn gzip(data: &[u8]) -> io::Result<Vec<u8>> {
+        use flate2::{write::GzEncoder, Compression};
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data)?;
+        encoder.finish()
+    }
+
+    #[test]
+    fn test_peek_reader() -> io::Result<()> {
+        // The underlying reader only returns 1 byte at a time.
+        let mut reader = PeekReader::new(BufReader::with_capacity(1, &b"noodles"[..]));
+
+        assert_eq!(reader.peek(4)?, b"nood");
+        assert_eq!(reader.peek(2)?, b"no");
+        assert_eq!(reader.peek(16)?, b"noodles");
+
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"no");
+        assert_eq!(reader.peek(2)?, b"od");
+
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
+        assert_eq!(buf, "odles");
+
+        assert!(reader.peek(4)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_compression() -> io::Result<()> {
+        fn t(src: &[u8], expected: Option<Compression>) {
+            let mut reader = PeekReader::new(src);
+            assert!(matches!(detect_compression(&mut reader), Ok(value) if value == expected));
+        }
+
+        t(b"", None);
+        t(b"@HD\tVN:1.6\n", None);
+        t(&compress(b"BCF\x02\x02")?, Some(Compression::Bgzf));
+        t(&gzip(b"BCF\x02\x02")?, Some(Compression::Gzip));
+        // the stream is shorter than a BGZF header
+        t(&[0x1f, 0x8b], Some(Compression::Gzip));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_format() -> io::Result<()> {
+        fn t(src: &[u8], expected: Format) {
+            // The underlying reader only returns 1 byte at a time.
+            let mut reader = PeekReader::new(BufReader::with_capacity(1, src));
+            assert!(matches!(detect_format(&mut reader), Ok(format) if format == expected));
+
+            // The input is not consumed.
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf).unwrap();
+            assert_eq!(buf, src);
+        }
+
+        t(b"BAM\x01", Format::Bam);
+        t(b"BCF\x02\x02", Format::Bcf);
+        t(b"CRAM\x03\x00", Format::Cram);
+        t(b"@HD\tVN:1.6\n", Format::Text);
+        t(b"##fileformat=VCFv4.3\n", Format::Text);
+        t(b"", Format::Text);
+
+        t(&compress(b"BAM\x01")?, Format::Bam);
+        t(&compress(b"BCF\x02\x02")?, Format::Bcf);
+        t(&compress(b"##fileformat=VCFv4.3\n")?, Format::Text);
+
+        t(&gzip(b"BAM\x01")?, Format::Bam);
+        t(&gzip(b"##fileformat=VCFv4.3\n")?, Format::Text);
+        t(&[0x1f, 0x8b], Format::Text);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_format_with_truncated_bgzf_block() -> io::Result<()> {
+        let data = compress(b"BAM\x01")?;
+        let block_size = usize::from(u16::from_le_bytes([data[16], data[17]])) + 1;
+
+        // Drop the block trailer (CRC32 and ISIZE).
+        let mut reader = PeekReader::new(&data[..block_size - 8]);
+        assert_eq!(detect_format(&mut reader)?, Format::Bam);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_format_with_invalid_bgzf_block() -> io::Result<()> {
+        let mut data = compress(b"BAM\x01")?;
+        // Corrupt the CRC32 in the block trailer.
+        let block_size = usize::from(u16::from_le_bytes([data[16], data[17]])) + 1;
+        data[block_size - 8] ^= 0xff;
+        let mut reader = PeekReader::new(&data[..]);
+
+        assert!(matches!(
+            detect_format(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -5,5 +5,8 @@
 #[cfg(feature = "alignment")]
 pub mod alignment;
 
+#[cfg(any(feature = "alignment", feature = "variant"))]
+pub mod format;
+
 #[cfg(feature = "variant")]
 pub mod variant;
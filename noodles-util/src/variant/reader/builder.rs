@@ -8,7 +8,10 @@ use noodles_bcf as bcf;
 use noodles_bgzf as bgzf;
 use noodles_vcf::{self as vcf, VariantReader};
 
-use crate::variant::{Compression, Format};
+use crate::{
+    format::PeekReader,
+    variant::{Compression, Format},
+};
 
 use super::Reader;
 
@@ -89,7 +92,7 @@ impl Builder {
     where
         R: Read + 'static,
     {
-        let mut reader = BufReader::new(reader);
+        let mut reader = PeekReader::new(BufReader::new(reader));
 
         let compression = self
             .compression
@@ -99,7 +102,7 @@ impl Builder {
         let format = self
             .format
             .map(Ok)
-            .unwrap_or_else(|| detect_format(&mut reader, compression))?;
+            .unwrap_or_else(|| detect_format(&mut reader))?;
 
         let inner: Box<dyn VariantReader<_>> = match (format, compression) {
             (Format::Vcf, None) => {
@@ -124,39 +127,34 @@ impl Builder {
     }
 }
 
-fn detect_compression<R>(reader: &mut R) -> io::Result<Option<Compression>>
+fn detect_compression<R>(reader: &mut PeekReader<R>) -> io::Result<Option<Compression>>
 where
     R: BufRead,
 {
-    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
-
-    let src = reader.fill_buf()?;
-    if src.get(..2) == Some(&GZIP_MAGIC_NUMBER) {
-        Ok(Some(Compression::Bgzf))
-    } else {
-        Ok(None)
+    use crate::format;
+
+    match format::detect_compression(reader)? {
+        Some(format::Compression::Bgzf) => Ok(Some(Compression::Bgzf)),
+        Some(format::Compression::Gzip) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unsupported compression: gzip",
+        )),
+        None => Ok(None),
     }
 }
 
-fn detect_format<R>(reader: &mut R, compression: Option<Compression>) -> io::Result<Format>
+fn detect_format<R>(reader: &mut PeekReader<R>) -> io::Result<Format>
 where
     R: BufRead,
 {
-    const BCF_MAGIC_NUMBER: [u8; 3] = *b"BCF";
-
-    let src = reader.fill_buf()?;
-
-    if compression == Some(Compression::Bgzf) {
-        let mut reader = bgzf::Reader::new(src);
-        let mut buf = [0; 3];
-        reader.read_exact(&mut buf).ok();
-
-        if buf == BCF_MAGIC_NUMBER {
-            return Ok(Format::Bcf);
-        }
-    } else if src.get(..3) == Some(&BCF_MAGIC_NUMBER) {
-        return Ok(Format::Bcf);
+    use crate::format;
+
+    match format::detect_format(reader)? {
+        format::Format::Bcf => Ok(Format::Bcf),
+        format::Format::Text => Ok(Format::Vcf),
+        format::Format::Bam | format::Format::Cram => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid variant format",
+        )),
     }
-
-    Ok(Format::Vcf)
 }
@@ -0,0 +1,54 @@
+//! Compares looking up INFO header records by raw key with a linear search.
+//!
+//! Run with `cargo bench -p noodles-vcf --bench header_lookup`.
+
+use std::time::Instant;
+
+use noodles_vcf::{
+    self as vcf,
+    header::{self, record::value::Map},
+    record::info,
+};
+
+const KEY_COUNT: usize = 4096;
+
+fn build_header() -> Result<vcf::Header, info::field::key::ParseError> {
+    let mut builder = vcf::Header::builder();
+
+    for i in 0..KEY_COUNT {
+        let key: info::field::Key = format!("K{i}").parse()?;
+        let map = Map::from(&key);
+        builder = builder.add_info(key, map);
+    }
+
+    Ok(builder.build())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let header = build_header()?;
+    let infos = header.infos();
+    let raw_keys: Vec<String> = infos.keys().map(|key| key.as_ref().into()).collect();
+
+    let start = Instant::now();
+    let hit_count = raw_keys
+        .iter()
+        .filter(|raw_key| infos.keys().any(|key| key.as_ref() == raw_key.as_str()))
+        .count();
+    let linear_search_elapsed = start.elapsed();
+    assert_eq!(hit_count, KEY_COUNT);
+
+    let start = Instant::now();
+    let hit_count = raw_keys
+        .iter()
+        .filter(|raw_key| header::lookup(infos, raw_key).is_some())
+        .count();
+    let lookup_elapsed = start.elapsed();
+    assert_eq!(hit_count, KEY_COUNT);
+
+    println!(
+        "{KEY_COUNT} keys: linear search = {linear_search_elapsed:?}, \
+         lookup = {lookup_elapsed:?}"
+    );
+
+    Ok(())
+}
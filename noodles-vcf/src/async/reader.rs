@@ -49,6 +49,7 @@ const CARRIAGE_RETURN: char = '\r';
 pub struct Reader<R> {
     inner: R,
     buf: String,
+    allow_iupac_reference_bases: bool,
 }
 
 impl<R> Reader<R>
@@ -68,9 +69,26 @@ where
         Self {
             inner,
             buf: String::new(),
+            allow_iupac_reference_bases: false,
         }
     }
 
+    /// Sets whether to allow IUPAC ambiguity codes in reference bases.
+    ///
+    /// See [`crate::reader::Builder::set_allow_iupac_reference_bases`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let data = [];
+    /// let mut reader = vcf::AsyncReader::new(&data[..]);
+    /// reader.set_allow_iupac_reference_bases(true);
+    /// ```
+    pub fn set_allow_iupac_reference_bases(&mut self, allow_iupac_reference_bases: bool) {
+        self.allow_iupac_reference_bases = allow_iupac_reference_bases;
+    }
+
     /// Reads the VCF header.
     ///
     /// This reads all header lines prefixed with a `#` (number sign), which includes the header
@@ -137,14 +155,17 @@ where
     /// # }
     /// ```
     pub async fn read_record(&mut self, header: &Header, record: &mut Record) -> io::Result<usize> {
-        use crate::reader::parse_record;
+        use crate::reader::{parse_record_with_options, record::ParseOptions};
 
         self.buf.clear();
 
         match read_line(&mut self.inner, &mut self.buf).await? {
             0 => Ok(0),
             n => {
-                parse_record(&self.buf, header, record)
+                let options = ParseOptions::default()
+                    .set_allow_iupac_reference_bases(self.allow_iupac_reference_bases);
+
+                parse_record_with_options(&self.buf, header, record, options)
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
                 Ok(n)
@@ -351,4 +372,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_record_with_allow_iupac_reference_bases() -> io::Result<()> {
+        use crate::record::reference_bases::Base;
+
+        let data = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tAR\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header().await?;
+        let mut record = Record::default();
+        assert!(reader.read_record(&header, &mut record).await.is_err());
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_allow_iupac_reference_bases(true);
+        reader.read_header().await?;
+        reader.read_record(&header, &mut record).await?;
+        assert_eq!(&record.reference_bases()[..], [Base::A, Base::N]);
+
+        Ok(())
+    }
 }
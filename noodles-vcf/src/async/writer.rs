@@ -2,8 +2,6 @@ use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
 use crate::{Header, Record};
 
-const LINE_FEED: u8 = b'\n';
-
 /// An async VCF writer.
 pub struct Writer<W>
 where
@@ -30,6 +28,9 @@ where
 
     /// Writes a VCF header.
     ///
+    /// This fails with an [`io::ErrorKind::InvalidInput`] error if a sample name contains a tab or
+    /// newline.
+    ///
     /// # Examples
     ///
     /// ```
@@ -47,12 +48,19 @@ where
     /// # }
     /// ```
     pub async fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        use crate::writer::validate_sample_names;
+
+        validate_sample_names(header)?;
+
         let raw_header = header.to_string();
         self.inner.write_all(raw_header.as_bytes()).await
     }
 
     /// Writes a VCF record.
     ///
+    /// The record is formatted the same way as [`crate::Writer::write_record`]. Unlike the sync
+    /// writer, the INFO field is not validated against a header.
+    ///
     /// # Examples
     ///
     /// ```
@@ -72,9 +80,65 @@ where
     /// # }
     /// ```
     pub async fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        let raw_record = record.to_string();
-        self.inner.write_all(raw_record.as_bytes()).await?;
-        self.inner.write_u8(LINE_FEED).await?;
+        use crate::writer::write_unvalidated_record;
+
+        let mut buf = Vec::new();
+        write_unvalidated_record(&mut buf, record)?;
+        self.inner.write_all(&buf).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Position;
+
+        let header = Header::default();
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_quality_score(1.0.try_into()?)
+            .set_info("AF=0.5,1e-10".parse()?)
+            .build()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&record).await?;
+
+        let mut expected = crate::Writer::new(Vec::new());
+        expected.write_record(&header, &record)?;
+
+        assert_eq!(writer.inner, expected.get_ref()[..]);
+        assert_eq!(writer.inner, b"sq0\t1\t.\tA\t.\t1\t.\tAF=0.5,1e-10\n");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_record_with_reserved_characters_in_info_string(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::{info::field::Value, Info, Position};
+
+        let key: crate::record::info::field::Key = "STR".parse()?;
+        let info: Info = [(key, Some(Value::from("ñ;a=b,c")))].into_iter().collect();
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_info(info)
+            .build()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&record).await?;
+
+        let expected = "sq0\t1\t.\tA\t.\t.\t.\tSTR=ñ%3Ba%3Db%2Cc\n";
+        assert_eq!(writer.inner, expected.as_bytes());
+
         Ok(())
     }
 }
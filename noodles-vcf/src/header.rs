@@ -3,13 +3,15 @@
 mod builder;
 pub mod file_format;
 mod fmt;
+mod lookup;
 mod number;
 pub mod parser;
+pub mod pedigree;
 pub mod record;
 
 pub use self::{
-    builder::Builder, file_format::FileFormat, number::Number, parser::ParseError, parser::Parser,
-    record::Record,
+    builder::Builder, file_format::FileFormat, lookup::lookup, number::Number, parser::ParseError,
+    parser::Parser, pedigree::Pedigree, record::Record,
 };
 
 use std::{hash::Hash, str::FromStr};
@@ -44,7 +46,7 @@ pub type SampleNames = IndexSet<String>;
 pub type OtherRecords = IndexMap<record::key::Other, record::value::Collection>;
 
 /// A VCF header.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq)]
 pub struct Header {
     file_format: FileFormat,
     infos: Infos,
@@ -57,6 +59,8 @@ pub struct Header {
     pedigree_db: Option<String>,
     sample_names: SampleNames,
     other_records: OtherRecords,
+    // Whether `filters` has a `PASS` filter added by the builder. This only affects formatting.
+    has_implicit_pass_filter: bool,
 }
 
 impl Header {
@@ -176,8 +180,8 @@ impl Header {
     ///     .build();
     ///
     /// let filters = header.filters();
-    /// assert_eq!(filters.len(), 1);
-    /// assert_eq!(&filters[0], &filter);
+    /// assert_eq!(filters.len(), 2); // PASS, q10
+    /// assert_eq!(filters.get("q10"), Some(&filter));
     /// ```
     pub fn filters(&self) -> &Filters {
         &self.filters
@@ -196,10 +200,12 @@ impl Header {
     /// header.filters_mut().insert(String::from("q10"), filter.clone());
     ///
     /// let filters = header.filters();
-    /// assert_eq!(filters.len(), 1);
-    /// assert_eq!(&filters[0], &filter);
+    /// assert_eq!(filters.len(), 2); // PASS, q10
+    /// assert_eq!(filters.get("q10"), Some(&filter));
     /// ```
     pub fn filters_mut(&mut self) -> &mut Filters {
+        // Once edited, a `PASS` filter is no longer considered implicit and is written.
+        self.has_implicit_pass_filter = false;
         &mut self.filters
     }
 
@@ -626,6 +632,22 @@ impl Header {
     }
 }
 
+impl PartialEq for Header {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_format == other.file_format
+            && self.infos == other.infos
+            && self.filters == other.filters
+            && self.formats == other.formats
+            && self.alternative_alleles == other.alternative_alleles
+            && self.assembly == other.assembly
+            && self.contigs == other.contigs
+            && self.meta == other.meta
+            && self.pedigree_db == other.pedigree_db
+            && self.sample_names == other.sample_names
+            && self.other_records == other.other_records
+    }
+}
+
 impl Default for Header {
     fn default() -> Self {
         Builder::default().build()
@@ -654,6 +676,11 @@ impl std::fmt::Display for Header {
         }
 
         for (id, filter) in self.filters() {
+            // An implicit `PASS` filter is not written, as it was not in the original header.
+            if self.has_implicit_pass_filter && id == "PASS" && filter == &Map::<Filter>::pass() {
+                continue;
+            }
+
             writeln!(
                 f,
                 "{}{}=<ID={}{}>",
@@ -825,6 +852,77 @@ mod tests {
         assert_eq!(header.to_string(), expected);
     }
 
+    #[test]
+    fn test_filters_with_implicit_pass() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            record::{Filters, Position},
+            Record,
+        };
+
+        let header: Header = "##fileformat=VCFv4.3
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        let expected = [
+            (String::from("PASS"), Map::<Filter>::pass()),
+            (String::from("q10"), Map::<Filter>::new("Quality below 10")),
+        ]
+        .into_iter()
+        .collect::<super::Filters>();
+
+        assert_eq!(header.filters(), &expected);
+
+        let actual = Record::try_from((&header, "sq0\t1\t.\tA\t.\t.\tPASS\t."))?;
+
+        let expected = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_filters(Filters::Pass)
+            .build()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_with_implicit_pass_filter() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder().build();
+
+        let parsed_header: Header = "##fileformat=VCFv4.4
+##FILTER=<ID=PASS,Description=\"All filters passed\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        assert_eq!(parsed_header.filters(), header.filters());
+        assert_eq!(parsed_header, header);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_with_edited_implicit_pass_filter() {
+        let mut header = Header::builder().build();
+
+        let pass_filter = header.filters_mut().shift_remove("PASS");
+        assert_eq!(pass_filter, Some(Map::<Filter>::pass()));
+
+        header
+            .filters_mut()
+            .insert(String::from("PASS"), Map::<Filter>::pass());
+
+        let expected = r#"##fileformat=VCFv4.4
+##FILTER=<ID=PASS,Description="All filters passed">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(header.to_string(), expected);
+    }
+
     #[test]
     fn test_insert_with_duplicate_keys() -> Result<(), Box<dyn std::error::Error>> {
         let key: record::key::Other = "noodles".parse()?;
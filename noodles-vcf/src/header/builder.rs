@@ -88,8 +88,8 @@ impl Builder {
     ///     .build();
     ///
     /// let filters = header.filters();
-    /// assert_eq!(filters.len(), 1);
-    /// assert_eq!(&filters[0], &filter);
+    /// assert_eq!(filters.len(), 2); // PASS, q10
+    /// assert_eq!(filters.get("q10"), Some(&filter));
     /// ```
     pub fn add_filter<I>(mut self, id: I, filter: Map<Filter>) -> Self
     where
@@ -360,10 +360,12 @@ impl Builder {
     /// let header = vcf::Header::builder().build();
     /// ```
     pub fn build(self) -> Header {
+        let (filters, has_implicit_pass_filter) = insert_pass_filter(self.filters);
+
         Header {
             file_format: self.file_format,
             infos: self.infos,
-            filters: self.filters,
+            filters,
             formats: self.formats,
             alternative_alleles: self.alternative_alleles,
             assembly: self.assembly,
@@ -372,10 +374,29 @@ impl Builder {
             pedigree_db: self.pedigree_db,
             sample_names: self.sample_names,
             other_records: self.other_records,
+            has_implicit_pass_filter,
         }
     }
 }
 
+// § 1.6.1 Fixed fields (2021-01-13): "PASS if this position has passed all filters, i.e., a call
+// is made at this position."
+//
+// "PASS" is implicitly defined and does not require a `FILTER` record. It is added as the first
+// filter if not explicitly given, which is returned as `true`.
+fn insert_pass_filter(filters: Filters) -> (Filters, bool) {
+    const PASS: &str = "PASS";
+
+    if filters.contains_key(PASS) {
+        return (filters, false);
+    }
+
+    let mut pass_filters = Filters::with_capacity(filters.len() + 1);
+    pass_filters.insert(String::from(PASS), Map::<Filter>::pass());
+    pass_filters.extend(filters);
+    (pass_filters, true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,7 +407,8 @@ mod tests {
 
         assert_eq!(header.file_format(), FileFormat::default());
         assert!(header.infos().is_empty());
-        assert!(header.filters().is_empty());
+        assert_eq!(header.filters().len(), 1);
+        assert!(header.filters().contains_key("PASS"));
         assert!(header.formats().is_empty());
         assert!(header.alternative_alleles().is_empty());
         assert!(header.assembly().is_none());
@@ -441,7 +463,7 @@ mod tests {
 
         assert_eq!(header.file_format(), FileFormat::new(4, 3));
         assert_eq!(header.infos().len(), 1);
-        assert_eq!(header.filters().len(), 1);
+        assert_eq!(header.filters().len(), 2);
         assert_eq!(header.formats().len(), 1);
         assert_eq!(header.alternative_alleles().len(), 1);
         assert_eq!(header.assembly(), Some("file:///assemblies.fasta"));
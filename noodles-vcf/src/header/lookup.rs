@@ -0,0 +1,128 @@
+use std::{hash::Hash, str::FromStr};
+
+use indexmap::IndexMap;
+
+/// Returns the header record with the given raw key.
+///
+/// This is used to find INFO ([`super::Infos`]) and FORMAT ([`super::Formats`]) records by the
+/// key as it appears in a record. The raw key is parsed and looked up by hash. If that misses,
+/// e.g., a standard key was built as an `Other` key, the header keys are compared with the raw key.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{self, record::value::{map::Info, Map}},
+///     record::info::field::key,
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+///     .build();
+///
+/// let (key, _) = header::lookup(header.infos(), "DP").expect("missing key");
+/// assert_eq!(key, &key::TOTAL_DEPTH);
+///
+/// assert!(header::lookup(header.infos(), "NS").is_none());
+/// ```
+pub fn lookup<'h, K, V>(records: &'h IndexMap<K, V>, raw_key: &str) -> Option<(&'h K, &'h V)>
+where
+    K: AsRef<str> + Eq + FromStr + Hash,
+{
+    raw_key
+        .parse::<K>()
+        .ok()
+        .and_then(|key| records.get_key_value(&key))
+        .or_else(|| records.iter().find(|(k, _)| k.as_ref() == raw_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::{
+            record::value::{
+                map::{info::Type, Format, Info},
+                Map,
+            },
+            Formats, Infos, Number,
+        },
+        record::{genotypes, info},
+    };
+
+    const KEY_COUNT: usize = 4096;
+
+    fn build_infos() -> Result<Infos, Box<dyn std::error::Error>> {
+        let mut infos: Infos = (0..KEY_COUNT)
+            .map(|i| {
+                let key: info::field::Key = format!("K{i}").parse()?;
+                let map = Map::<Info>::from(&key);
+                Ok((key, map))
+            })
+            .collect::<Result<_, info::field::key::ParseError>>()?;
+
+        let key = info::field::key::TOTAL_DEPTH;
+        infos.insert(key.clone(), Map::<Info>::from(&key));
+
+        Ok(infos)
+    }
+
+    fn build_formats() -> Result<Formats, Box<dyn std::error::Error>> {
+        let mut formats: Formats = (0..KEY_COUNT)
+            .map(|i| {
+                let key: genotypes::keys::Key = format!("K{i}").parse()?;
+                let map = Map::<Format>::from(&key);
+                Ok((key, map))
+            })
+            .collect::<Result<_, genotypes::keys::key::ParseError>>()?;
+
+        let key = genotypes::keys::key::GENOTYPE;
+        formats.insert(key.clone(), Map::<Format>::from(&key));
+
+        Ok(formats)
+    }
+
+    fn linear_search<'h, K, V>(records: &'h IndexMap<K, V>, raw_key: &str) -> Option<(&'h K, &'h V)>
+    where
+        K: AsRef<str>,
+    {
+        records.iter().find(|(k, _)| k.as_ref() == raw_key)
+    }
+
+    #[test]
+    fn test_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        fn t<K, V>(records: &IndexMap<K, V>, misses: &[&str])
+        where
+            K: AsRef<str> + Eq + FromStr + Hash + std::fmt::Debug,
+            V: PartialEq + std::fmt::Debug,
+        {
+            for key in records.keys() {
+                let raw_key = key.as_ref();
+                assert_eq!(lookup(records, raw_key), linear_search(records, raw_key));
+            }
+
+            for raw_key in misses {
+                assert!(lookup(records, raw_key).is_none());
+            }
+        }
+
+        let mut infos = build_infos()?;
+        t(&infos, &["K4096", "NS", ""]);
+
+        let key = info::field::Key::Other("AC".parse()?);
+        infos.insert(key, Map::<Info>::new(Number::Count(1), Type::Integer, ""));
+        assert_eq!(lookup(&infos, "AC"), linear_search(&infos, "AC"));
+        assert!(lookup(&infos, "AC").is_some());
+
+        let mut formats = build_formats()?;
+        t(&formats, &["K4096", "GQ", ""]);
+
+        let key = genotypes::keys::Key::Other("DP".parse()?);
+        formats.insert(key.clone(), Map::<Format>::from(&key));
+        assert_eq!(lookup(&formats, "DP"), linear_search(&formats, "DP"));
+        assert!(lookup(&formats, "DP").is_some());
+
+        Ok(())
+    }
+}
@@ -234,7 +234,7 @@ mod tests {
 
         assert_eq!(header.file_format(), FileFormat::new(4, 3));
         assert_eq!(header.infos().len(), 1);
-        assert_eq!(header.filters().len(), 1);
+        assert_eq!(header.filters().len(), 2);
         assert_eq!(header.formats().len(), 1);
         assert_eq!(header.alternative_alleles().len(), 1);
         assert_eq!(header.assembly(), Some("file:///assemblies.fasta"));
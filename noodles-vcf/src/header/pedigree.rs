@@ -0,0 +1,242 @@
+//! VCF header pedigree.
+
+use indexmap::{IndexMap, IndexSet};
+
+use super::{record::value::Collection, Header};
+
+const KEY: &str = "PEDIGREE";
+const FATHER: &str = "Father";
+const MOTHER: &str = "Mother";
+
+/// The parents of a sample.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Parents {
+    father: Option<String>,
+    mother: Option<String>,
+}
+
+impl Parents {
+    /// Returns the father sample name.
+    pub fn father(&self) -> Option<&str> {
+        self.father.as_deref()
+    }
+
+    /// Returns the mother sample name.
+    pub fn mother(&self) -> Option<&str> {
+        self.mother.as_deref()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &str> {
+        self.father().into_iter().chain(self.mother())
+    }
+}
+
+/// A VCF header pedigree.
+///
+/// This is a parent-child graph of samples built from the structured `PEDIGREE` records of a
+/// header, e.g., `##PEDIGREE=<ID=child,Father=father,Mother=mother>`. The `ID` is the child sample
+/// name, and `Father` and `Mother` are its parents. Both parents are optional. Other fields, e.g.,
+/// the `Original` of a derived genome, are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, header::Pedigree};
+///
+/// let header: vcf::Header = "##fileformat=VCFv4.3
+/// ###PEDIGREE=<ID=child,Father=father,Mother=mother>
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tchild\tfather\tmother
+/// ".parse()?;
+///
+/// let pedigree = Pedigree::from(&header);
+/// assert_eq!(pedigree.trios().collect::<Vec<_>>(), [("child", "father", "mother")]);
+/// # Ok::<_, vcf::header::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Pedigree(IndexMap<String, Parents>);
+
+impl Pedigree {
+    /// Returns the number of samples with at least one parent.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether there are any samples with parents.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the parents of the given sample.
+    pub fn parents(&self, sample_name: &str) -> Option<&Parents> {
+        self.0.get(sample_name)
+    }
+
+    /// Returns an iterator over the children of the given sample.
+    pub fn children<'a>(&'a self, sample_name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.0
+            .iter()
+            .filter(move |(_, parents)| parents.iter().any(|name| name == sample_name))
+            .map(|(child, _)| child.as_str())
+    }
+
+    /// Returns the ancestors of the given sample.
+    ///
+    /// Ancestors are ordered by generation, i.e., parents first, then grandparents, and so on.
+    /// Each ancestor is listed once, even if it is reachable through multiple lines.
+    pub fn ancestors(&self, sample_name: &str) -> Vec<&str> {
+        let mut ancestors = IndexSet::new();
+        let mut i = 0;
+
+        if let Some(parents) = self.parents(sample_name) {
+            ancestors.extend(parents.iter());
+        }
+
+        while let Some(&name) = ancestors.get_index(i) {
+            if let Some(parents) = self.parents(name) {
+                ancestors.extend(parents.iter().filter(|&parent| parent != sample_name));
+            }
+
+            i += 1;
+        }
+
+        ancestors.into_iter().collect()
+    }
+
+    /// Returns an iterator over all complete trios.
+    ///
+    /// Each item is a tuple of the child, father, and mother sample names. Samples with only one
+    /// known parent are not included.
+    pub fn trios(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+        self.0.iter().filter_map(
+            |(child, parents)| match (parents.father(), parents.mother()) {
+                (Some(father), Some(mother)) => Some((child.as_str(), father, mother)),
+                _ => None,
+            },
+        )
+    }
+}
+
+impl From<&Header> for Pedigree {
+    fn from(header: &Header) -> Self {
+        let mut pedigree = IndexMap::new();
+
+        if let Some(Collection::Structured(records)) = header.get(KEY) {
+            for (id, map) in records {
+                let fields = map.other_fields();
+
+                let parents = Parents {
+                    father: fields.get(FATHER).cloned(),
+                    mother: fields.get(MOTHER).cloned(),
+                };
+
+                if parents.father.is_some() || parents.mother.is_some() {
+                    pedigree.insert(id.clone(), parents);
+                }
+            }
+        }
+
+        Self(pedigree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_header() -> Result<Header, crate::header::ParseError> {
+        "\
+##fileformat=VCFv4.3
+##PEDIGREE=<ID=child,Father=father,Mother=mother>
+##PEDIGREE=<ID=father,Father=grandfather,Mother=grandmother>
+##PEDIGREE=<ID=sibling,Father=father,Mother=mother>
+##PEDIGREE=<ID=half_sibling,Father=father>
+##PEDIGREE=<ID=tumor,Original=child>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tchild\tfather\tmother
+"
+        .parse()
+    }
+
+    #[test]
+    fn test_from_header() -> Result<(), crate::header::ParseError> {
+        let pedigree = Pedigree::from(&build_header()?);
+        assert_eq!(pedigree.len(), 4);
+
+        let parents = pedigree.parents("child");
+        assert_eq!(parents.and_then(|p| p.father()), Some("father"));
+        assert_eq!(parents.and_then(|p| p.mother()), Some("mother"));
+
+        let parents = pedigree.parents("half_sibling");
+        assert_eq!(parents.and_then(|p| p.father()), Some("father"));
+        assert!(parents.and_then(|p| p.mother()).is_none());
+
+        assert!(pedigree.parents("tumor").is_none());
+        assert!(pedigree.parents("mother").is_none());
+
+        assert!(Pedigree::from(&Header::default()).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_children() -> Result<(), crate::header::ParseError> {
+        let pedigree = Pedigree::from(&build_header()?);
+
+        assert_eq!(
+            pedigree.children("father").collect::<Vec<_>>(),
+            ["child", "sibling", "half_sibling"]
+        );
+        assert_eq!(
+            pedigree.children("mother").collect::<Vec<_>>(),
+            ["child", "sibling"]
+        );
+        assert!(pedigree.children("child").next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestors() -> Result<(), crate::header::ParseError> {
+        let pedigree = Pedigree::from(&build_header()?);
+
+        assert_eq!(
+            pedigree.ancestors("child"),
+            ["father", "mother", "grandfather", "grandmother"]
+        );
+        assert_eq!(pedigree.ancestors("father"), ["grandfather", "grandmother"]);
+        assert!(pedigree.ancestors("mother").is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestors_with_cycle() -> Result<(), crate::header::ParseError> {
+        let header = "\
+##fileformat=VCFv4.3
+##PEDIGREE=<ID=s0,Father=s1>
+##PEDIGREE=<ID=s1,Father=s0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        let pedigree = Pedigree::from(&header);
+        assert_eq!(pedigree.ancestors("s0"), ["s1"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trios() -> Result<(), crate::header::ParseError> {
+        let pedigree = Pedigree::from(&build_header()?);
+
+        assert_eq!(
+            pedigree.trios().collect::<Vec<_>>(),
+            [
+                ("child", "father", "mother"),
+                ("father", "grandfather", "grandmother"),
+                ("sibling", "father", "mother"),
+            ]
+        );
+
+        Ok(())
+    }
+}
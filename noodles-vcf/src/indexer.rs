@@ -0,0 +1,146 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+use noodles_tabix as tabix;
+
+use super::{Reader, Record};
+
+/// Indexes a bgzipped VCF file.
+///
+/// The end position of each record is calculated using [`Record::end`], i.e., the `END` INFO
+/// field value is used when present. This allows records with symbolic alternate alleles, e.g.,
+/// structural variants, to be found by querying any position in their span.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_vcf as vcf;
+/// let index = vcf::index("sample.vcf.gz")?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index<P>(src: P) -> io::Result<csi::Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(bgzf::Reader::new).map(Reader::new)?;
+
+    index_inner(&mut reader)
+}
+
+fn index_inner<R>(reader: &mut Reader<bgzf::Reader<R>>) -> io::Result<csi::Index>
+where
+    R: Read,
+{
+    let header = reader.read_header()?;
+
+    let mut record = Record::default();
+
+    let mut indexer = tabix::index::Indexer::default();
+    indexer.set_header(csi::index::header::Builder::vcf().build());
+
+    let mut start_position = reader.virtual_position();
+
+    while reader.read_record(&header, &mut record)? != 0 {
+        let end_position = reader.virtual_position();
+        let chunk = Chunk::new(start_position, end_position);
+
+        let reference_sequence_name = record.chromosome().to_string();
+
+        let start = Position::try_from(usize::from(record.position()))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let end = record
+            .end()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            .and_then(|position| {
+                Position::try_from(usize::from(position))
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })?;
+
+        indexer.add_record(&reference_sequence_name, start, end, chunk)?;
+
+        start_position = end_position;
+    }
+
+    Ok(indexer.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{
+        header::record::value::{
+            map::{Contig, Info},
+            Map,
+        },
+        record::{self, info::field::key},
+        Header, Writer,
+    };
+
+    #[test]
+    fn test_index() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_info(key::END_POSITION, Map::<Info>::from(&key::END_POSITION))
+            .build();
+
+        let build_record = |position, end| {
+            record::Builder::default()
+                .set_chromosome("sq0".parse()?)
+                .set_position(record::Position::from(position))
+                .set_reference_bases("N".parse()?)
+                .set_alternate_bases("<DEL>".parse()?)
+                .set_info(format!("END={end}").parse()?)
+                .build()
+                .map_err(Box::<dyn std::error::Error>::from)
+        };
+
+        let records = [
+            build_record(1000, 2000)?,
+            build_record(8000, 40000)?,
+            build_record(60000, 60100)?,
+        ];
+
+        let mut writer = Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_record(&header, record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(&data[..]));
+        let index = index_inner(&mut reader)?;
+
+        let t = |raw_region: &str, expected: &[Record]| -> Result<(), Box<dyn std::error::Error>> {
+            let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(&data)));
+            reader.read_header()?;
+
+            let region = raw_region.parse()?;
+            let actual: Vec<_> = reader
+                .query(&header, &index, &region)?
+                .collect::<io::Result<_>>()?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        };
+
+        t("sq0:1500-1500", &records[..1])?;
+        t("sq0:35000-35000", &records[1..2])?;
+        t("sq0:60050-60050", &records[2..])?;
+        t("sq0:50000-50000", &[])?;
+
+        Ok(())
+    }
+}
@@ -1,21 +1,32 @@
 //! VCF reader and iterators.
 
+mod assert_sorted;
 mod builder;
+mod error_action;
 mod header;
 pub(crate) mod query;
 pub mod record;
 mod records;
 
-pub(crate) use self::record::parse_record;
-pub use self::{builder::Builder, query::Query, records::Records};
+pub(crate) use self::record::{parse_record, parse_record_with_options};
+pub use self::{
+    assert_sorted::{assert_sorted, AssertSorted},
+    builder::Builder,
+    error_action::ErrorAction,
+    query::Query,
+    records::Records,
+};
 
-use std::io::{self, BufRead, Read, Seek};
+use std::{
+    fmt,
+    io::{self, BufRead, Read, Seek},
+};
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi as csi;
 
-use self::header::read_header;
+use self::{header::read_header, record::ParseOptions};
 use super::{Header, Record, VariantReader};
 
 /// A VCF reader.
@@ -41,12 +52,17 @@ use super::{Header, Record, VariantReader};
 /// }
 /// # Ok::<_, std::io::Error>(())
 /// ```
-#[derive(Debug)]
 pub struct Reader<R> {
     inner: R,
     buf: String,
+    validate_contig_bounds: bool,
+    allow_iupac_reference_bases: bool,
+    error_policy: Option<Box<ErrorPolicy>>,
+    scratch_record: Record,
 }
 
+type ErrorPolicy = dyn FnMut(&str, &io::Error) -> ErrorAction + Send;
+
 impl<R> Reader<R>
 where
     R: BufRead,
@@ -69,6 +85,10 @@ where
         Self {
             inner,
             buf: String::new(),
+            validate_contig_bounds: false,
+            allow_iupac_reference_bases: false,
+            error_policy: None,
+            scratch_record: Record::default(),
         }
     }
 
@@ -114,6 +134,41 @@ where
         self.inner
     }
 
+    /// Sets an error policy for records that fail to be read.
+    ///
+    /// The error policy is called with the raw line and the error when a record fails to parse or
+    /// validate. It returns whether to skip the line ([`ErrorAction::Skip`]) and continue reading
+    /// the next record or to return the error ([`ErrorAction::Abort`]).
+    ///
+    /// By default, all errors are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, reader::ErrorAction};
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// sq0\tndls\t.\tA\t.\t.\tPASS\t.
+    /// sq0\t3\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// reader.set_error_policy(|_, _| ErrorAction::Skip);
+    ///
+    /// let header = reader.read_header()?;
+    /// let records: Vec<_> = reader.records(&header).collect::<Result<_, _>>()?;
+    /// assert_eq!(records.len(), 2);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn set_error_policy<F>(&mut self, error_policy: F)
+    where
+        F: FnMut(&str, &io::Error) -> ErrorAction + Send + 'static,
+    {
+        self.error_policy = Some(Box::new(error_policy));
+    }
+
     /// Reads the VCF header.
     ///
     /// This reads all header lines prefixed with a `#` (number sign), which includes the header
@@ -150,8 +205,9 @@ where
     /// It is more ergonomic to read records using an iterator (see [`Self::records`]), but using
     /// this method allows control of the record buffer.
     ///
-    /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
-    /// stream reached EOF.
+    /// If successful, the number of bytes read is returned, which includes any lines skipped by the
+    /// error policy (see [`Self::set_error_policy`]). If the number of bytes read is 0, the stream
+    /// reached EOF, and the given record is left unmodified.
     ///
     /// # Examples
     ///
@@ -171,15 +227,47 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn read_record(&mut self, header: &Header, record: &mut Record) -> io::Result<usize> {
-        self.buf.clear();
+        let options = ParseOptions::default()
+            .set_allow_iupac_reference_bases(self.allow_iupac_reference_bases);
+
+        let mut len = 0;
 
-        match read_line(&mut self.inner, &mut self.buf)? {
-            0 => Ok(0),
-            n => {
-                parse_record(&self.buf, header, record)
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        loop {
+            self.buf.clear();
 
-                Ok(n)
+            match read_line(&mut self.inner, &mut self.buf)? {
+                0 => return Ok(0),
+                n => len += n,
+            }
+
+            let result = match self.error_policy {
+                // A skipped line must not leave the given record partially overwritten.
+                Some(_) => parse_buf(
+                    &self.buf,
+                    header,
+                    &mut self.scratch_record,
+                    options,
+                    self.validate_contig_bounds,
+                )
+                .map(|_| std::mem::swap(record, &mut self.scratch_record)),
+                None => parse_buf(
+                    &self.buf,
+                    header,
+                    record,
+                    options,
+                    self.validate_contig_bounds,
+                ),
+            };
+
+            match result {
+                Ok(()) => return Ok(len),
+                Err(e) => match self.error_policy.as_mut() {
+                    Some(error_policy) => match error_policy(&self.buf, &e) {
+                        ErrorAction::Skip => {}
+                        ErrorAction::Abort => return Err(e),
+                    },
+                    None => return Err(e),
+                },
             }
         }
     }
@@ -211,6 +299,24 @@ where
     }
 }
 
+impl<R> fmt::Debug for Reader<R>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reader")
+            .field("inner", &self.inner)
+            .field("buf", &self.buf)
+            .field("validate_contig_bounds", &self.validate_contig_bounds)
+            .field(
+                "allow_iupac_reference_bases",
+                &self.allow_iupac_reference_bases,
+            )
+            .field("error_policy", &self.error_policy.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
 impl<R> Reader<bgzf::Reader<R>>
 where
     R: Read,
@@ -325,6 +431,23 @@ where
     }
 }
 
+fn parse_buf(
+    buf: &str,
+    header: &Header,
+    record: &mut Record,
+    options: ParseOptions,
+    should_validate_contig_bounds: bool,
+) -> io::Result<()> {
+    parse_record_with_options(buf, header, record, options)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if should_validate_contig_bounds {
+        validate_contig_bounds(header, record)?;
+    }
+
+    Ok(())
+}
+
 // Reads all bytes until a line feed ('\n') or EOF is reached.
 //
 // The buffer will not include the trailing newline ('\n' or '\r\n').
@@ -353,25 +476,59 @@ where
 }
 
 pub(crate) fn resolve_region(index: &csi::Index, region: &Region) -> io::Result<(usize, String)> {
-    let header = index
-        .header()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing tabix header"))?;
-
-    let i = header
-        .reference_sequence_names()
-        .get_index_of(region.name())
-        .ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!(
-                    "region reference sequence does not exist in reference sequences: {region:?}"
-                ),
-            )
-        })?;
+    if index.header().is_none() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "missing tabix header",
+        ));
+    }
+
+    let i = index.name_to_id(region.name()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("region reference sequence does not exist in reference sequences: {region:?}"),
+        )
+    })?;
 
     Ok((i, region.name().into()))
 }
 
+fn validate_contig_bounds(header: &Header, record: &Record) -> io::Result<()> {
+    use crate::record::Chromosome;
+
+    let name = match record.chromosome() {
+        Chromosome::Name(name) => name,
+        Chromosome::Symbol(_) => return Ok(()),
+    };
+
+    let length = match header
+        .contigs()
+        .get(name.as_str())
+        .and_then(|contig| contig.length())
+    {
+        Some(length) => length,
+        None => return Ok(()),
+    };
+
+    let start = usize::from(record.position());
+
+    let end = record
+        .end()
+        .map(usize::from)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let max_position = start.max(end);
+
+    if max_position > length {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("record position ({max_position}) exceeds contig length ({name}: {length})"),
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -399,6 +556,313 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_at_eof_does_not_modify_record() -> Result<(), Box<dyn std::error::Error>> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t8\tid0\tA\tG\t13\tPASS\tNS=1\tGT\t0|1
+";
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+
+        let mut record = Record::default();
+        assert!(record.is_default());
+
+        reader.read_record(&header, &mut record)?;
+        assert!(!record.is_default());
+        let expected = record.clone();
+
+        let bytes_read = reader.read_record(&header, &mut record)?;
+        assert_eq!(bytes_read, 0);
+        assert_eq!(record, expected);
+
+        let mut record = Record::default();
+        let bytes_read = reader.read_record(&header, &mut record)?;
+        assert_eq!(bytes_read, 0);
+        assert!(record.is_default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_reused_record() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##INFO=<ID=DB,Number=0,Type=Flag,Description=\"dbSNP membership\">
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##FILTER=<ID=s50,Description=\"Less than 50% of samples have data\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Genotype quality\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+sq0\t1\tid0;id1\tACGT\tA,C\t13\tq10;s50\tNS=2;DB\tGT:GQ\t0|1:8\t1/1:21
+sq0\t3\t.\tG\t.\t.\t.\t.\tGT\t0/0\t.
+<sq1>\t5\tid2\tN\t<DEL>\t5\tPASS\tDB\tGQ\t.\t34
+sq10\t8\t.\tT\tC\t.\tq10\tNS=1\tGT:GQ\t.:.\t0|1
+sq10\t13\t.\tA\t.\t.\t.\t.\tGT\t./.\t./.
+";
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+
+        let lines: Vec<_> = DATA
+            .split(|&b| b == b'\n')
+            .filter(|line| !line.is_empty() && !line.starts_with(b"#"))
+            .collect();
+
+        let mut record = Record::default();
+
+        for line in lines {
+            let bytes_read = reader.read_record(&header, &mut record)?;
+            assert_eq!(bytes_read, line.len() + 1);
+
+            let s = std::str::from_utf8(line)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let mut expected = Record::default();
+            parse_record(s, &header, &mut expected)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            assert_eq!(record, expected);
+        }
+
+        assert_eq!(reader.read_record(&header, &mut record)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_error_policy() -> io::Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\tndls\t.\tA\t.\t.\tPASS\t.
+sq0\t3\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+        assert!(reader.records(&header).any(|result| result.is_err()));
+
+        let skipped_lines = Arc::new(Mutex::new(Vec::new()));
+
+        let mut reader = Reader::new(DATA);
+
+        {
+            let skipped_lines = Arc::clone(&skipped_lines);
+
+            reader.set_error_policy(move |line, e| {
+                assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                skipped_lines.lock().unwrap().push(line.to_string());
+                ErrorAction::Skip
+            });
+        }
+
+        let header = reader.read_header()?;
+        let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(usize::from(records[0].position()), 1);
+        assert_eq!(usize::from(records[1].position()), 3);
+        assert_eq!(
+            *skipped_lines.lock().unwrap(),
+            ["sq0\tndls\t.\tA\t.\t.\tPASS\t."]
+        );
+
+        let mut reader = Reader::new(DATA);
+        reader.set_error_policy(|_, _| ErrorAction::Abort);
+        let header = reader.read_header()?;
+        let mut record = Record::default();
+        reader.read_record(&header, &mut record)?;
+
+        assert!(matches!(
+            reader.read_record(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = Reader::new(DATA);
+        reader.set_error_policy(|_, _| ErrorAction::Skip);
+        let header = reader.read_header()?;
+        let mut record = Record::default();
+        assert_eq!(reader.read_record(&header, &mut record)?, 21);
+        // The skipped line is included in the number of bytes read.
+        assert_eq!(reader.read_record(&header, &mut record)?, 45);
+        assert_eq!(usize::from(record.position()), 3);
+
+        // `Cell` is `Send` but not `Sync`.
+        let skip_count = std::cell::Cell::new(0);
+
+        let mut reader = Reader::new(DATA);
+        reader.set_error_policy(move |_, _| {
+            skip_count.set(skip_count.get() + 1);
+            ErrorAction::Skip
+        });
+        let header = reader.read_header()?;
+        let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_error_policy_and_skipped_last_line() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\tid0\tA\t.\t.\tPASS\t.
+sq1\t3\tid1\tC\tndls\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(DATA);
+        reader.set_error_policy(|_, _| ErrorAction::Skip);
+        let header = reader.read_header()?;
+
+        let mut record = Record::default();
+        reader.read_record(&header, &mut record)?;
+        let expected = record.clone();
+
+        assert_eq!(reader.read_record(&header, &mut record)?, 0);
+        assert_eq!(record, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_validate_contig_bounds() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+##INFO=<ID=END,Number=1,Type=Integer,Description=\"End position\">
+##contig=<ID=sq0,length=8>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t8\t.\tA\t.\t.\tPASS\t.
+sq1\t13\t.\tA\t.\t.\tPASS\t.
+sq2\t21\t.\tA\t.\t.\tPASS\t.
+sq0\t13\t.\tA\t.\t.\tPASS\t.
+sq0\t7\t.\tAC\t.\t.\tPASS\t.
+sq0\t8\t.\tACG\t.\t.\tPASS\t.
+sq0\t1\t.\tA\t<DEL>\t.\tPASS\tEND=13
+";
+
+        let mut reader = Builder::default()
+            .set_validate_contig_bounds(true)
+            .build_from_reader(DATA)?;
+        let header = reader.read_header()?;
+
+        let mut record = Record::default();
+
+        // contig with a length
+        reader.read_record(&header, &mut record)?;
+        // contig without a length
+        reader.read_record(&header, &mut record)?;
+        // contig missing from the header
+        reader.read_record(&header, &mut record)?;
+
+        // start position past the contig length
+        assert!(matches!(
+            reader.read_record(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // end position at the contig length
+        reader.read_record(&header, &mut record)?;
+
+        // end position from the reference bases past the contig length
+        assert!(matches!(
+            reader.read_record(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // end position from `INFO/END` past the contig length
+        assert!(matches!(
+            reader.read_record(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // validation is disabled by default
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+        let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_allow_iupac_reference_bases() -> io::Result<()> {
+        use crate::record::reference_bases::Base;
+
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tAr\t.\t.\tPASS\t.
+";
+
+        let mut reader = Builder::default()
+            .set_allow_iupac_reference_bases(true)
+            .build_from_reader(DATA)?;
+        let header = reader.read_header()?;
+
+        let mut record = Record::default();
+        reader.read_record(&header, &mut record)?;
+        assert_eq!(&record.reference_bases()[..], &[Base::A, Base::N][..]);
+        assert_eq!(record.reference_bases().to_string(), "AN");
+
+        // IUPAC ambiguity codes are invalid by default
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+
+        assert!(matches!(
+            reader.read_record(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_genotype_key_not_first() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::{
+            genotypes::{keys::key, sample::Value},
+            Genotypes,
+        };
+
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.4
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\t.\t.\tPASS\t.\tDP:GT\t13:0|0
+";
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+
+        let mut record = Record::default();
+        reader.read_record(&header, &mut record)?;
+
+        let genotypes = record.genotypes();
+        assert_eq!(
+            genotypes.keys().iter().collect::<Vec<_>>(),
+            [&key::READ_DEPTH, &key::GENOTYPE]
+        );
+        assert_eq!(
+            genotypes
+                .get_index(0)
+                .and_then(|sample| sample.get(&key::GENOTYPE)),
+            Some(Some(&Value::from("0|0")))
+        );
+
+        let genotypes = Genotypes::parse("DP:GT\t13:0|0", &header)?;
+        assert_eq!(genotypes.keys(), record.genotypes().keys());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -0,0 +1,181 @@
+use std::{collections::HashSet, io};
+
+use crate::{
+    record::{Chromosome, Position},
+    Record,
+};
+
+/// An iterator adapter that verifies records are coordinate-sorted.
+///
+/// This is created by calling [`assert_sorted`].
+pub struct AssertSorted<I> {
+    records: I,
+    last_key: Option<(Chromosome, Position)>,
+    visited_chromosomes: HashSet<Chromosome>,
+}
+
+impl<I> Iterator for AssertSorted<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.records.next()? {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let chromosome = record.chromosome();
+        let position = record.position();
+
+        let is_ordered = match &self.last_key {
+            Some((last_chromosome, last_position)) if chromosome == last_chromosome => {
+                position >= *last_position
+            }
+            Some(_) => !self.visited_chromosomes.contains(chromosome),
+            None => true,
+        };
+
+        if !is_ordered {
+            if let Some((last_chromosome, last_position)) = &self.last_key {
+                return Some(Err(out_of_order_error(
+                    chromosome,
+                    position,
+                    last_chromosome,
+                    *last_position,
+                )));
+            }
+        }
+
+        match &mut self.last_key {
+            Some((last_chromosome, last_position)) if chromosome == last_chromosome => {
+                *last_position = position;
+            }
+            _ => {
+                // The chromosome is only cloned when it changes.
+                self.visited_chromosomes.insert(chromosome.clone());
+                self.last_key = Some((chromosome.clone(), position));
+            }
+        }
+
+        Some(Ok(record))
+    }
+}
+
+/// Wraps an iterator of records to verify they are coordinate-sorted.
+///
+/// Records on the same chromosome must be contiguous and ordered by position. The order of
+/// chromosomes is not checked against the header.
+///
+/// The returned iterator yields an error with the kind [`io::ErrorKind::InvalidData`] for a
+/// record that is out of order.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufReader}};
+/// use noodles_vcf as vcf;
+///
+/// let mut reader = File::open("sample.vcf").map(BufReader::new).map(vcf::Reader::new)?;
+/// let header = reader.read_header()?;
+///
+/// for result in vcf::reader::assert_sorted(reader.records(&header)) {
+///     let record = result?;
+///     // ...
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn assert_sorted<I>(records: I) -> AssertSorted<I::IntoIter>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    AssertSorted {
+        records: records.into_iter(),
+        last_key: None,
+        visited_chromosomes: HashSet::new(),
+    }
+}
+
+fn out_of_order_error(
+    chromosome: &Chromosome,
+    position: Position,
+    last_chromosome: &Chromosome,
+    last_position: Position,
+) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "out-of-order record at {chromosome}:{position} \
+             (previous record at {last_chromosome}:{last_position})"
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(chromosome: &str, position: usize) -> io::Result<Record> {
+        Record::builder()
+            .set_chromosome(
+                chromosome
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+            )
+            .set_position(Position::from(position))
+            .set_reference_bases(
+                "A".parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+            )
+            .build()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+    }
+
+    #[test]
+    fn test_assert_sorted() -> io::Result<()> {
+        let records = vec![
+            build_record("sq1", 8),
+            build_record("sq1", 8),
+            build_record("sq1", 13),
+            build_record("sq0", 5),
+        ];
+
+        let actual: Vec<_> = assert_sorted(records).collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_sorted_with_unsorted_records() {
+        let t = |records: Vec<io::Result<Record>>, expected_message: &str| {
+            let mut iter = assert_sorted(records);
+
+            let e = loop {
+                match iter.next() {
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => break e,
+                    None => panic!("expected an error"),
+                }
+            };
+
+            assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+            assert_eq!(e.to_string(), expected_message);
+        };
+
+        t(
+            vec![build_record("sq0", 13), build_record("sq0", 8)],
+            "out-of-order record at sq0:8 (previous record at sq0:13)",
+        );
+
+        t(
+            vec![
+                build_record("sq0", 8),
+                build_record("sq1", 5),
+                build_record("sq0", 13),
+            ],
+            "out-of-order record at sq0:13 (previous record at sq1:5)",
+        );
+    }
+}
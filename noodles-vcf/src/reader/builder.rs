@@ -10,9 +10,53 @@ use super::Reader;
 
 /// A VCF reader builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    validate_contig_bounds: bool,
+    allow_iupac_reference_bases: bool,
+}
 
 impl Builder {
+    /// Sets whether to validate record positions against contig lengths.
+    ///
+    /// When enabled, reading a record fails if its start position or end position is greater
+    /// than the length of its contig, as declared in the header (`##contig=<ID=...,length=...>`).
+    /// Records on contigs that are missing from the header or that do not have a length are not
+    /// checked.
+    ///
+    /// By default, record positions are not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::reader::Builder::default().set_validate_contig_bounds(true);
+    /// ```
+    pub fn set_validate_contig_bounds(mut self, validate_contig_bounds: bool) -> Self {
+        self.validate_contig_bounds = validate_contig_bounds;
+        self
+    }
+
+    /// Sets whether to allow IUPAC ambiguity codes in reference bases.
+    ///
+    /// Reference bases (`REF`) must be one of `A`, `C`, `G`, `T`, or `N` (case-insensitive). When
+    /// enabled, the IUPAC ambiguity codes `B`, `D`, `H`, `K`, `M`, `R`, `S`, `V`, `W`, and `Y` are
+    /// also accepted and read as [`crate::record::reference_bases::Base::N`].
+    ///
+    /// By default, IUPAC ambiguity codes are invalid. This also applies to
+    /// `vcf::Record::from_str`; use [`crate::Record::parse_with_options`] to parse a record with
+    /// this option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::reader::Builder::default().set_allow_iupac_reference_bases(true);
+    /// ```
+    pub fn set_allow_iupac_reference_bases(mut self, allow_iupac_reference_bases: bool) -> Self {
+        self.allow_iupac_reference_bases = allow_iupac_reference_bases;
+        self
+    }
+
     /// Builds a VCF reader from a path.
     pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<Box<dyn BufRead>>>
     where
@@ -35,6 +79,9 @@ impl Builder {
     where
         R: BufRead,
     {
-        Ok(Reader::new(reader))
+        let mut reader = Reader::new(reader);
+        reader.validate_contig_bounds = self.validate_contig_bounds;
+        reader.allow_iupac_reference_bases = self.allow_iupac_reference_bases;
+        Ok(reader)
     }
 }
@@ -0,0 +1,10 @@
+/// An action to take when a record fails to be read.
+///
+/// This is returned by an error policy set using [`super::Reader::set_error_policy`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorAction {
+    /// Skip the line and continue reading the next record.
+    Skip,
+    /// Return the error.
+    Abort,
+}
@@ -5,7 +5,7 @@ mod chromosome;
 mod filters;
 mod genotypes;
 mod ids;
-mod info;
+pub(crate) mod info;
 mod position;
 mod quality_score;
 mod reference_bases;
@@ -85,10 +85,58 @@ impl From<ParseError> for core::Error {
     }
 }
 
+/// Options for parsing a raw VCF record.
+///
+/// By default, parsing is strict, i.e., all options are disabled.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ParseOptions {
+    allow_iupac_reference_bases: bool,
+}
+
+impl ParseOptions {
+    /// Returns whether IUPAC ambiguity codes are allowed in reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::reader::record::ParseOptions;
+    /// let options = ParseOptions::default();
+    /// assert!(!options.allow_iupac_reference_bases());
+    /// ```
+    pub fn allow_iupac_reference_bases(&self) -> bool {
+        self.allow_iupac_reference_bases
+    }
+
+    /// Sets whether to allow IUPAC ambiguity codes in reference bases.
+    ///
+    /// See [`crate::reader::Builder::set_allow_iupac_reference_bases`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::reader::record::ParseOptions;
+    /// let options = ParseOptions::default().set_allow_iupac_reference_bases(true);
+    /// assert!(options.allow_iupac_reference_bases());
+    /// ```
+    pub fn set_allow_iupac_reference_bases(mut self, allow_iupac_reference_bases: bool) -> Self {
+        self.allow_iupac_reference_bases = allow_iupac_reference_bases;
+        self
+    }
+}
+
 pub(crate) fn parse_record(
+    s: &str,
+    header: &Header,
+    record: &mut Record,
+) -> Result<(), ParseError> {
+    parse_record_with_options(s, header, record, ParseOptions::default())
+}
+
+pub(crate) fn parse_record_with_options(
     mut s: &str,
     header: &Header,
     record: &mut Record,
+    options: ParseOptions,
 ) -> Result<(), ParseError> {
     let field = next_field(&mut s);
     parse_chromosome(field, record.chromosome_mut()).map_err(ParseError::InvalidChromosome)?;
@@ -103,8 +151,12 @@ pub(crate) fn parse_record(
     }
 
     let field = next_field(&mut s);
-    parse_reference_bases(field, record.reference_bases_mut())
-        .map_err(ParseError::InvalidReferenceBases)?;
+    parse_reference_bases(
+        field,
+        options.allow_iupac_reference_bases,
+        record.reference_bases_mut(),
+    )
+    .map_err(ParseError::InvalidReferenceBases)?;
 
     record.alternate_bases_mut().clear();
     let field = next_field(&mut s);
@@ -95,4 +95,22 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_alternate_bases_with_overlapping_deletion() -> Result<(), ParseError> {
+        let mut alternate_bases = AlternateBases::default();
+        parse_alternate_bases("A,*", &mut alternate_bases)?;
+
+        assert_eq!(
+            alternate_bases,
+            AlternateBases::from(vec![
+                Allele::Bases(vec![Base::A]),
+                Allele::OverlappingDeletion,
+            ])
+        );
+
+        assert_eq!(alternate_bases.to_string(), "A,*");
+
+        Ok(())
+    }
 }
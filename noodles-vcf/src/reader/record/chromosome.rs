@@ -31,8 +31,13 @@ pub(super) fn parse_chromosome(s: &str, chromosome: &mut Chromosome) -> Result<(
     // symbol
     if let Some(t) = s.strip_prefix('<') {
         if let Some(t) = t.strip_suffix('>') {
-            if !matches!(chromosome, Chromosome::Symbol(symbol) if symbol == t) {
-                *chromosome = Chromosome::Symbol(t.into());
+            if !is_valid_name(t) {
+                return Err(ParseError::Invalid);
+            }
+
+            match chromosome {
+                Chromosome::Symbol(symbol) => replace(symbol, t),
+                Chromosome::Name(_) => *chromosome = Chromosome::Symbol(t.into()),
             }
 
             return Ok(());
@@ -41,16 +46,27 @@ pub(super) fn parse_chromosome(s: &str, chromosome: &mut Chromosome) -> Result<(
 
     // name
     if !matches!(chromosome, Chromosome::Name(name) if name == s) {
-        if is_valid_name(s) {
-            *chromosome = Chromosome::Name(s.into());
-        } else {
+        if !is_valid_name(s) {
             return Err(ParseError::Invalid);
         }
+
+        match chromosome {
+            Chromosome::Name(name) => replace(name, s),
+            Chromosome::Symbol(_) => *chromosome = Chromosome::Name(s.into()),
+        }
     }
 
     Ok(())
 }
 
+// Reuses the allocation of the destination string.
+fn replace(dst: &mut String, s: &str) {
+    if dst != s {
+        dst.clear();
+        dst.push_str(s);
+    }
+}
+
 // § 1.4.7 "Contig field format"
 fn is_valid_name_char(c: char) -> bool {
     ('!'..='~').contains(&c)
@@ -82,13 +98,27 @@ mod tests {
         parse_chromosome("sq0", &mut chromosome)?;
         assert_eq!(chromosome, Chromosome::Name(String::from("sq0")));
 
+        parse_chromosome("sq1", &mut chromosome)?;
+        assert_eq!(chromosome, Chromosome::Name(String::from("sq1")));
+
         parse_chromosome("<sq0>", &mut chromosome)?;
         assert_eq!(chromosome, Chromosome::Symbol(String::from("sq0")));
 
-        assert_eq!(
-            parse_chromosome("", &mut chromosome),
-            Err(ParseError::Invalid)
-        );
+        parse_chromosome("<sq1>", &mut chromosome)?;
+        assert_eq!(chromosome, Chromosome::Symbol(String::from("sq1")));
+
+        parse_chromosome("sq0", &mut chromosome)?;
+        assert_eq!(chromosome, Chromosome::Name(String::from("sq0")));
+
+        let t = |s: &str, chromosome: &mut Chromosome| {
+            assert_eq!(parse_chromosome(s, chromosome), Err(ParseError::Invalid));
+            assert_eq!(chromosome, &Chromosome::Name(String::from("sq0")));
+        };
+
+        t("", &mut chromosome);
+        t("sq 0", &mut chromosome);
+        t("<>", &mut chromosome);
+        t("<sq 0>", &mut chromosome);
 
         Ok(())
     }
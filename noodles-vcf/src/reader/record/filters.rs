@@ -14,6 +14,8 @@ pub enum ParseError {
     InvalidFilter,
     /// A filter is duplicated.
     DuplicateFilter,
+    /// `PASS` is combined with other filters.
+    PassWithFilters,
 }
 
 impl error::Error for ParseError {}
@@ -24,6 +26,7 @@ impl fmt::Display for ParseError {
             Self::Empty => write!(f, "empty input"),
             Self::InvalidFilter => write!(f, "invalid filter"),
             Self::DuplicateFilter => write!(f, "duplicate filter"),
+            Self::PassWithFilters => write!(f, "PASS cannot be combined with other filters"),
         }
     }
 }
@@ -58,6 +61,8 @@ pub(super) fn parse_filters(s: &str, filters: &mut Option<Filters>) -> Result<()
             return Err(ParseError::DuplicateFilter);
         } else if !is_valid_filter(raw_filter) {
             return Err(ParseError::InvalidFilter);
+        } else if raw_filter == PASS {
+            return Err(ParseError::PassWithFilters);
         }
     }
 
@@ -68,7 +73,7 @@ pub(super) fn parse_filters(s: &str, filters: &mut Option<Filters>) -> Result<()
 
 fn is_valid_filter(s: &str) -> bool {
     match s {
-        "" | "0" => false,
+        "" | "0" | "." => false,
         _ => s.chars().all(|c| !c.is_whitespace()),
     }
 }
@@ -125,6 +130,18 @@ mod tests {
             parse_filters("q10;q10", &mut filters),
             Err(ParseError::DuplicateFilter)
         );
+        assert_eq!(
+            parse_filters("q10;.", &mut filters),
+            Err(ParseError::InvalidFilter)
+        );
+        assert_eq!(
+            parse_filters("PASS;q10", &mut filters),
+            Err(ParseError::PassWithFilters)
+        );
+        assert_eq!(
+            parse_filters("q10;PASS", &mut filters),
+            Err(ParseError::PassWithFilters)
+        );
 
         Ok(())
     }
@@ -5,7 +5,7 @@ use noodles_core as core;
 use crate::{
     reader::record::MISSING,
     record::genotypes::{
-        keys::{key, Key},
+        keys::{is_valid_genotype_key_position, key, Key},
         Keys,
     },
     Header,
@@ -20,7 +20,7 @@ pub enum ParseError {
     InvalidKey(key::ParseError),
     /// The genotype key (`GT`) position is invalid.
     ///
-    /// The genotype key must be first, if present.
+    /// Before VCF 4.4, the genotype key must be first, if present.
     InvalidGenotypeKeyPosition,
     /// A key is duplicated.
     DuplicateKey(Key),
@@ -79,7 +79,7 @@ pub(super) fn parse_keys(header: &Header, s: &str, keys: &mut Keys) -> Result<()
     }
 
     if let Some(i) = gt_position {
-        if i != 0 {
+        if !is_valid_genotype_key_position(header.file_format(), i) {
             return Err(ParseError::InvalidGenotypeKeyPosition);
         }
     }
@@ -90,10 +90,13 @@ pub(super) fn parse_keys(header: &Header, s: &str, keys: &mut Keys) -> Result<()
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::header::FileFormat;
 
     #[test]
     fn test_parse_keys() -> Result<(), Box<dyn std::error::Error>> {
-        let header = Header::default();
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .build();
         let mut keys = Keys::default();
 
         keys.clear();
@@ -132,4 +135,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_keys_with_genotype_key_not_first() -> Result<(), Box<dyn std::error::Error>> {
+        let mut keys = Keys::default();
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 2))
+            .build();
+
+        assert_eq!(
+            parse_keys(&header, "GQ:GT", &mut keys),
+            Err(ParseError::InvalidGenotypeKeyPosition)
+        );
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 4))
+            .build();
+
+        keys.clear();
+        parse_keys(&header, "GQ:GT", &mut keys)?;
+        assert_eq!(
+            keys.iter().collect::<Vec<_>>(),
+            [&key::CONDITIONAL_GENOTYPE_QUALITY, &key::GENOTYPE]
+        );
+
+        Ok(())
+    }
 }
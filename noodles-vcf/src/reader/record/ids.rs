@@ -117,6 +117,24 @@ mod tests {
             Err(ParseError::DuplicateId(id0))
         );
 
+        ids.clear();
+        assert_eq!(
+            parse_ids("rs1;rs1", &mut ids),
+            Err(ParseError::DuplicateId("rs1".parse()?))
+        );
+
+        ids.clear();
+        assert_eq!(
+            parse_ids("rs 1", &mut ids),
+            Err(ParseError::InvalidId(id::ParseError::ContainsWhitespace))
+        );
+
+        ids.clear();
+        assert_eq!(
+            parse_ids("rs0;rs 1", &mut ids),
+            Err(ParseError::InvalidId(id::ParseError::ContainsWhitespace))
+        );
+
         Ok(())
     }
 }
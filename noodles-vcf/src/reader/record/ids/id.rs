@@ -9,8 +9,8 @@ use crate::record::ids::Id;
 pub enum ParseError {
     /// The input is empty.
     Empty,
-    /// The input is invalid.
-    Invalid,
+    /// The input contains whitespace.
+    ContainsWhitespace,
 }
 
 impl error::Error for ParseError {}
@@ -19,7 +19,7 @@ impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Empty => write!(f, "empty input"),
-            Self::Invalid => write!(f, "invalid input"),
+            Self::ContainsWhitespace => write!(f, "input contains whitespace"),
         }
     }
 }
@@ -36,7 +36,7 @@ pub(super) fn parse_id(s: &str) -> Result<Id, ParseError> {
     } else if is_valid_id(s) {
         Ok(Id(s.into()))
     } else {
-        Err(ParseError::Invalid)
+        Err(ParseError::ContainsWhitespace)
     }
 }
 
@@ -53,7 +53,8 @@ mod tests {
         assert_eq!(parse_id("nd0"), Ok("nd0".parse()?));
 
         assert_eq!(parse_id(""), Err(ParseError::Empty));
-        assert_eq!(parse_id("nd 0"), Err(ParseError::Invalid));
+        assert_eq!(parse_id("nd 0"), Err(ParseError::ContainsWhitespace));
+        assert_eq!(parse_id("nd0\t"), Err(ParseError::ContainsWhitespace));
 
         Ok(())
     }
@@ -54,7 +54,7 @@ impl From<ParseError> for core::Error {
     }
 }
 
-pub(super) fn parse_info(header: &Header, s: &str, info: &mut Info) -> Result<(), ParseError> {
+pub(crate) fn parse_info(header: &Header, s: &str, info: &mut Info) -> Result<(), ParseError> {
     use indexmap::map::Entry;
 
     const DELIMITER: char = ';';
@@ -129,4 +129,97 @@ mod tests {
             Err(ParseError::InvalidValue(key::SAMPLES_WITH_DATA_COUNT, _))
         ));
     }
+
+    #[test]
+    fn test_parse_field_with_array_values_with_missing_elements(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::{
+            record::value::{map::Info, Map},
+            Number,
+        };
+
+        let integers_key: Key = "I32".parse()?;
+        let floats_key: Key = "F32".parse()?;
+        let characters_key: Key = "CHAR".parse()?;
+
+        let header = Header::builder()
+            .add_info(
+                integers_key.clone(),
+                Map::<Info>::new(Number::Count(2), Type::Integer, String::new()),
+            )
+            .add_info(
+                floats_key.clone(),
+                Map::<Info>::new(Number::Count(2), Type::Float, String::new()),
+            )
+            .add_info(
+                characters_key.clone(),
+                Map::<Info>::new(Number::Count(2), Type::Character, String::new()),
+            )
+            .build();
+
+        fn t<V>(header: &Header, s: &str, key: &Key, expected_value: Option<V>)
+        where
+            V: Into<Value>,
+        {
+            let actual = parse_field(header, s);
+            let expected = Ok((key.clone(), expected_value.map(|v| v.into())));
+            assert_eq!(actual, expected);
+        }
+
+        t(
+            &header,
+            "I32=8,13",
+            &integers_key,
+            Some(vec![Some(8), Some(13)]),
+        );
+        t(&header, "I32=8,.", &integers_key, Some(vec![Some(8), None]));
+        t(
+            &header,
+            "I32=.,13",
+            &integers_key,
+            Some(vec![None, Some(13)]),
+        );
+        t(
+            &header,
+            "I32=.,.",
+            &integers_key,
+            Some(vec![None::<i32>, None]),
+        );
+        t(&header, "I32=.", &integers_key, None::<Vec<Option<i32>>>);
+
+        t(
+            &header,
+            "F32=0.5,.",
+            &floats_key,
+            Some(vec![Some(0.5), None]),
+        );
+        t(
+            &header,
+            "F32=.,0.5",
+            &floats_key,
+            Some(vec![None, Some(0.5)]),
+        );
+        t(&header, "F32=.", &floats_key, None::<Vec<Option<f32>>>);
+
+        t(
+            &header,
+            "CHAR=n,.",
+            &characters_key,
+            Some(vec![Some('n'), None]),
+        );
+        t(
+            &header,
+            "CHAR=.,n",
+            &characters_key,
+            Some(vec![None, Some('n')]),
+        );
+        t(
+            &header,
+            "CHAR=.",
+            &characters_key,
+            None::<Vec<Option<char>>>,
+        );
+
+        Ok(())
+    }
 }
@@ -121,6 +121,7 @@ fn parse_flag(s: &str) -> Result<Value, ParseError> {
 }
 
 fn parse_raw_char(s: &str) -> Result<char, ParseError> {
+    let s = value::percent_decode(s).map_err(|_| ParseError::InvalidCharacter)?;
     let mut chars = s.chars();
 
     if let Some(c) = chars.next() {
@@ -269,6 +270,16 @@ mod tests {
             parse_value(Number::Count(2), Type::Character, "n,d,l,."),
             Ok(Value::from(vec![Some('n'), Some('d'), Some('l'), None]))
         );
+
+        assert_eq!(
+            parse_value(Number::Count(1), Type::Character, "%3B"),
+            Ok(Value::from(';'))
+        );
+
+        assert_eq!(
+            parse_value(Number::Count(1), Type::Character, "nd"),
+            Err(ParseError::InvalidCharacter)
+        );
     }
 
     #[test]
@@ -13,6 +13,10 @@ pub enum ParseError {
     Invalid,
     /// The value is negative.
     Negative,
+    /// The value is NaN.
+    Nan,
+    /// The value is infinite.
+    Infinite,
 }
 impl error::Error for ParseError {}
 
@@ -22,6 +26,8 @@ impl fmt::Display for ParseError {
             Self::Empty => write!(f, "empty input"),
             Self::Invalid => write!(f, "invalid input"),
             Self::Negative => write!(f, "negative value"),
+            Self::Nan => write!(f, "NaN"),
+            Self::Infinite => write!(f, "infinite value"),
         }
     }
 }
@@ -43,6 +49,8 @@ pub(super) fn parse_quality_score(s: &str) -> Result<QualityScore, ParseError> {
 
     QualityScore::try_from(n).map_err(|e| match e {
         TryFromFloatError::Negative => ParseError::Negative,
+        TryFromFloatError::Nan => ParseError::Nan,
+        TryFromFloatError::Infinite => ParseError::Infinite,
     })
 }
 
@@ -59,6 +67,8 @@ mod tests {
         assert_eq!(parse_quality_score("."), Err(ParseError::Invalid));
         assert_eq!(parse_quality_score("ndls"), Err(ParseError::Invalid));
         assert_eq!(parse_quality_score("-1.0"), Err(ParseError::Negative));
+        assert_eq!(parse_quality_score("NaN"), Err(ParseError::Nan));
+        assert_eq!(parse_quality_score("inf"), Err(ParseError::Infinite));
 
         Ok(())
     }
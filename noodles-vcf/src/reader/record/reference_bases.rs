@@ -42,6 +42,7 @@ impl From<ParseError> for core::Error {
 
 pub(super) fn parse_reference_bases(
     s: &str,
+    allow_iupac_codes: bool,
     reference_bases: &mut ReferenceBases,
 ) -> Result<(), ParseError> {
     if s.is_empty() {
@@ -51,7 +52,7 @@ pub(super) fn parse_reference_bases(
     reference_bases.0.clear();
 
     for c in s.chars() {
-        let base = parse_base(c).map_err(ParseError::InvalidBase)?;
+        let base = parse_base(c, allow_iupac_codes).map_err(ParseError::InvalidBase)?;
         reference_bases.0.push(base);
     }
 
@@ -70,30 +71,43 @@ mod tests {
 
         let expected = [Base::A, Base::T, Base::C, Base::G, Base::N];
 
-        parse_reference_bases("ATCGN", &mut reference_bases)?;
+        parse_reference_bases("ATCGN", false, &mut reference_bases)?;
         assert_eq!(&reference_bases[..], &expected[..]);
 
-        parse_reference_bases("atcgn", &mut reference_bases)?;
+        parse_reference_bases("atcgn", false, &mut reference_bases)?;
         assert_eq!(&reference_bases[..], &expected[..]);
 
-        parse_reference_bases("AtCgN", &mut reference_bases)?;
+        parse_reference_bases("AtCgN", false, &mut reference_bases)?;
         assert_eq!(&reference_bases[..], &expected[..]);
 
         assert_eq!(
-            parse_reference_bases("", &mut reference_bases),
+            parse_reference_bases("", false, &mut reference_bases),
             Err(ParseError::Empty)
         );
 
         assert!(matches!(
-            parse_reference_bases(".", &mut reference_bases),
+            parse_reference_bases(".", false, &mut reference_bases),
             Err(ParseError::InvalidBase(_))
         ));
 
         assert!(matches!(
-            parse_reference_bases("Z", &mut reference_bases),
+            parse_reference_bases("Z", false, &mut reference_bases),
             Err(ParseError::InvalidBase(_))
         ));
 
+        assert!(matches!(
+            parse_reference_bases("ARN", false, &mut reference_bases),
+            Err(ParseError::InvalidBase(_))
+        ));
+
+        parse_reference_bases("ARn", true, &mut reference_bases)?;
+        assert_eq!(&reference_bases[..], &[Base::A, Base::N, Base::N][..]);
+
+        assert_eq!(
+            parse_reference_bases("", true, &mut reference_bases),
+            Err(ParseError::Empty)
+        );
+
         Ok(())
     }
 }
@@ -27,13 +27,17 @@ impl From<ParseError> for core::Error {
     }
 }
 
-pub(super) fn parse_base(c: char) -> Result<Base, ParseError> {
+pub(super) fn parse_base(c: char, allow_iupac_codes: bool) -> Result<Base, ParseError> {
     match c.to_ascii_uppercase() {
         'A' => Ok(Base::A),
         'C' => Ok(Base::C),
         'G' => Ok(Base::G),
         'T' => Ok(Base::T),
         'N' => Ok(Base::N),
+        // IUPAC ambiguity codes are treated as any base.
+        'B' | 'D' | 'H' | 'K' | 'M' | 'R' | 'S' | 'V' | 'W' | 'Y' if allow_iupac_codes => {
+            Ok(Base::N)
+        }
         _ => Err(ParseError::Invalid(c)),
     }
 }
@@ -44,19 +48,31 @@ mod tests {
 
     #[test]
     fn test_parse_base() {
-        assert_eq!(parse_base('A'), Ok(Base::A));
-        assert_eq!(parse_base('C'), Ok(Base::C));
-        assert_eq!(parse_base('G'), Ok(Base::G));
-        assert_eq!(parse_base('T'), Ok(Base::T));
-        assert_eq!(parse_base('N'), Ok(Base::N));
-
-        assert_eq!(parse_base('a'), Ok(Base::A));
-        assert_eq!(parse_base('c'), Ok(Base::C));
-        assert_eq!(parse_base('g'), Ok(Base::G));
-        assert_eq!(parse_base('t'), Ok(Base::T));
-        assert_eq!(parse_base('n'), Ok(Base::N));
-
-        assert_eq!(parse_base('Z'), Err(ParseError::Invalid('Z')));
-        assert_eq!(parse_base('z'), Err(ParseError::Invalid('z')));
+        assert_eq!(parse_base('A', false), Ok(Base::A));
+        assert_eq!(parse_base('C', false), Ok(Base::C));
+        assert_eq!(parse_base('G', false), Ok(Base::G));
+        assert_eq!(parse_base('T', false), Ok(Base::T));
+        assert_eq!(parse_base('N', false), Ok(Base::N));
+
+        assert_eq!(parse_base('a', false), Ok(Base::A));
+        assert_eq!(parse_base('c', false), Ok(Base::C));
+        assert_eq!(parse_base('g', false), Ok(Base::G));
+        assert_eq!(parse_base('t', false), Ok(Base::T));
+        assert_eq!(parse_base('n', false), Ok(Base::N));
+
+        assert_eq!(parse_base('Z', false), Err(ParseError::Invalid('Z')));
+        assert_eq!(parse_base('z', false), Err(ParseError::Invalid('z')));
+    }
+
+    #[test]
+    fn test_parse_base_with_iupac_codes() {
+        for c in "BDHKMRSVWYbdhkmrsvwy".chars() {
+            assert_eq!(parse_base(c, false), Err(ParseError::Invalid(c)));
+            assert_eq!(parse_base(c, true), Ok(Base::N));
+        }
+
+        assert_eq!(parse_base('A', true), Ok(Base::A));
+        assert_eq!(parse_base('Z', true), Err(ParseError::Invalid('Z')));
+        assert_eq!(parse_base('.', true), Err(ParseError::Invalid('.')));
     }
 }
@@ -1,5 +1,6 @@
 //! VCF record and fields.
 
+mod allele_counts;
 pub mod alternate_bases;
 pub mod builder;
 pub mod chromosome;
@@ -7,6 +8,8 @@ pub mod filters;
 pub mod genotypes;
 pub mod ids;
 pub mod info;
+mod info_cardinality;
+mod normalize;
 mod parser;
 pub mod position;
 pub mod quality_score;
@@ -14,14 +17,18 @@ pub mod reference_bases;
 pub(crate) mod value;
 
 pub use self::{
-    alternate_bases::AlternateBases, builder::Builder, chromosome::Chromosome, filters::Filters,
-    genotypes::Genotypes, ids::Ids, info::Info, position::Position, quality_score::QualityScore,
+    allele_counts::AlleleCounts, alternate_bases::AlternateBases, builder::Builder,
+    chromosome::Chromosome, filters::Filters, genotypes::Genotypes, ids::Ids, info::Info,
+    info_cardinality::InfoCardinalityError, position::Position, quality_score::QualityScore,
     reference_bases::ReferenceBases,
 };
 
-use std::{error, fmt, num, str::FromStr};
+use std::{error, fmt, io, num, str::FromStr};
 
-use super::{reader::record::ParseError, Header};
+use super::{
+    reader::record::{ParseError, ParseOptions},
+    Header,
+};
 
 pub(crate) const MISSING_FIELD: &str = ".";
 pub(crate) const FIELD_DELIMITER: char = '\t';
@@ -65,6 +72,28 @@ impl Record {
         Self::try_from((header, s))
     }
 
+    /// Parses a raw VCF record using a VCF header as context and the given parse options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, reader::record::ParseOptions, record::reference_bases::Base};
+    ///
+    /// let s = "sq0\t8\t.\tR\t.\t.\tPASS\t.";
+    /// let header = vcf::Header::default();
+    /// let options = ParseOptions::default().set_allow_iupac_reference_bases(true);
+    /// let record = vcf::Record::parse_with_options(s, &header, options)?;
+    /// assert_eq!(&record.reference_bases()[..], [Base::N]);
+    /// # Ok::<_, vcf::reader::record::ParseError>(())
+    /// ```
+    pub fn parse_with_options(
+        s: &str,
+        header: &Header,
+        options: ParseOptions,
+    ) -> Result<Self, ParseError> {
+        parser::parse_with_options(s, header, options)
+    }
+
     /// Returns a builder to create a record from each of its fields.
     ///
     /// # Examples
@@ -587,9 +616,35 @@ impl Record {
     pub fn genotypes_mut(&mut self) -> &mut Genotypes {
         &mut self.genotypes
     }
+
+    /// Returns whether the record is equal to the default record.
+    ///
+    /// See [`Record::default`] for the values of a default record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let mut record = vcf::Record::default();
+    /// assert!(record.is_default());
+    ///
+    /// *record.position_mut() = Position::from(8);
+    /// assert!(!record.is_default());
+    /// ```
+    pub fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
 }
 
 impl Default for Record {
+    /// Creates a default VCF record.
+    ///
+    /// A default record has a chromosome name of `.`, a position of 1, and reference bases of
+    /// `N`. The IDs, alternate bases, info, and genotypes are empty, and the quality score and
+    /// filters are missing.
+    ///
+    /// This is typically used as a reusable buffer for [`crate::Reader::read_record`].
     fn default() -> Self {
         use self::reference_bases::Base;
 
@@ -707,6 +762,115 @@ impl Record {
 
         Ok(Position::from(end))
     }
+
+    /// Normalizes the reference and alternate bases of the record.
+    ///
+    /// Alleles are left-aligned and parsimoniously trimmed against the given reference sequence,
+    /// as described in "Unified representation of genetic variants" (Tan et al., 2015). All
+    /// alleles of a multiallelic record are normalized together, sharing the same reference bases.
+    ///
+    /// The reference sequence is the full sequence of the record's chromosome, where the first
+    /// base is at position 1.
+    ///
+    /// This is a no-op if an alternate allele is not a list of bases (e.g., a symbolic allele) or
+    /// if the record is not a variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(5))
+    ///     .set_reference_bases("AA".parse()?)
+    ///     .set_alternate_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// record.normalize(b"TCAAAAGT")?;
+    ///
+    /// assert_eq!(record.position(), Position::from(2));
+    /// assert_eq!(record.reference_bases().to_string(), "CA");
+    /// assert_eq!(record.alternate_bases().to_string(), "C");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn normalize(&mut self, reference_sequence: &[u8]) -> io::Result<()> {
+        normalize::normalize(self, reference_sequence)
+    }
+
+    /// Computes the allele counts of the record from the genotypes of its samples.
+    ///
+    /// This scans the genotype (`GT`) of each sample and returns the allele count for each
+    /// alternate allele (`AC`), the total number of called alleles (`AN`), and the allele
+    /// frequency for each alternate allele (`AF`). Missing alleles and samples without a genotype
+    /// are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     record::{
+    ///         genotypes::{keys::key, sample::Value, Keys},
+    ///         Genotypes, Position,
+    ///     },
+    /// };
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C".parse()?)
+    ///     .set_genotypes(Genotypes::new(
+    ///         Keys::try_from(vec![key::GENOTYPE])?,
+    ///         vec![vec![Some(Value::from("0|1"))], vec![Some(Value::from("1/1"))]],
+    ///     ))
+    ///     .build()?;
+    ///
+    /// let allele_counts = record.allele_counts()?;
+    /// assert_eq!(allele_counts.ac(), [3]);
+    /// assert_eq!(allele_counts.an(), 4);
+    /// assert_eq!(allele_counts.af(), [0.75]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allele_counts(&self) -> io::Result<AlleleCounts> {
+        allele_counts::allele_counts(self)
+    }
+
+    /// Validates the number of values of each INFO field against the number of alleles.
+    ///
+    /// Fields defined with `Number=A` must have one value per alternate allele; `Number=R`, one
+    /// value per allele, including the reference; and `Number=G`, one value per possible diploid
+    /// genotype. Missing values and fields without a definition in the header (or a reserved
+    /// definition) are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Info, Map},
+    ///     record::{info::field::key, Position},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(key::ALLELE_FREQUENCIES, Map::<Info>::from(&key::ALLELE_FREQUENCIES))
+    ///     .build();
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C,G".parse()?)
+    ///     .set_info(vcf::record::Info::try_from_str("AF=0.5", header.infos())?)
+    ///     .build()?;
+    ///
+    /// assert!(record.validate_info_cardinality(&header).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_info_cardinality(&self, header: &Header) -> Result<(), InfoCardinalityError> {
+        info_cardinality::validate_info_cardinality(header, self)
+    }
 }
 
 impl fmt::Display for Record {
@@ -733,7 +897,7 @@ impl fmt::Display for Record {
         }
 
         if let Some(qual) = self.quality_score() {
-            write!(f, "\t{qual}")?;
+            write!(f, "\t{}", value::Float(f32::from(qual)))?;
         } else {
             write!(f, "\t{MISSING_FIELD}")?;
         }
@@ -793,6 +957,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_is_default() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(Record::default().is_default());
+
+        let record = Record::builder()
+            .set_chromosome(".".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("N".parse()?)
+            .build()?;
+        assert!(record.is_default());
+
+        let mut record = Record::default();
+        *record.chromosome_mut() = "sq0".parse()?;
+        assert!(!record.is_default());
+
+        let mut record = Record::default();
+        *record.reference_bases_mut() = "NN".parse()?;
+        assert!(!record.is_default());
+
+        let mut record = Record::default();
+        *record.filters_mut() = Some(Filters::Pass);
+        assert!(!record.is_default());
+
+        let mut record = Record::default();
+        *record.info_mut() = "NS=2".parse()?;
+        assert!(!record.is_default());
+
+        Ok(())
+    }
+
     #[test]
     fn test_end() -> Result<(), Box<dyn std::error::Error>> {
         use crate::record::info::field::key;
@@ -0,0 +1,174 @@
+//! VCF record allele counts.
+
+use std::io;
+
+use super::Record;
+
+/// VCF record allele counts.
+///
+/// These are the values of the `AC`, `AN`, and `AF` info fields computed from the genotypes of a
+/// record.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AlleleCounts {
+    ac: Vec<u32>,
+    an: u32,
+    af: Vec<f64>,
+}
+
+impl AlleleCounts {
+    /// Returns the allele count for each alternate allele (`AC`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::AlleleCounts;
+    /// let allele_counts = AlleleCounts::default();
+    /// assert!(allele_counts.ac().is_empty());
+    /// ```
+    pub fn ac(&self) -> &[u32] {
+        &self.ac
+    }
+
+    /// Returns the total number of called alleles (`AN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::AlleleCounts;
+    /// let allele_counts = AlleleCounts::default();
+    /// assert_eq!(allele_counts.an(), 0);
+    /// ```
+    pub fn an(&self) -> u32 {
+        self.an
+    }
+
+    /// Returns the allele frequency for each alternate allele (`AF`).
+    ///
+    /// This is `AC / AN` for each alternate allele. If no alleles are called (`AN` = 0), each
+    /// allele frequency is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::AlleleCounts;
+    /// let allele_counts = AlleleCounts::default();
+    /// assert!(allele_counts.af().is_empty());
+    /// ```
+    pub fn af(&self) -> &[f64] {
+        &self.af
+    }
+}
+
+pub(super) fn allele_counts(record: &Record) -> io::Result<AlleleCounts> {
+    let alternate_allele_count = record.alternate_bases().len();
+
+    let mut ac = vec![0; alternate_allele_count];
+    let mut an = 0;
+
+    for result in record.genotypes().genotypes() {
+        let genotype = match result? {
+            Some(genotype) => genotype,
+            None => continue,
+        };
+
+        for allele in genotype.iter() {
+            let position = match allele.position() {
+                Some(position) => position,
+                None => continue,
+            };
+
+            if position > 0 {
+                let count = ac.get_mut(position - 1).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "invalid genotype allele position: \
+                             expected <= {alternate_allele_count}, got {position}"
+                        ),
+                    )
+                })?;
+
+                *count += 1;
+            }
+
+            an += 1;
+        }
+    }
+
+    let af = ac
+        .iter()
+        .map(|&n| {
+            if an == 0 {
+                0.0
+            } else {
+                f64::from(n) / f64::from(an)
+            }
+        })
+        .collect();
+
+    Ok(AlleleCounts { ac, an, af })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::record::value::{map::Format, Map},
+        record::{genotypes::keys::key, Genotypes, Position},
+        Header,
+    };
+
+    fn build_record(
+        alternate_bases: &str,
+        genotypes: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+            .build();
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_alternate_bases(alternate_bases.parse()?)
+            .set_genotypes(Genotypes::parse(genotypes, &header)?)
+            .build()?;
+
+        Ok(record)
+    }
+
+    #[test]
+    fn test_allele_counts() -> Result<(), Box<dyn std::error::Error>> {
+        let record = build_record("C", "GT\t0/0\t0|1\t1/1\t./.\t.\t1/.")?;
+        let actual = allele_counts(&record)?;
+        assert_eq!(actual.ac(), [4]);
+        assert_eq!(actual.an(), 7);
+        assert_eq!(actual.af(), [4.0 / 7.0]);
+
+        let record = build_record("C,G", "GT\t0/1\t1/2\t2/2\t0\t./2")?;
+        let actual = allele_counts(&record)?;
+        assert_eq!(actual.ac(), [2, 4]);
+        assert_eq!(actual.an(), 8);
+        assert_eq!(actual.af(), [0.25, 0.5]);
+
+        let record = build_record("C", "GT\t./.\t.")?;
+        let actual = allele_counts(&record)?;
+        assert_eq!(actual.ac(), [0]);
+        assert_eq!(actual.an(), 0);
+        assert_eq!(actual.af(), [0.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_allele_counts_with_invalid_allele_position() -> Result<(), Box<dyn std::error::Error>> {
+        let record = build_record("C", "GT\t0/2")?;
+
+        assert!(matches!(
+            allele_counts(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
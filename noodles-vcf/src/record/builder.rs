@@ -3,9 +3,10 @@
 use std::{error, fmt};
 
 use super::{
-    reference_bases::Base, AlternateBases, Chromosome, Filters, Genotypes, Ids, Info, Position,
-    QualityScore, Record, ReferenceBases,
+    genotypes, info, reference_bases::Base, AlternateBases, Chromosome, Filters, Genotypes, Ids,
+    Info, Position, QualityScore, Record, ReferenceBases,
 };
+use crate::{header, Header};
 
 /// A VCF record builder.
 #[derive(Debug, Default, PartialEq)]
@@ -30,6 +31,10 @@ pub enum BuildError {
     MissingPosition,
     /// The reference bases are missing.
     MissingReferenceBases,
+    /// An INFO field key is not defined in the header.
+    MissingInfoDefinition(info::field::Key),
+    /// A FORMAT key is not defined in the header.
+    MissingFormatDefinition(genotypes::keys::Key),
 }
 
 impl error::Error for BuildError {}
@@ -40,6 +45,10 @@ impl fmt::Display for BuildError {
             Self::MissingChromosome => f.write_str("missing chromosome"),
             Self::MissingPosition => f.write_str("missing position"),
             Self::MissingReferenceBases => f.write_str("missing reference bases"),
+            Self::MissingInfoDefinition(key) => write!(f, "missing INFO definition for {key}"),
+            Self::MissingFormatDefinition(key) => {
+                write!(f, "missing FORMAT definition for {key}")
+            }
         }
     }
 }
@@ -267,6 +276,50 @@ impl Builder {
         self
     }
 
+    /// Sets the information fields, checking that every key is defined in the given header.
+    ///
+    /// This fails with [`BuildError::MissingInfoDefinition`] if a key does not have a header INFO
+    /// record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Info, Map},
+    ///     record::{builder::BuildError, info::field::key, Position},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(key::SAMPLES_WITH_DATA_COUNT, Map::<Info>::from(&key::SAMPLES_WITH_DATA_COUNT))
+    ///     .build();
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .try_set_info(&header, "NS=3".parse()?)?
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.info().len(), 1);
+    ///
+    /// assert_eq!(
+    ///     vcf::Record::builder().try_set_info(&header, "NS=3;DP=8".parse()?),
+    ///     Err(BuildError::MissingInfoDefinition(key::TOTAL_DEPTH)),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_set_info(self, header: &Header, info: Info) -> Result<Self, BuildError> {
+        if let Some(key) = info
+            .keys()
+            .find(|key| header::lookup(header.infos(), key.as_ref()).is_none())
+        {
+            return Err(BuildError::MissingInfoDefinition(key.clone()));
+        }
+
+        Ok(self.set_info(info))
+    }
+
     /// Sets the list of genotypes.
     ///
     /// # Examples
@@ -298,6 +351,67 @@ impl Builder {
         self
     }
 
+    /// Sets the list of genotypes, checking that every key is defined in the given header.
+    ///
+    /// This fails with [`BuildError::MissingFormatDefinition`] if a key does not have a header
+    /// FORMAT record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Format, Map},
+    ///     record::{
+    ///         builder::BuildError,
+    ///         genotypes::{keys::key, sample::Value},
+    ///         Genotypes, Position,
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+    ///     .add_sample_name("sample0")
+    ///     .build();
+    ///
+    /// let genotypes = Genotypes::new("GT".parse()?, vec![vec![Some(Value::from("0|0"))]]);
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .try_set_genotypes(&header, genotypes.clone())?
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.genotypes(), &genotypes);
+    ///
+    /// let genotypes = Genotypes::new(
+    ///     "GT:GQ".parse()?,
+    ///     vec![vec![Some(Value::from("0|0")), Some(Value::from(13))]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vcf::Record::builder().try_set_genotypes(&header, genotypes),
+    ///     Err(BuildError::MissingFormatDefinition(key::CONDITIONAL_GENOTYPE_QUALITY)),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_set_genotypes(
+        self,
+        header: &Header,
+        genotypes: Genotypes,
+    ) -> Result<Self, BuildError> {
+        if let Some(key) = genotypes
+            .keys()
+            .iter()
+            .find(|key| header::lookup(header.formats(), key.as_ref()).is_none())
+        {
+            return Err(BuildError::MissingFormatDefinition(key.clone()));
+        }
+
+        Ok(self.set_genotypes(genotypes))
+    }
+
     /// Builds a VCF record.
     ///
     /// # Examples
@@ -363,4 +477,73 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_set_info() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{map, Map};
+
+        let header = Header::builder()
+            .add_info(
+                info::field::key::SAMPLES_WITH_DATA_COUNT,
+                Map::<map::Info>::from(&info::field::key::SAMPLES_WITH_DATA_COUNT),
+            )
+            .add_info(
+                info::field::key::TOTAL_DEPTH,
+                Map::<map::Info>::from(&info::field::key::TOTAL_DEPTH),
+            )
+            .build();
+
+        let info: Info = "NS=3;DP=13".parse()?;
+
+        let record = Builder::default()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .try_set_info(&header, info.clone())?
+            .build()?;
+
+        assert_eq!(record.info(), &info);
+
+        assert_eq!(
+            Builder::default().try_set_info(&header, "NS=3;NDLS=8".parse()?),
+            Err(BuildError::MissingInfoDefinition("NDLS".parse()?))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_set_genotypes() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{map, Map};
+
+        use genotypes::{keys::key, sample::Value};
+
+        let header = Header::builder()
+            .add_format(key::GENOTYPE, Map::<map::Format>::from(&key::GENOTYPE))
+            .add_sample_name("sample0")
+            .build();
+
+        let genotypes = Genotypes::new("GT".parse()?, vec![vec![Some(Value::from("0|1"))]]);
+
+        let record = Builder::default()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .try_set_genotypes(&header, genotypes.clone())?
+            .build()?;
+
+        assert_eq!(record.genotypes(), &genotypes);
+
+        let genotypes = Genotypes::new(
+            "GT:DP".parse()?,
+            vec![vec![Some(Value::from("0|1")), Some(Value::from(8))]],
+        );
+
+        assert_eq!(
+            Builder::default().try_set_genotypes(&header, genotypes),
+            Err(BuildError::MissingFormatDefinition(key::READ_DEPTH))
+        );
+
+        Ok(())
+    }
 }
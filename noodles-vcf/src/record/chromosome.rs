@@ -3,7 +3,7 @@
 use std::{error, fmt, str::FromStr};
 
 /// A VCF record chromosome (`CHROM`).
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Chromosome {
     /// A reference sequence name.
     Name(String),
@@ -51,7 +51,11 @@ impl FromStr for Chromosome {
         // symbol
         if let Some(t) = s.strip_prefix('<') {
             if let Some(t) = t.strip_suffix('>') {
-                return Ok(Self::Symbol(t.into()));
+                return if is_valid_name(t) {
+                    Ok(Self::Symbol(t.into()))
+                } else {
+                    Err(ParseError::Invalid)
+                };
             }
         }
 
@@ -76,13 +80,12 @@ fn is_valid_name_char(c: char) -> bool {
 pub(crate) fn is_valid_name(s: &str) -> bool {
     let mut chars = s.chars();
 
-    if let Some(c) = chars.next() {
-        if c == '*' || c == '=' || !is_valid_name_char(c) {
-            return false;
-        }
-    }
+    let is_valid_first_char = chars
+        .next()
+        .map(|c| c != '*' && c != '=' && is_valid_name_char(c))
+        .unwrap_or_default();
 
-    chars.all(is_valid_name_char)
+    is_valid_first_char && chars.all(is_valid_name_char)
 }
 
 #[cfg(test)]
@@ -99,6 +102,7 @@ mod tests {
     fn test_from_str() {
         assert_eq!("sq0".parse(), Ok(Chromosome::Name(String::from("sq0"))));
         assert_eq!("<sq0>".parse(), Ok(Chromosome::Symbol(String::from("sq0"))));
+        assert_eq!("sq0:1".parse(), Ok(Chromosome::Name(String::from("sq0:1"))));
 
         assert_eq!("".parse::<Chromosome>(), Err(ParseError::Empty));
         assert_eq!("sq 0".parse::<Chromosome>(), Err(ParseError::Invalid));
@@ -106,5 +110,9 @@ mod tests {
         assert_eq!(">sq0".parse::<Chromosome>(), Err(ParseError::Invalid));
         assert_eq!("*sq0".parse::<Chromosome>(), Err(ParseError::Invalid));
         assert_eq!("=sq0".parse::<Chromosome>(), Err(ParseError::Invalid));
+        assert_eq!("sq0\t".parse::<Chromosome>(), Err(ParseError::Invalid));
+        assert_eq!("<>".parse::<Chromosome>(), Err(ParseError::Invalid));
+        assert_eq!("<sq 0>".parse::<Chromosome>(), Err(ParseError::Invalid));
+        assert_eq!("<<sq0>>".parse::<Chromosome>(), Err(ParseError::Invalid));
     }
 }
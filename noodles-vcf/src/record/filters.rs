@@ -8,6 +8,9 @@ const PASS_STATUS: &str = "PASS";
 const DELIMITER: char = ';';
 
 /// VCF record filters (`FILTER`).
+///
+/// A missing value (`.`) is not a filter state; it is represented by the absence of filters,
+/// i.e., `None` in [`crate::Record::filters`].
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Filters {
     /// Pass (`PASS`).
@@ -25,6 +28,8 @@ pub enum TryFromIteratorError {
     DuplicateFilter(String),
     /// A filter is invalid.
     InvalidFilter(String),
+    /// `PASS` is combined with other filters.
+    PassWithFilters,
 }
 
 impl error::Error for TryFromIteratorError {}
@@ -35,6 +40,9 @@ impl fmt::Display for TryFromIteratorError {
             Self::Empty => f.write_str("empty input"),
             Self::DuplicateFilter(filter) => write!(f, "duplicate filter: {filter}"),
             Self::InvalidFilter(s) => write!(f, "invalid filter: {s}"),
+            Self::PassWithFilters => {
+                write!(f, "{PASS_STATUS} cannot be combined with other filters")
+            }
         }
     }
 }
@@ -77,8 +85,12 @@ impl Filters {
 
         if filters.is_empty() {
             Err(TryFromIteratorError::Empty)
-        } else if filters.len() == 1 && filters.contains(PASS_STATUS) {
-            Ok(Self::Pass)
+        } else if filters.contains(PASS_STATUS) {
+            if filters.len() == 1 {
+                Ok(Self::Pass)
+            } else {
+                Err(TryFromIteratorError::PassWithFilters)
+            }
         } else {
             Ok(Self::Fail(filters))
         }
@@ -138,7 +150,7 @@ impl FromStr for Filters {
 
 fn is_valid_filter(s: &str) -> bool {
     match s {
-        "" | "0" => false,
+        "" | "0" | "." => false,
         _ => s.chars().all(|c| !c.is_ascii_whitespace()),
     }
 }
@@ -183,6 +195,18 @@ mod tests {
             Filters::try_from_iter(["q 10"]),
             Err(TryFromIteratorError::InvalidFilter(String::from("q 10")))
         );
+        assert_eq!(
+            Filters::try_from_iter(["."]),
+            Err(TryFromIteratorError::InvalidFilter(String::from(".")))
+        );
+        assert_eq!(
+            Filters::try_from_iter(["PASS", "q10"]),
+            Err(TryFromIteratorError::PassWithFilters)
+        );
+        assert_eq!(
+            Filters::try_from_iter(["q10", "PASS"]),
+            Err(TryFromIteratorError::PassWithFilters)
+        );
     }
 
     #[test]
@@ -253,5 +277,17 @@ mod tests {
                 TryFromIteratorError::InvalidFilter(String::from(""))
             ))
         );
+        assert_eq!(
+            ".".parse::<Filters>(),
+            Err(ParseError::InvalidFilters(
+                TryFromIteratorError::InvalidFilter(String::from("."))
+            ))
+        );
+        assert_eq!(
+            "PASS;q10".parse::<Filters>(),
+            Err(ParseError::InvalidFilters(
+                TryFromIteratorError::PassWithFilters
+            ))
+        );
     }
 }
@@ -8,6 +8,7 @@ pub use self::{keys::Keys, sample::Sample};
 use std::{
     error,
     fmt::{self, Write},
+    io,
     str::FromStr,
 };
 
@@ -127,6 +128,100 @@ impl Genotypes {
         &mut self.keys
     }
 
+    /// Adds a key and a value for each sample.
+    ///
+    /// The key is appended to the genotypes keys, and the corresponding value is appended to each
+    /// sample, in order. A sample with dropped trailing fields is padded with missing values
+    /// first.
+    ///
+    /// This returns an error if the number of values does not match the number of samples, the
+    /// key is a duplicate, the key is the genotype key (`GT`) but would be in an invalid position
+    /// for the header file format, or a sample has more values than keys. The genotypes are left
+    /// unchanged in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     record::{
+    ///         genotypes::{keys::key, sample::Value, Keys},
+    ///         Genotypes,
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let mut genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::GENOTYPE])?,
+    ///     vec![vec![Some(Value::from("0|0"))]],
+    /// );
+    ///
+    /// genotypes.add_key(
+    ///     &header,
+    ///     key::CONDITIONAL_GENOTYPE_QUALITY,
+    ///     vec![Some(Value::from(13))],
+    /// )?;
+    ///
+    /// assert_eq!(genotypes.to_string(), "GT:GQ\t0|0:13");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_key(
+        &mut self,
+        header: &Header,
+        key: keys::Key,
+        values: Vec<Option<Value>>,
+    ) -> io::Result<()> {
+        let key_count = self.keys.len();
+
+        if values.len() != self.values.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "sample count mismatch: expected {}, got {}",
+                    self.values.len(),
+                    values.len()
+                ),
+            ));
+        } else if self.keys.contains(&key) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                keys::TryFromKeyVectorError::DuplicateKey(key),
+            ));
+        } else if key == keys::key::GENOTYPE
+            && !keys::is_valid_genotype_key_position(header.file_format(), key_count)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                keys::TryFromKeyVectorError::InvalidGenotypeKeyPosition,
+            ));
+        }
+
+        if let Some((i, sample_values)) = self
+            .values
+            .iter()
+            .enumerate()
+            .find(|(_, sample_values)| sample_values.len() > key_count)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "sample {i} has more values ({}) than keys ({key_count})",
+                    sample_values.len()
+                ),
+            ));
+        }
+
+        self.keys.insert(key);
+
+        for (sample_values, value) in self.values.iter_mut().zip(values) {
+            sample_values.resize(key_count, None);
+            sample_values.push(value);
+        }
+
+        Ok(())
+    }
+
     /// Returns genotypes samples.
     pub fn values(&self) -> impl Iterator<Item = Sample<'_>> {
         self.values
@@ -141,11 +236,77 @@ impl Genotypes {
             .map(|values| Sample::new(&self.keys, values))
     }
 
-    /// Returns the VCF record genotype value.
-    pub fn genotypes(&self) -> Result<Vec<Option<sample::value::Genotype>>, sample::GenotypeError> {
+    /// Returns the genotype values for the sample with the given name.
+    ///
+    /// The sample is looked up by the position of its name in the header sample names, which is
+    /// expected to match the order of the genotypes columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Format, Map},
+    ///     record::{genotypes::{keys::key, sample::Value}, Genotypes},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let genotypes = Genotypes::parse("GT\t0|0\t1/1", &header)?;
+    ///
+    /// let sample = genotypes.sample(&header, "sample1").expect("missing sample");
+    /// assert_eq!(
+    ///     sample.get(&key::GENOTYPE),
+    ///     Some(Some(&Value::String(String::from("1/1"))))
+    /// );
+    ///
+    /// assert!(genotypes.sample(&header, "sample2").is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sample(&self, header: &Header, name: &str) -> Option<Sample<'_>> {
+        header
+            .sample_names()
+            .get_index_of(name)
+            .and_then(|i| self.get_index(i))
+    }
+
+    /// Returns an iterator over the parsed genotype (`GT`) of each sample.
+    ///
+    /// The genotype is `None` if the `GT` field is absent or its value is missing (`.`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     genotypes::{keys::key, sample::Value, Keys},
+    ///     Genotypes,
+    /// };
+    ///
+    /// let genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::GENOTYPE])?,
+    ///     vec![vec![Some(Value::from("0|1"))], vec![None]],
+    /// );
+    ///
+    /// let actual: Vec<_> = genotypes.genotypes().collect::<std::io::Result<_>>()?;
+    /// let expected = [Some("0|1".parse()?), None];
+    /// assert_eq!(actual, expected);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn genotypes(
+        &self,
+    ) -> impl Iterator<Item = io::Result<Option<sample::value::Genotype>>> + '_ {
         self.values()
-            .map(|sample| sample.genotype().transpose())
-            .collect()
+            .map(|sample| match sample.get(&keys::key::GENOTYPE) {
+                None | Some(None) => Ok(None),
+                Some(Some(_)) => sample
+                    .genotype()
+                    .transpose()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            })
     }
 }
 
@@ -224,7 +385,8 @@ fn parse(s: &str, header: &Header) -> Result<Genotypes, ParseError> {
 
     let (format, rest) = s.split_once(FIELD_DELIMITER).ok_or(ParseError::Invalid)?;
 
-    let keys = format.parse().map_err(ParseError::InvalidKeys)?;
+    let keys = keys::parse_with_file_format(format, header.formats(), header.file_format())
+        .map_err(ParseError::InvalidKeys)?;
 
     let values = rest
         .split(FIELD_DELIMITER)
@@ -279,6 +441,30 @@ mod tests {
     use super::*;
     use crate::record::genotypes::keys::key;
 
+    #[test]
+    fn test_sample() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{map::Format, Map};
+
+        let header = crate::Header::builder()
+            .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let genotypes = Genotypes::parse("GT\t0|0\t1/1", &header)?;
+
+        let sample = genotypes.sample(&header, "sample1");
+        assert_eq!(sample, genotypes.get_index(1));
+        assert_eq!(
+            sample.and_then(|sample| sample.get(&key::GENOTYPE)),
+            Some(Some(&Value::from("1/1")))
+        );
+
+        assert!(genotypes.sample(&header, "sample2").is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_genotypes() -> Result<(), Box<dyn std::error::Error>> {
         use crate::header::record::value::{map::Format, Map};
@@ -293,16 +479,115 @@ mod tests {
 
         let genotypes = Genotypes::parse("GT:GQ\t0|0:7\t./.:20\t1/1:1\t.", &header)?;
 
-        let actual = genotypes.genotypes();
-        let expected = Ok(vec![
+        let actual: Vec<_> = genotypes.genotypes().collect::<io::Result<_>>()?;
+        let expected = [
             Some("0|0".parse()?),
             Some("./.".parse()?),
             Some("1/1".parse()?),
             None,
-        ]);
+        ];
 
         assert_eq!(actual, expected);
 
+        let genotypes = Genotypes::parse("GT:GQ\t0|1:7\t.:20", &header)?;
+        let actual: Vec<_> = genotypes.genotypes().collect::<io::Result<_>>()?;
+        let expected = [Some("0|1".parse()?), None];
+        assert_eq!(actual, expected);
+
+        let genotypes = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE])?,
+            vec![vec![Some(Value::from(0))]],
+        );
+        let mut iter = genotypes.genotypes();
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let genotypes = Genotypes::new(
+            Keys::try_from(vec![key::CONDITIONAL_GENOTYPE_QUALITY])?,
+            vec![vec![Some(Value::from(13))]],
+        );
+        let actual: Vec<_> = genotypes.genotypes().collect::<io::Result<_>>()?;
+        assert_eq!(actual, [None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_key() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::FileFormat;
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .build();
+
+        let mut genotypes = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE])?,
+            vec![
+                vec![Some(Value::from("0|0"))],
+                vec![Some(Value::from("0/1"))],
+                vec![],
+            ],
+        );
+
+        genotypes.add_key(
+            &header,
+            key::CONDITIONAL_GENOTYPE_QUALITY,
+            vec![Some(Value::from(13)), None, Some(Value::from(21))],
+        )?;
+
+        let expected = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE, key::CONDITIONAL_GENOTYPE_QUALITY])?,
+            vec![
+                vec![Some(Value::from("0|0")), Some(Value::from(13))],
+                vec![Some(Value::from("0/1")), None],
+                vec![None, Some(Value::from(21))],
+            ],
+        );
+
+        assert_eq!(genotypes, expected);
+        assert_eq!(genotypes.to_string(), "GT:GQ\t0|0:13\t0/1:.\t.:21");
+
+        assert!(matches!(
+            genotypes.add_key(&header, key::READ_DEPTH, vec![Some(Value::from(5))]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            genotypes.add_key(&header, key::CONDITIONAL_GENOTYPE_QUALITY, vec![None, None, None]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert_eq!(genotypes, expected);
+
+        let mut genotypes = Genotypes::new(
+            Keys::try_from(vec![key::READ_DEPTH])?,
+            vec![vec![Some(Value::from(5))]],
+        );
+
+        assert!(matches!(
+            genotypes.add_key(&header, key::GENOTYPE, vec![Some(Value::from("0|0"))]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 4))
+            .build();
+
+        genotypes.add_key(&header, key::GENOTYPE, vec![Some(Value::from("0|0"))])?;
+        assert_eq!(genotypes.to_string(), "DP:GT\t5:0|0");
+
+        let mut genotypes = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE])?,
+            vec![vec![Some(Value::from("0|0")), Some(Value::from(13))]],
+        );
+
+        assert!(matches!(
+            genotypes.add_key(&header, key::READ_DEPTH, vec![Some(Value::from(5))]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
         Ok(())
     }
 
@@ -25,6 +25,31 @@ impl Keys {
     pub fn try_from_str(s: &str, formats: &header::Formats) -> Result<Self, ParseError> {
         parse(s, formats)
     }
+
+    /// Converts a list of keys to VCF record genotypes keys using the given file format.
+    ///
+    /// Unlike `TryFrom<Vec<Key>>`, the genotype key (`GT`) position is validated using the given
+    /// file format, i.e., it may be in any position for VCF 4.4 and later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header::FileFormat,
+    ///     record::genotypes::{keys::key, Keys},
+    /// };
+    ///
+    /// let keys = vec![key::CONDITIONAL_GENOTYPE_QUALITY, key::GENOTYPE];
+    ///
+    /// assert!(Keys::try_from_keys(FileFormat::new(4, 3), keys.clone()).is_err());
+    /// assert!(Keys::try_from_keys(FileFormat::new(4, 4), keys).is_ok());
+    /// ```
+    pub fn try_from_keys(
+        file_format: header::FileFormat,
+        keys: Vec<Key>,
+    ) -> Result<Self, TryFromKeyVectorError> {
+        collect_keys(keys, |i| is_valid_genotype_key_position(file_format, i))
+    }
 }
 
 impl Deref for Keys {
@@ -99,19 +124,35 @@ impl FromStr for Keys {
 }
 
 fn parse(s: &str, formats: &header::Formats) -> Result<Keys, ParseError> {
+    parse_raw_keys(s, formats)
+        .and_then(|keys| Keys::try_from(keys).map_err(ParseError::InvalidFormat))
+}
+
+// Unlike `parse`, this allows the genotype key (`GT`) in any position for VCF 4.4+.
+pub(super) fn parse_with_file_format(
+    s: &str,
+    formats: &header::Formats,
+    file_format: header::FileFormat,
+) -> Result<Keys, ParseError> {
+    parse_raw_keys(s, formats)
+        .and_then(|keys| Keys::try_from_keys(file_format, keys).map_err(ParseError::InvalidFormat))
+}
+
+fn parse_raw_keys(s: &str, formats: &header::Formats) -> Result<Vec<Key>, ParseError> {
     if s.is_empty() {
         Err(ParseError::Empty)
     } else {
         s.split(DELIMITER)
-            .map(
-                |raw_key| match formats.keys().find(|k| k.as_ref() == raw_key) {
-                    Some(k) => Ok(k.clone()),
-                    None => raw_key.parse(),
-                },
-            )
+            .map(|raw_key| parse_key(raw_key, formats))
             .collect::<Result<Vec<_>, _>>()
             .map_err(ParseError::InvalidKey)
-            .and_then(|keys| Keys::try_from(keys).map_err(ParseError::InvalidFormat))
+    }
+}
+
+fn parse_key(raw_key: &str, formats: &header::Formats) -> Result<Key, key::ParseError> {
+    match header::lookup(formats, raw_key) {
+        Some((key, _)) => Ok(key.clone()),
+        None => raw_key.parse(),
     }
 }
 
@@ -139,28 +180,39 @@ impl fmt::Display for TryFromKeyVectorError {
     }
 }
 
+// Before VCF 4.4, the genotype key (`GT`) must be first, if present. VCF 4.4 allows it in any
+// position.
+pub(crate) fn is_valid_genotype_key_position(file_format: header::FileFormat, i: usize) -> bool {
+    i == 0 || file_format >= header::FileFormat::new(4, 4)
+}
+
 impl TryFrom<Vec<Key>> for Keys {
     type Error = TryFromKeyVectorError;
 
     fn try_from(keys: Vec<Key>) -> Result<Self, Self::Error> {
-        if keys.is_empty() {
-            return Ok(Keys::default());
-        } else if let Some(i) = keys.iter().position(|k| k == &key::GENOTYPE) {
-            if i != 0 {
-                return Err(TryFromKeyVectorError::InvalidGenotypeKeyPosition);
-            }
+        collect_keys(keys, |i| i == 0)
+    }
+}
+
+fn collect_keys<F>(keys: Vec<Key>, is_valid_position: F) -> Result<Keys, TryFromKeyVectorError>
+where
+    F: Fn(usize) -> bool,
+{
+    if let Some(i) = keys.iter().position(|k| k == &key::GENOTYPE) {
+        if !is_valid_position(i) {
+            return Err(TryFromKeyVectorError::InvalidGenotypeKeyPosition);
         }
+    }
 
-        let mut set = IndexSet::new();
+    let mut set = IndexSet::new();
 
-        for key in &keys {
-            if !set.insert(key.clone()) {
-                return Err(TryFromKeyVectorError::DuplicateKey(key.clone()));
-            }
+    for key in &keys {
+        if !set.insert(key.clone()) {
+            return Err(TryFromKeyVectorError::DuplicateKey(key.clone()));
         }
-
-        Ok(Self(set))
     }
+
+    Ok(Keys(set))
 }
 
 #[cfg(test)]
@@ -245,4 +297,30 @@ mod tests {
             Err(TryFromKeyVectorError::DuplicateKey(key::GENOTYPE))
         );
     }
+
+    #[test]
+    fn test_try_from_keys() {
+        use crate::header::FileFormat;
+
+        let keys = vec![key::CONDITIONAL_GENOTYPE_QUALITY, key::GENOTYPE];
+
+        assert_eq!(
+            Keys::try_from_keys(FileFormat::new(4, 3), keys.clone()),
+            Err(TryFromKeyVectorError::InvalidGenotypeKeyPosition)
+        );
+
+        assert_eq!(
+            Keys::try_from_keys(FileFormat::new(4, 4), keys),
+            Ok(Keys(
+                [key::CONDITIONAL_GENOTYPE_QUALITY, key::GENOTYPE]
+                    .into_iter()
+                    .collect()
+            ))
+        );
+
+        assert_eq!(
+            Keys::try_from_keys(FileFormat::new(4, 4), vec![key::GENOTYPE, key::GENOTYPE]),
+            Err(TryFromKeyVectorError::DuplicateKey(key::GENOTYPE))
+        );
+    }
 }
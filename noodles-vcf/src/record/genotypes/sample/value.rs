@@ -15,7 +15,7 @@ use crate::{
         },
         Number,
     },
-    record::value::percent_decode,
+    record::value::{percent_decode, Float},
 };
 
 const DELIMITER: char = ',';
@@ -40,7 +40,7 @@ impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Integer(n) => write!(f, "{n}"),
-            Self::Float(n) => write!(f, "{n}"),
+            Self::Float(n) => write!(f, "{}", Float(*n)),
             Self::Character(c) => write!(f, "{c}"),
             Self::String(s) => write!(f, "{s}"),
             Self::Array(array) => write!(f, "{array}"),
@@ -294,6 +294,9 @@ mod tests {
         let value = Value::from(0.333);
         assert_eq!(value.to_string(), "0.333");
 
+        let value = Value::from(0.00001);
+        assert_eq!(value.to_string(), "1e-05");
+
         let value = Value::from('n');
         assert_eq!(value.to_string(), "n");
 
@@ -318,6 +321,9 @@ mod tests {
         let value = Value::from(vec![Some(0.333), None]);
         assert_eq!(value.to_string(), "0.333,.");
 
+        let value = Value::from(vec![Some(1.0), Some(123456789.0)]);
+        assert_eq!(value.to_string(), "1,1.23457e+08");
+
         let value = Value::from(vec![Some('n')]);
         assert_eq!(value.to_string(), "n");
 
@@ -175,10 +175,16 @@ impl Info {
     /// let mut info: Info = [ns].into_iter().collect();
     /// assert_eq!(info.len(), 1);
     ///
-    /// info.insert(key::TOTAL_DEPTH, Some(Value::Integer(13)));
-    ///
+    /// assert!(info.insert(key::TOTAL_DEPTH, Some(Value::Integer(13))).is_none());
     /// assert_eq!(info.len(), 2);
     /// assert_eq!(info.get(&key::TOTAL_DEPTH), Some(Some(&Value::Integer(13))));
+    ///
+    /// assert_eq!(
+    ///     info.insert(key::TOTAL_DEPTH, Some(Value::Integer(8))),
+    ///     Some(Some(Value::Integer(13)))
+    /// );
+    /// assert_eq!(info.len(), 2);
+    /// assert_eq!(info.get(&key::TOTAL_DEPTH), Some(Some(&Value::Integer(8))));
     /// ```
     pub fn insert(
         &mut self,
@@ -395,6 +401,54 @@ mod tests {
         assert_eq!(info, expected);
     }
 
+    #[test]
+    fn test_insert() {
+        let mut info = Info::default();
+
+        assert!(info
+            .insert(key::TOTAL_DEPTH, Some(field::Value::Integer(13)))
+            .is_none());
+        assert_eq!(
+            info.get(&key::TOTAL_DEPTH),
+            Some(Some(&field::Value::Integer(13)))
+        );
+
+        assert_eq!(
+            info.insert(key::TOTAL_DEPTH, Some(field::Value::Integer(8))),
+            Some(Some(field::Value::Integer(13)))
+        );
+        assert_eq!(
+            info.get(&key::TOTAL_DEPTH),
+            Some(Some(&field::Value::Integer(8)))
+        );
+
+        assert_eq!(
+            info.insert(key::TOTAL_DEPTH, None),
+            Some(Some(field::Value::Integer(8)))
+        );
+        assert_eq!(info.get(&key::TOTAL_DEPTH), Some(None));
+
+        assert_eq!(info.len(), 1);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut info: Info = [(key::TOTAL_DEPTH, Some(field::Value::Integer(13)))]
+            .into_iter()
+            .collect();
+
+        if let Some(Some(field::Value::Integer(n))) = info.get_mut(&key::TOTAL_DEPTH) {
+            *n += 8;
+        }
+
+        assert_eq!(
+            info.get(&key::TOTAL_DEPTH),
+            Some(Some(&field::Value::Integer(21)))
+        );
+
+        assert!(info.get_mut(&key::ALLELE_FREQUENCIES).is_none());
+    }
+
     #[test]
     fn test_from_str() -> Result<(), ParseError> {
         let actual: Info = "NS=2".parse()?;
@@ -15,7 +15,7 @@ use crate::{
         },
         Number,
     },
-    record::value::percent_decode,
+    record::value::{percent_decode, Float},
 };
 
 const DELIMITER: char = ',';
@@ -41,7 +41,7 @@ impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Integer(n) => write!(f, "{n}"),
-            Self::Float(n) => write!(f, "{n}"),
+            Self::Float(n) => write!(f, "{}", Float(*n)),
             Self::Flag => Ok(()),
             Self::Character(c) => write!(f, "{c}"),
             Self::String(s) => write!(f, "{s}"),
@@ -299,6 +299,9 @@ mod tests {
         let value = Value::from(0.333);
         assert_eq!(value.to_string(), "0.333");
 
+        let value = Value::from(0.00001);
+        assert_eq!(value.to_string(), "1e-05");
+
         assert_eq!(Value::Flag.to_string(), "");
 
         let value = Value::from('n');
@@ -325,6 +328,9 @@ mod tests {
         let value = Value::from(vec![Some(0.333), None]);
         assert_eq!(value.to_string(), "0.333,.");
 
+        let value = Value::from(vec![Some(1.0), Some(123456789.0)]);
+        assert_eq!(value.to_string(), "1,1.23457e+08");
+
         let value = Value::from(vec![Some('n')]);
         assert_eq!(value.to_string(), "n");
 
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use super::{DELIMITER, MISSING_VALUE};
+use crate::record::value::Float;
 
 /// A VCF record info field array value.
 #[derive(Clone, Debug, PartialEq)]
@@ -40,7 +41,7 @@ impl fmt::Display for Array {
                     }
 
                     if let Some(v) = value {
-                        write!(f, "{v}")?;
+                        write!(f, "{}", Float(*v))?;
                     } else {
                         f.write_str(MISSING_VALUE)?;
                     }
@@ -0,0 +1,156 @@
+use std::{error, fmt};
+
+use super::{
+    info::field::{Key, Value},
+    Record,
+};
+use crate::{header::Number, Header};
+
+/// An error returned when the number of values of a VCF record info field is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InfoCardinalityError {
+    /// The number of values does not match the number of alleles.
+    InvalidValueCount {
+        /// The info field key.
+        key: Key,
+        /// The number declared for the field.
+        number: Number,
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+}
+
+impl error::Error for InfoCardinalityError {}
+
+impl fmt::Display for InfoCardinalityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValueCount {
+                key,
+                number,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid value count for {key} (Number={number}): expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+pub(super) fn validate_info_cardinality(
+    header: &Header,
+    record: &Record,
+) -> Result<(), InfoCardinalityError> {
+    use crate::header::record::value::map::info::definition::definition;
+
+    let alternate_allele_count = record.alternate_bases().len();
+
+    for (key, value) in record.info().as_ref() {
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+
+        let number = match header
+            .infos()
+            .get(key)
+            .map(|info| info.number())
+            .or_else(|| definition(header.file_format(), key).map(|(n, _, _)| n))
+        {
+            Some(number) => number,
+            None => continue,
+        };
+
+        let expected = match number {
+            Number::A => alternate_allele_count,
+            Number::R => alternate_allele_count + 1,
+            // INFO fields have no sample ploidy, so genotypes are assumed to be diploid.
+            Number::G => (alternate_allele_count + 1) * (alternate_allele_count + 2) / 2,
+            Number::Count(_) | Number::Unknown => continue,
+        };
+
+        let actual = value_count(value);
+
+        if actual != expected {
+            return Err(InfoCardinalityError::InvalidValueCount {
+                key: key.clone(),
+                number,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn value_count(value: &Value) -> usize {
+    use super::info::field::value::Array;
+
+    match value {
+        Value::Flag => 0,
+        Value::Integer(_) | Value::Float(_) | Value::Character(_) | Value::String(_) => 1,
+        Value::Array(Array::Integer(values)) => values.len(),
+        Value::Array(Array::Float(values)) => values.len(),
+        Value::Array(Array::Character(values)) => values.len(),
+        Value::Array(Array::String(values)) => values.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::record::value::{map::Info, Map},
+        record::{info::field::key, Position},
+    };
+
+    fn build_header() -> Header {
+        Header::builder()
+            .add_info(
+                key::ALLELE_FREQUENCIES,
+                Map::<Info>::from(&key::ALLELE_FREQUENCIES),
+            )
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+            .build()
+    }
+
+    fn build_record(header: &Header, info: &str) -> Result<Record, Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_alternate_bases("C,G".parse()?)
+            .set_info(super::super::Info::try_from_str(info, header.infos())?)
+            .build()?;
+
+        Ok(record)
+    }
+
+    #[test]
+    fn test_validate_info_cardinality() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let record = build_record(&header, "AF=0.25,0.5;DP=8")?;
+        assert!(validate_info_cardinality(&header, &record).is_ok());
+
+        let record = build_record(&header, "AF=.")?;
+        assert!(validate_info_cardinality(&header, &record).is_ok());
+
+        let record = build_record(&header, "AF=0.25")?;
+        assert_eq!(
+            validate_info_cardinality(&header, &record),
+            Err(InfoCardinalityError::InvalidValueCount {
+                key: key::ALLELE_FREQUENCIES,
+                number: Number::A,
+                expected: 2,
+                actual: 1,
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+use std::io;
+
+use super::{
+    alternate_bases::Allele, reference_bases::Base, AlternateBases, Position, Record,
+    ReferenceBases,
+};
+
+pub(super) fn normalize(record: &mut Record, reference_sequence: &[u8]) -> io::Result<()> {
+    let mut alleles = Vec::with_capacity(record.alternate_bases().len() + 1);
+    alleles.push(record.reference_bases().to_vec());
+
+    for allele in record.alternate_bases().iter() {
+        match allele {
+            Allele::Bases(bases) => alleles.push(bases.clone()),
+            _ => return Ok(()),
+        }
+    }
+
+    if alleles.len() < 2
+        || alleles.iter().any(|bases| bases.is_empty())
+        || alleles[1..].iter().any(|bases| bases == &alleles[0])
+    {
+        return Ok(());
+    }
+
+    let mut position = usize::from(record.position());
+
+    let reference_bases = position
+        .checked_sub(1)
+        .and_then(|start| {
+            let end = start.checked_add(alleles[0].len())?;
+            reference_sequence.get(start..end)
+        })
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "record position is out of bounds of the reference sequence",
+            )
+        })?;
+
+    for (&expected, &actual) in reference_bases.iter().zip(&alleles[0]) {
+        if parse_base(expected)? != actual {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "record reference bases do not match the reference sequence",
+            ));
+        }
+    }
+
+    loop {
+        if alleles.iter().any(|bases| bases.is_empty()) {
+            // `position` is guaranteed to be > 1.
+            position -= 1;
+            let base = parse_base(reference_sequence[position - 1])?;
+
+            for bases in &mut alleles {
+                bases.insert(0, base);
+            }
+        } else if has_common_last_base(&alleles) {
+            if position == 1 && alleles.iter().any(|bases| bases.len() == 1) {
+                break;
+            }
+
+            for bases in &mut alleles {
+                bases.pop();
+            }
+        } else {
+            break;
+        }
+    }
+
+    while alleles.iter().all(|bases| bases.len() > 1) && has_common_first_base(&alleles) {
+        for bases in &mut alleles {
+            bases.remove(0);
+        }
+
+        position += 1;
+    }
+
+    let mut alleles = alleles.into_iter();
+
+    // SAFETY: `alleles` is guaranteed to be non-empty, and each allele is non-empty.
+    *record.reference_bases_mut() = ReferenceBases::try_from(alleles.next().unwrap()).unwrap();
+    *record.alternate_bases_mut() =
+        AlternateBases::from(alleles.map(Allele::Bases).collect::<Vec<_>>());
+    *record.position_mut() = Position::from(position);
+
+    Ok(())
+}
+
+fn parse_base(b: u8) -> io::Result<Base> {
+    Base::try_from(char::from(b.to_ascii_uppercase()))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn has_common_first_base(alleles: &[Vec<Base>]) -> bool {
+    let first_base = alleles[0][0];
+    alleles.iter().all(|bases| bases[0] == first_base)
+}
+
+fn has_common_last_base(alleles: &[Vec<Base>]) -> bool {
+    let last_base = alleles[0][alleles[0].len() - 1];
+    alleles.iter().all(|bases| bases.last() == Some(&last_base))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(
+        position: usize,
+        reference_bases: &str,
+        alternate_bases: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(position))
+            .set_reference_bases(reference_bases.parse()?)
+            .set_alternate_bases(alternate_bases.parse()?)
+            .build()?;
+
+        Ok(record)
+    }
+
+    #[test]
+    fn test_normalize() -> Result<(), Box<dyn std::error::Error>> {
+        const REFERENCE_SEQUENCE: &[u8] = b"TCAAAAGT";
+
+        // single-nucleotide variant
+        let mut record = build_record(3, "A", "G")?;
+        normalize(&mut record, REFERENCE_SEQUENCE)?;
+        assert_eq!(record, build_record(3, "A", "G")?);
+
+        // right-shifted deletion
+        let mut record = build_record(5, "AA", "A")?;
+        normalize(&mut record, REFERENCE_SEQUENCE)?;
+        assert_eq!(record, build_record(2, "CA", "C")?);
+
+        // common suffix
+        let mut record = build_record(2, "CAAAAG", "CAAAG")?;
+        normalize(&mut record, REFERENCE_SEQUENCE)?;
+        assert_eq!(record, build_record(2, "CA", "C")?);
+
+        // common prefix
+        let mut record = build_record(6, "AG", "AC")?;
+        normalize(&mut record, REFERENCE_SEQUENCE)?;
+        assert_eq!(record, build_record(7, "G", "C")?);
+
+        // multiallelic
+        let mut record = build_record(5, "AA", "A,AAA")?;
+        normalize(&mut record, REFERENCE_SEQUENCE)?;
+        assert_eq!(record, build_record(2, "CA", "C,CAA")?);
+
+        // lowercase reference sequence
+        let mut record = build_record(5, "AA", "A")?;
+        normalize(&mut record, b"tcaaaagt")?;
+        assert_eq!(record, build_record(2, "CA", "C")?);
+
+        // deletion at the start of the reference sequence
+        let mut record = build_record(2, "AC", "C")?;
+        normalize(&mut record, b"AAC")?;
+        assert_eq!(record, build_record(1, "AA", "A")?);
+
+        // symbolic allele
+        let mut record = build_record(5, "A", "<DEL>")?;
+        normalize(&mut record, REFERENCE_SEQUENCE)?;
+        assert_eq!(record, build_record(5, "A", "<DEL>")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_with_invalid_reference_bases() -> Result<(), Box<dyn std::error::Error>> {
+        const REFERENCE_SEQUENCE: &[u8] = b"TCAAAAGT";
+
+        let mut record = build_record(1, "CA", "C")?;
+        assert!(matches!(
+            normalize(&mut record, REFERENCE_SEQUENCE),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut record = build_record(8, "TA", "T")?;
+        assert!(matches!(
+            normalize(&mut record, REFERENCE_SEQUENCE),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 use super::Record;
-use crate::{reader::record::ParseError, Header};
+use crate::{
+    reader::record::{ParseError, ParseOptions},
+    Header,
+};
 
 pub fn parse(s: &str, header: &Header) -> Result<Record, ParseError> {
     use crate::reader::parse_record;
@@ -9,6 +12,18 @@ pub fn parse(s: &str, header: &Header) -> Result<Record, ParseError> {
     Ok(record)
 }
 
+pub fn parse_with_options(
+    s: &str,
+    header: &Header,
+    options: ParseOptions,
+) -> Result<Record, ParseError> {
+    use crate::reader::parse_record_with_options;
+
+    let mut record = Record::default();
+    parse_record_with_options(s, header, &mut record, options)?;
+    Ok(record)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,6 +57,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_with_filters() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Filters;
+
+        let header = Header::default();
+
+        let record = parse("sq0\t1\t.\tA\t.\t.\tPASS\t.", &header)?;
+        assert_eq!(record.filters(), Some(&Filters::Pass));
+
+        let record = parse("sq0\t1\t.\tA\t.\t.\tq10;s50\t.", &header)?;
+        assert_eq!(record.filters(), Some(&"q10;s50".parse()?));
+
+        let record = parse("sq0\t1\t.\tA\t.\t.\t.\t.", &header)?;
+        assert!(record.filters().is_none());
+
+        assert!(matches!(
+            parse("sq0\t1\t.\tA\t.\t.\tPASS;q10\t.", &header),
+            Err(ParseError::InvalidFilters(_))
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_with_genotype_info() -> Result<(), Box<dyn std::error::Error>> {
         use crate::record::{
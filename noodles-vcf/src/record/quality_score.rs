@@ -19,6 +19,10 @@ impl fmt::Display for QualityScore {
 pub enum TryFromFloatError {
     /// The input is negative.
     Negative,
+    /// The input is NaN.
+    Nan,
+    /// The input is infinite.
+    Infinite,
 }
 
 impl error::Error for TryFromFloatError {}
@@ -27,6 +31,8 @@ impl fmt::Display for TryFromFloatError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Negative => f.write_str("negative value"),
+            Self::Nan => f.write_str("NaN"),
+            Self::Infinite => f.write_str("infinite value"),
         }
     }
 }
@@ -35,7 +41,11 @@ impl TryFrom<f32> for QualityScore {
     type Error = TryFromFloatError;
 
     fn try_from(value: f32) -> Result<Self, Self::Error> {
-        if value < MIN {
+        if value.is_nan() {
+            Err(TryFromFloatError::Nan)
+        } else if value.is_infinite() {
+            Err(TryFromFloatError::Infinite)
+        } else if value < MIN {
             Err(TryFromFloatError::Negative)
         } else {
             Ok(Self(value))
@@ -113,12 +123,23 @@ mod tests {
             QualityScore::try_from(-8.0),
             Err(TryFromFloatError::Negative)
         );
+        assert_eq!(
+            QualityScore::try_from(f32::NAN),
+            Err(TryFromFloatError::Nan)
+        );
+        assert_eq!(
+            QualityScore::try_from(f32::INFINITY),
+            Err(TryFromFloatError::Infinite)
+        );
+        assert_eq!(
+            QualityScore::try_from(f32::NEG_INFINITY),
+            Err(TryFromFloatError::Infinite)
+        );
     }
 
     #[test]
     fn test_from_str() {
         assert_eq!("5.8".parse(), Ok(QualityScore(5.8)));
-        assert_eq!("Infinity".parse(), Ok(QualityScore(f32::INFINITY)));
 
         assert_eq!("".parse::<QualityScore>(), Err(ParseError::Empty));
         assert!(matches!(
@@ -133,6 +154,14 @@ mod tests {
             "-8.5".parse::<QualityScore>(),
             Err(ParseError::InvalidValue(_))
         ));
+        assert_eq!(
+            "NaN".parse::<QualityScore>(),
+            Err(ParseError::InvalidValue(TryFromFloatError::Nan))
+        );
+        assert_eq!(
+            "Infinity".parse::<QualityScore>(),
+            Err(ParseError::InvalidValue(TryFromFloatError::Infinite))
+        );
     }
 
     #[test]
@@ -1,4 +1,8 @@
-use std::{borrow::Cow, str};
+mod float;
+
+pub(crate) use self::float::Float;
+
+use std::{borrow::Cow, fmt::Write, str};
 
 use percent_encoding::percent_decode_str;
 
@@ -6,6 +10,32 @@ pub(crate) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
     percent_decode_str(s).decode_utf8()
 }
 
+// § 1.2 "Character encoding, non-printable characters and characters with special meaning"
+// (2021-01-13). `:` only has a special meaning in the genotypes fields, so it is not encoded in
+// INFO values. Other characters, including non-ASCII characters, are written as is.
+pub(crate) fn percent_encode(s: &str) -> Cow<'_, str> {
+    fn is_reserved(c: char) -> bool {
+        c.is_ascii_control() || matches!(c, '%' | ',' | ';' | '=')
+    }
+
+    if !s.contains(is_reserved) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        if is_reserved(c) {
+            // SAFETY: Writing to a `String` cannot fail.
+            write!(buf, "%{:02X}", u32::from(c)).unwrap();
+        } else {
+            buf.push(c);
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,4 +46,12 @@ mod tests {
         assert_eq!(percent_decode("noodles%3Dvcf")?, "noodles=vcf");
         Ok(())
     }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("noodles"), "noodles");
+        assert_eq!(percent_encode("noodles=vcf"), "noodles%3Dvcf");
+        assert_eq!(percent_encode("a;b,c:d%e\tf"), "a%3Bb%2Cc:d%25e%09f");
+        assert_eq!(percent_encode("ñ=ü"), "ñ%3Dü");
+    }
 }
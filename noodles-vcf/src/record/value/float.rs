@@ -0,0 +1,79 @@
+use std::fmt;
+
+// The number of significant digits htslib writes for floating-point values, i.e., `%g`.
+const PRECISION: usize = 6;
+
+/// A floating-point value formatted like htslib.
+///
+/// This matches the default formatting of htslib (and, consequently, bcftools), which is
+/// equivalent to C's `%g`: up to 6 significant digits with trailing zeros removed, using scientific
+/// notation for exponents < -4 or >= 6.
+pub(crate) struct Float(pub(crate) f32);
+
+impl fmt::Display for Float {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.0;
+
+        if !n.is_finite() {
+            return write!(f, "{n}");
+        }
+
+        let s = format!("{:.*e}", PRECISION - 1, n);
+
+        // `LowerExp` always writes an exponent, which is always a valid integer.
+        let (mantissa, raw_exponent) = s.split_once('e').expect("missing exponent");
+        let exponent: i32 = raw_exponent.parse().expect("invalid exponent");
+
+        if exponent < -4 || exponent >= PRECISION as i32 {
+            let sign = if exponent < 0 { '-' } else { '+' };
+            let mantissa = trim_fractional_zeros(mantissa);
+            write!(f, "{mantissa}e{sign}{:02}", exponent.abs())
+        } else {
+            let precision = (PRECISION as i32 - 1 - exponent) as usize;
+            let s = format!("{n:.precision$}");
+            f.write_str(trim_fractional_zeros(&s))
+        }
+    }
+}
+
+fn trim_fractional_zeros(s: &str) -> &str {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        fn t(n: f32, expected: &str) {
+            assert_eq!(Float(n).to_string(), expected);
+        }
+
+        t(0.0, "0");
+        t(-0.0, "-0");
+        t(1.0, "1");
+        t(-2.5, "-2.5");
+        t(0.333, "0.333");
+        t(8.13, "8.13");
+        t(1.0 / 3.0, "0.333333");
+        t(0.0001, "0.0001");
+        t(100000.0, "100000");
+        t(999999.5, "1e+06");
+
+        t(0.00001, "1e-05");
+        t(1e-10, "1e-10");
+        t(0.000012345678, "1.23457e-05");
+        t(1000000.0, "1e+06");
+        t(123456789.0, "1.23457e+08");
+        t(1e20, "1e+20");
+        t(f32::MAX, "3.40282e+38");
+
+        t(f32::NAN, "NaN");
+        t(f32::INFINITY, "inf");
+    }
+}
@@ -2,6 +2,9 @@ mod record;
 
 use std::io::{self, Write};
 
+#[cfg(feature = "async")]
+pub(crate) use self::record::write_unvalidated_record;
+
 use self::record::write_record;
 use super::{Header, Record, VariantWriter};
 
@@ -102,8 +105,35 @@ where
         self.inner
     }
 
+    /// Flushes the underlying writer.
+    ///
+    /// The writer does not buffer any data itself, i.e., every header and record is written
+    /// directly to the underlying writer. This is only needed when the underlying writer is
+    /// buffered, e.g., a [`std::io::BufWriter`], to ensure all data is written and to handle
+    /// errors that would otherwise be ignored when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, BufWriter};
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::Writer::new(BufWriter::new(Vec::new()));
+    /// writer.write_header(&vcf::Header::default())?;
+    /// writer.flush()?;
+    ///
+    /// assert!(writer.get_ref().get_ref().starts_with(b"##fileformat=VCFv4.4\n"));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
     /// Writes a VCF header.
     ///
+    /// Sample names are written as columns of the header header (`#CHROM`...). This fails with
+    /// an [`io::ErrorKind::InvalidInput`] error if a sample name contains a tab or newline.
+    ///
     /// # Examples
     ///
     /// ```
@@ -117,11 +147,16 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        validate_sample_names(header)?;
         write!(self.inner, "{header}")
     }
 
     /// Writes a VCF record.
     ///
+    /// Info field values are checked against the types of their definitions in the header. An
+    /// integer value is written as is for a `Float` field, but other mismatched types, e.g., a
+    /// string value for an `Integer` field, fail with an [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -139,8 +174,8 @@ where
     /// writer.write_record(&header, &record)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn write_record(&mut self, _: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, record)
+    pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
+        write_record(&mut self.inner, header, record)
     }
 }
 
@@ -157,6 +192,20 @@ where
     }
 }
 
+// Sample names are tab-delimited columns of the header header (`#CHROM`...).
+pub(crate) fn validate_sample_names(header: &Header) -> io::Result<()> {
+    for sample_name in header.sample_names() {
+        if sample_name.contains(['\t', '\n', '\r']) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid sample name: {sample_name:?}"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +227,116 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_header_with_parsed_pass_filter() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(src: &str, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+            let header: Header = src.parse()?;
+
+            let mut writer = Writer::new(Vec::new());
+            writer.write_header(&header)?;
+
+            assert_eq!(writer.get_ref().as_slice(), expected);
+
+            Ok(())
+        }
+
+        // implicit
+        t(
+            "##fileformat=VCFv4.3
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+",
+            b"##fileformat=VCFv4.3
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+",
+        )?;
+
+        // explicit
+        t(
+            "##fileformat=VCFv4.3
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##FILTER=<ID=PASS,Description=\"All calls passed\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+",
+            b"##fileformat=VCFv4.3
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##FILTER=<ID=PASS,Description=\"All calls passed\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+",
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flush() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::BufWriter;
+
+        let header = Header::default();
+
+        let records = [
+            Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::from(1))
+                .set_reference_bases("A".parse()?)
+                .build()?,
+            Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::from(8))
+                .set_reference_bases("C".parse()?)
+                .build()?,
+        ];
+
+        let mut expected_writer = Writer::new(Vec::new());
+        expected_writer.write_header(&header)?;
+
+        for record in &records {
+            expected_writer.write_record(&header, record)?;
+        }
+
+        let expected = expected_writer.into_inner();
+
+        let mut writer = Writer::new(BufWriter::with_capacity(1 << 16, Vec::new()));
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_record(&header, record)?;
+        }
+
+        assert!(writer.get_ref().get_ref().is_empty());
+
+        writer.flush()?;
+        assert_eq!(writer.get_ref().get_ref(), &expected);
+
+        writer.get_mut().get_mut().clear();
+        writer.write_record(&header, &records[0])?;
+        writer.flush()?;
+        assert_eq!(writer.get_ref().get_ref(), b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_header_with_invalid_sample_name() {
+        let t = |sample_name: &str| {
+            let header = Header::builder().add_sample_name(sample_name).build();
+
+            let mut writer = Writer::new(Vec::new());
+
+            assert!(matches!(
+                writer.write_header(&header),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+
+            assert!(writer.get_ref().is_empty());
+        };
+
+        t("sample\t0");
+        t("sample\n0");
+        t("sample\r0");
+    }
+
     #[test]
     fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
         let header = Header::default();
@@ -197,6 +356,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_record_with_overlapping_deletion() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::alternate_bases::Allele;
+
+        let header = Header::default();
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_alternate_bases("C,*".parse()?)
+            .build()?;
+
+        assert!(matches!(
+            record.alternate_bases().get(1),
+            Some(Allele::OverlappingDeletion)
+        ));
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&header, &record)?;
+
+        let expected = b"sq0\t1\t.\tA\tC,*\t.\t.\t.\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        let mut reader = crate::Reader::new(&writer.get_ref()[..]);
+        let mut actual = Record::default();
+        reader.read_record(&header, &mut actual)?;
+        assert_eq!(actual, record);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_record_with_format() -> Result<(), Box<dyn std::error::Error>> {
         use crate::record::{
@@ -229,4 +420,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_floats() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::QualityScore;
+
+        let header = Header::default();
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_quality_score(QualityScore::try_from(1234567.0)?)
+            .set_info("AF=0.00001".parse()?)
+            .set_genotypes("GT:GL\t0|0:-0.5,-1.0,-123456789.0".parse()?)
+            .build()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&header, &record)?;
+
+        let expected =
+            "sq0\t1\t.\tA\t.\t1.23457e+06\t.\tAF=1e-05\tGT:GL\t0|0:-0.5,-1,-1.23457e+08\n";
+        assert_eq!(writer.get_ref(), expected.as_bytes());
+
+        assert_eq!(format!("{record}\n"), expected);
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 mod chromosome;
 mod filters;
+mod float;
 mod genotypes;
 mod ids;
 mod info;
@@ -8,14 +9,29 @@ mod quality_score;
 use std::io::{self, Write};
 
 use self::{
-    chromosome::write_chromosome, filters::write_filters, genotypes::write_genotypes,
-    ids::write_ids, info::write_info, quality_score::write_quality_score,
+    chromosome::write_chromosome,
+    filters::write_filters,
+    genotypes::write_genotypes,
+    ids::write_ids,
+    info::{validate_info, write_info},
+    quality_score::write_quality_score,
 };
-use crate::Record;
+use crate::{Header, Record};
 
 const MISSING: &[u8] = b".";
 
-pub(super) fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
+pub(super) fn write_record<W>(writer: &mut W, header: &Header, record: &Record) -> io::Result<()>
+where
+    W: Write,
+{
+    // Validate fields that depend on the header before writing anything, so that an invalid
+    // record does not leave a partial line.
+    validate_info(header, record.info())?;
+    write_unvalidated_record(writer, record)
+}
+
+// Writes a record without validating it against a header.
+pub(crate) fn write_unvalidated_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
 where
     W: Write,
 {
@@ -73,9 +89,78 @@ mod tests {
             .build()?;
 
         let mut buf = Vec::new();
-        write_record(&mut buf, &record)?;
+        write_record(&mut buf, &Header::default(), &record)?;
         assert_eq!(buf, b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_filters() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Filters;
+
+        fn t(filters: Option<Filters>, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+            let mut builder = Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::from(1))
+                .set_reference_bases("A".parse()?);
+
+            if let Some(filters) = filters {
+                builder = builder.set_filters(filters);
+            }
+
+            let record = builder.build()?;
+
+            let mut buf = Vec::new();
+            write_record(&mut buf, &Header::default(), &record)?;
+            assert_eq!(buf, expected);
+
+            Ok(())
+        }
+
+        t(None, b"sq0\t1\t.\tA\t.\t.\t.\t.\n")?;
+        t(Some(Filters::Pass), b"sq0\t1\t.\tA\t.\t.\tPASS\t.\n")?;
+        t(
+            Some(Filters::try_from_iter(["s50", "q10"])?),
+            b"sq0\t1\t.\tA\t.\t.\ts50;q10\t.\n",
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_info_value_type_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{map, Map},
+            record::info::field::{key, Value},
+        };
+
+        let header = Header::builder()
+            .add_info(
+                key::SAMPLES_WITH_DATA_COUNT,
+                Map::<map::Info>::from(&key::SAMPLES_WITH_DATA_COUNT),
+            )
+            .build();
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_info(
+                [(key::SAMPLES_WITH_DATA_COUNT, Some(Value::from("two")))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()?;
+
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            write_record(&mut buf, &header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
 }
@@ -1,11 +1,20 @@
 use std::io::{self, Write};
 
-use crate::record::Chromosome;
+use crate::record::{chromosome::is_valid_name, Chromosome};
 
 pub(super) fn write_chromosome<W>(writer: &mut W, chromosome: &Chromosome) -> io::Result<()>
 where
     W: Write,
 {
+    let (Chromosome::Name(s) | Chromosome::Symbol(s)) = chromosome;
+
+    if !is_valid_name(s) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid chromosome: {chromosome}"),
+        ));
+    }
+
     match chromosome {
         Chromosome::Name(name) => writer.write_all(name.as_bytes())?,
         Chromosome::Symbol(symbol) => {
@@ -39,6 +48,18 @@ mod tests {
         let chromosome = "<sq0>".parse()?;
         t(&mut buf, &chromosome, b"<sq0>")?;
 
+        for chromosome in [
+            Chromosome::Name(String::new()),
+            Chromosome::Name(String::from("sq 0")),
+            Chromosome::Symbol(String::from("sq\t0")),
+        ] {
+            buf.clear();
+            assert!(matches!(
+                write_chromosome(&mut buf, &chromosome),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
         Ok(())
     }
 }
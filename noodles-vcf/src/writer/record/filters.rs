@@ -10,21 +10,20 @@ where
     const DELIMITER: &[u8] = b";";
     const PASS: &[u8] = b"PASS";
 
-    if let Some(filters) = filters {
-        match filters {
-            Filters::Pass => writer.write_all(PASS)?,
-            Filters::Fail(ids) => {
-                for (i, id) in ids.iter().enumerate() {
-                    if i > 0 {
-                        writer.write_all(DELIMITER)?;
-                    }
-
-                    writer.write_all(id.as_bytes())?;
+    // Failed filters are written in insertion order. An empty set of failed filters is treated as
+    // missing.
+    match filters {
+        Some(Filters::Pass) => writer.write_all(PASS)?,
+        Some(Filters::Fail(ids)) if !ids.is_empty() => {
+            for (i, id) in ids.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(DELIMITER)?;
                 }
+
+                writer.write_all(id.as_bytes())?;
             }
         }
-    } else {
-        writer.write_all(MISSING)?;
+        _ => writer.write_all(MISSING)?,
     }
 
     Ok(())
@@ -54,6 +53,11 @@ mod tests {
         let filters = Filters::try_from_iter(["q10", "s50"])?;
         t(&mut buf, Some(&filters), b"q10;s50")?;
 
+        let filters = Filters::try_from_iter(["s50", "q10", "dp5"])?;
+        t(&mut buf, Some(&filters), b"s50;q10;dp5")?;
+
+        t(&mut buf, Some(&Filters::Fail(Default::default())), b".")?;
+
         Ok(())
     }
 }
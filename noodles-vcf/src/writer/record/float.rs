@@ -0,0 +1,38 @@
+use std::io::{self, Write};
+
+use crate::record::value::Float;
+
+/// Writes a floating-point value.
+///
+/// See [`Float`] for how the value is formatted.
+pub(super) fn write_float<W>(writer: &mut W, n: f32) -> io::Result<()>
+where
+    W: Write,
+{
+    write!(writer, "{}", Float(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_float() -> io::Result<()> {
+        fn t(buf: &mut Vec<u8>, n: f32, expected: &str) -> io::Result<()> {
+            buf.clear();
+            write_float(buf, n)?;
+            assert_eq!(buf, expected.as_bytes());
+            Ok(())
+        }
+
+        let mut buf = Vec::new();
+
+        t(&mut buf, 1.0, "1")?;
+        t(&mut buf, 0.333, "0.333")?;
+        t(&mut buf, 0.00001, "1e-05")?;
+        t(&mut buf, 123456789.0, "1.23457e+08")?;
+        t(&mut buf, f32::NAN, "NaN")?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use std::io::{self, Write};
 
-use super::MISSING;
+use super::{float::write_float, MISSING};
 use crate::record::{
     genotypes::{
         sample::{value::Array, Value},
@@ -70,7 +70,7 @@ where
 
     match value {
         Value::Integer(n) => write!(writer, "{n}"),
-        Value::Float(n) => write!(writer, "{n}"),
+        Value::Float(n) => write_float(writer, *n),
         Value::Character(c) => write!(writer, "{c}"),
         Value::String(s) => writer.write_all(s.as_bytes()),
         Value::Array(Array::Integer(values)) => {
@@ -95,7 +95,7 @@ where
                 }
 
                 if let Some(n) = v {
-                    write!(writer, "{n}")?;
+                    write_float(writer, *n)?;
                 } else {
                     writer.write_all(MISSING)?;
                 }
@@ -184,6 +184,8 @@ mod tests {
 
         t(&mut buf, &Value::from(8), b"8")?;
         t(&mut buf, &Value::from(0.333), b"0.333")?;
+        t(&mut buf, &Value::from(1.0), b"1")?;
+        t(&mut buf, &Value::from(123456789.0), b"1.23457e+08")?;
         t(&mut buf, &Value::from('n'), b"n")?;
         t(&mut buf, &Value::from("noodles"), b"noodles")?;
 
@@ -1,9 +1,14 @@
 use std::io::{self, Write};
 
-use super::MISSING;
-use crate::record::{
-    info::field::{value::Array, Value},
-    Info,
+use super::{float::write_float, MISSING};
+use crate::{
+    header::record::value::map::info::Type,
+    record::{
+        info::field::{value::Array, Key, Value},
+        value::percent_encode,
+        Info,
+    },
+    Header,
 };
 
 pub(super) fn write_info<W>(writer: &mut W, info: &Info) -> io::Result<()>
@@ -40,6 +45,63 @@ where
     Ok(())
 }
 
+pub(super) fn validate_info(header: &Header, info: &Info) -> io::Result<()> {
+    for (key, value) in info.as_ref() {
+        if let Some(v) = value {
+            validate_value_type(header, key, v)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Integers are written as is for `Float` fields, as they are valid floating-point values.
+fn validate_value_type(header: &Header, key: &Key, value: &Value) -> io::Result<()> {
+    use crate::header::record::value::map::info::definition::definition;
+
+    let ty = match header
+        .infos()
+        .get(key)
+        .map(|info| info.ty())
+        .or_else(|| definition(header.file_format(), key).map(|(_, t, _)| t))
+    {
+        Some(ty) => ty,
+        None => return Ok(()),
+    };
+
+    let is_compatible = match ty {
+        Type::Integer => matches!(value, Value::Integer(_) | Value::Array(Array::Integer(_))),
+        Type::Float => matches!(
+            value,
+            Value::Integer(_)
+                | Value::Float(_)
+                | Value::Array(Array::Integer(_))
+                | Value::Array(Array::Float(_))
+        ),
+        Type::Flag => matches!(value, Value::Flag),
+        Type::Character => matches!(
+            value,
+            Value::Character(_) | Value::Array(Array::Character(_))
+        ),
+        Type::String => matches!(
+            value,
+            Value::Character(_)
+                | Value::String(_)
+                | Value::Array(Array::Character(_))
+                | Value::Array(Array::String(_))
+        ),
+    };
+
+    if is_compatible {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid info field value type for {key}: expected {ty}, got {value:?}"),
+        ))
+    }
+}
+
 fn write_value<W>(writer: &mut W, value: &Value) -> io::Result<()>
 where
     W: Write,
@@ -48,10 +110,10 @@ where
 
     match value {
         Value::Integer(n) => write!(writer, "{n}"),
-        Value::Float(n) => write!(writer, "{n}"),
+        Value::Float(n) => write_float(writer, *n),
         Value::Flag => Ok(()),
-        Value::Character(c) => write!(writer, "{c}"),
-        Value::String(s) => writer.write_all(s.as_bytes()),
+        Value::Character(c) => write_char(writer, *c),
+        Value::String(s) => write_string(writer, s),
         Value::Array(Array::Integer(values)) => {
             for (i, v) in values.iter().enumerate() {
                 if i > 0 {
@@ -74,7 +136,7 @@ where
                 }
 
                 if let Some(n) = v {
-                    write!(writer, "{n}")?;
+                    write_float(writer, *n)?;
                 } else {
                     writer.write_all(MISSING)?;
                 }
@@ -89,7 +151,7 @@ where
                 }
 
                 if let Some(c) = v {
-                    write!(writer, "{c}")?;
+                    write_char(writer, *c)?;
                 } else {
                     writer.write_all(MISSING)?;
                 }
@@ -104,7 +166,7 @@ where
                 }
 
                 if let Some(s) = v {
-                    writer.write_all(s.as_bytes())?;
+                    write_string(writer, s)?;
                 } else {
                     writer.write_all(MISSING)?;
                 }
@@ -115,6 +177,21 @@ where
     }
 }
 
+fn write_char<W>(writer: &mut W, c: char) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut buf = [0; 4];
+    write_string(writer, c.encode_utf8(&mut buf))
+}
+
+fn write_string<W>(writer: &mut W, s: &str) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_all(percent_encode(s).as_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +228,182 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_value() -> io::Result<()> {
+        fn t(buf: &mut Vec<u8>, value: &Value, expected: &[u8]) -> io::Result<()> {
+            buf.clear();
+            write_value(buf, value)?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        let mut buf = Vec::new();
+
+        t(&mut buf, &Value::from(1.0), b"1")?;
+        t(
+            &mut buf,
+            &Value::from(vec![Some(0.333), Some(1e-10)]),
+            b"0.333,1e-10",
+        )?;
+        t(&mut buf, &Value::from('n'), b"n")?;
+        t(&mut buf, &Value::from(';'), b"%3B")?;
+        t(&mut buf, &Value::from(vec![Some(','), None]), b"%2C,.")?;
+        t(&mut buf, &Value::from("a;b=c"), b"a%3Bb%3Dc")?;
+        t(&mut buf, &Value::from("a:b"), b"a:b")?;
+        t(
+            &mut buf,
+            &Value::from(vec![Some(String::from("a,b")), None]),
+            b"a%2Cb,.",
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_info_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::{
+                record::value::{
+                    map::{self, info::Type},
+                    Map,
+                },
+                Number,
+            },
+            reader::record::info::parse_info,
+            record::info::field::key,
+            Header,
+        };
+
+        let character_array_key: key::Key = "CHARS".parse()?;
+        let string_key: key::Key = "STR".parse()?;
+
+        let header = Header::builder()
+            .add_info(
+                key::SAMPLES_WITH_DATA_COUNT,
+                Map::<map::Info>::from(&key::SAMPLES_WITH_DATA_COUNT),
+            )
+            .add_info(
+                key::ALLELE_FREQUENCIES,
+                Map::<map::Info>::from(&key::ALLELE_FREQUENCIES),
+            )
+            .add_info(
+                key::IS_IN_DB_SNP,
+                Map::<map::Info>::from(&key::IS_IN_DB_SNP),
+            )
+            .add_info(
+                character_array_key.clone(),
+                Map::<map::Info>::new(Number::Unknown, Type::Character, "Characters"),
+            )
+            .add_info(
+                string_key.clone(),
+                Map::<map::Info>::new(Number::Count(1), Type::String, "A string"),
+            )
+            .build();
+
+        let infos = [
+            [(key::SAMPLES_WITH_DATA_COUNT, Some(Value::from(2)))]
+                .into_iter()
+                .collect(),
+            [(
+                key::ALLELE_FREQUENCIES,
+                Some(Value::from(vec![Some(0.5), Some(0.25)])),
+            )]
+            .into_iter()
+            .collect(),
+            [(
+                character_array_key.clone(),
+                Some(Value::from(vec![Some('n'), None, Some('d')])),
+            )]
+            .into_iter()
+            .collect(),
+            [(key::IS_IN_DB_SNP, Some(Value::Flag))]
+                .into_iter()
+                .collect(),
+            [(key::IS_IN_DB_SNP, None)].into_iter().collect(),
+            [(string_key, Some(Value::from("noodles=vcf; 100%")))]
+                .into_iter()
+                .collect(),
+            [(
+                character_array_key,
+                Some(Value::from(vec![Some(';'), Some(':'), Some('%')])),
+            )]
+            .into_iter()
+            .collect(),
+        ];
+
+        let mut buf = Vec::new();
+
+        for expected in infos {
+            buf.clear();
+            write_info(&mut buf, &expected)?;
+
+            let s = std::str::from_utf8(&buf)?;
+            let mut actual = Info::default();
+            parse_info(&header, s, &mut actual)?;
+
+            assert_eq!(actual, expected, "{s}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_info() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{map, Map},
+            reader::record::info::parse_info,
+            record::info::field::key,
+        };
+
+        let header = Header::builder()
+            .add_info(
+                key::SAMPLES_WITH_DATA_COUNT,
+                Map::<map::Info>::from(&key::SAMPLES_WITH_DATA_COUNT),
+            )
+            .add_info(
+                key::ALLELE_FREQUENCIES,
+                Map::<map::Info>::from(&key::ALLELE_FREQUENCIES),
+            )
+            .build();
+
+        let mut buf = Vec::new();
+
+        // integer for a `Float` field
+        let info = [(
+            key::ALLELE_FREQUENCIES,
+            Some(Value::from(vec![Some(1), None])),
+        )]
+        .into_iter()
+        .collect();
+
+        validate_info(&header, &info)?;
+        write_info(&mut buf, &info)?;
+        assert_eq!(buf, b"AF=1,.");
+
+        let s = std::str::from_utf8(&buf)?;
+        let mut actual = Info::default();
+        parse_info(&header, s, &mut actual)?;
+
+        let expected = [(
+            key::ALLELE_FREQUENCIES,
+            Some(Value::from(vec![Some(1.0), None])),
+        )]
+        .into_iter()
+        .collect();
+
+        assert_eq!(actual, expected);
+
+        // string for an `Integer` field
+        let info = [(key::SAMPLES_WITH_DATA_COUNT, Some(Value::from("two")))]
+            .into_iter()
+            .collect();
+
+        assert!(matches!(
+            validate_info(&header, &info),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 use std::io::{self, Write};
 
-use super::MISSING;
+use super::{float::write_float, MISSING};
 use crate::record::QualityScore;
 
 pub(super) fn write_quality_score<W>(
@@ -11,7 +11,7 @@ where
     W: Write,
 {
     if let Some(qual) = quality_score.map(f32::from) {
-        write!(writer, "{qual}")?;
+        write_float(writer, qual)?;
     } else {
         writer.write_all(MISSING)?;
     }
@@ -41,6 +41,13 @@ mod tests {
         t(&mut buf, None, b".")?;
         t(&mut buf, QualityScore::try_from(0.0).map(Some)?, b"0")?;
         t(&mut buf, QualityScore::try_from(8.13).map(Some)?, b"8.13")?;
+        t(&mut buf, QualityScore::try_from(30.1).map(Some)?, b"30.1")?;
+        t(&mut buf, QualityScore::try_from(1e7).map(Some)?, b"1e+07")?;
+        t(
+            &mut buf,
+            QualityScore::try_from(1.0 / 3.0).map(Some)?,
+            b"0.333333",
+        )?;
 
         Ok(())
     }